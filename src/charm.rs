@@ -0,0 +1,137 @@
+use super::*;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Charm {
+  Cursed,
+  Lost,
+  Reinscription,
+  Unbound,
+  Vindicated,
+}
+
+impl Charm {
+  pub const ALL: [Charm; 5] = [
+    Self::Cursed,
+    Self::Lost,
+    Self::Reinscription,
+    Self::Unbound,
+    Self::Vindicated,
+  ];
+
+  fn flag(self) -> u16 {
+    1 << self as u16
+  }
+
+  pub(crate) fn set(self, charms: &mut u16) {
+    *charms |= self.flag();
+  }
+
+  pub(crate) fn is_set(self, charms: u16) -> bool {
+    charms & self.flag() != 0
+  }
+
+  pub(crate) fn charms(charms: u16) -> Vec<Charm> {
+    Self::ALL
+      .iter()
+      .copied()
+      .filter(|charm| charm.is_set(charms))
+      .collect()
+  }
+
+  pub(crate) fn icon(self) -> &'static str {
+    match self {
+      Self::Cursed => "👹",
+      Self::Lost => "🤔",
+      Self::Reinscription => "♻️",
+      Self::Unbound => "🔓",
+      Self::Vindicated => "❤️‍🔥",
+    }
+  }
+}
+
+impl Display for Charm {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        Self::Cursed => "cursed",
+        Self::Lost => "lost",
+        Self::Reinscription => "reinscription",
+        Self::Unbound => "unbound",
+        Self::Vindicated => "vindicated",
+      }
+    )
+  }
+}
+
+impl FromStr for Charm {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "cursed" => Ok(Self::Cursed),
+      "lost" => Ok(Self::Lost),
+      "reinscription" => Ok(Self::Reinscription),
+      "unbound" => Ok(Self::Unbound),
+      "vindicated" => Ok(Self::Vindicated),
+      _ => Err(anyhow!("invalid charm: {s}")),
+    }
+  }
+}
+
+impl Serialize for Charm {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_str(self)
+  }
+}
+
+impl<'de> Deserialize<'de> for Charm {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    Ok(DeserializeFromStr::deserialize(deserializer)?.0)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn flags_are_unique() {
+    let mut charms = 0;
+
+    for charm in Charm::ALL {
+      assert!(!charm.is_set(charms));
+      charm.set(&mut charms);
+      assert!(charm.is_set(charms));
+    }
+
+    assert_eq!(Charm::charms(charms), Charm::ALL);
+  }
+
+  #[test]
+  fn charms() {
+    let mut charms = 0;
+
+    Charm::Lost.set(&mut charms);
+    Charm::Unbound.set(&mut charms);
+
+    assert_eq!(Charm::charms(charms), [Charm::Lost, Charm::Unbound]);
+    assert_eq!(Charm::charms(0), []);
+  }
+
+  #[test]
+  fn from_str_round_trips() {
+    for charm in Charm::ALL {
+      assert_eq!(charm.to_string().parse::<Charm>().unwrap(), charm);
+    }
+
+    assert!("foo".parse::<Charm>().is_err());
+  }
+}
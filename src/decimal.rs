@@ -1,23 +1,69 @@
 use super::*;
 
-#[derive(PartialEq, Debug)]
+#[derive(Debug, PartialEq, Copy, Clone)]
 pub(crate) struct Decimal {
-  height: Height,
-  offset: u64,
+  value: u128,
+  scale: u8,
 }
 
-impl From<Sat> for Decimal {
-  fn from(sat: Sat) -> Self {
-    Self {
-      height: sat.height(),
-      offset: sat.third(),
+impl Decimal {
+  pub(crate) fn to_amount(self, divisibility: u8) -> Result<u128> {
+    match divisibility.checked_sub(self.scale) {
+      Some(difference) => Ok(
+        self
+          .value
+          .checked_mul(
+            10u128
+              .checked_pow(u32::from(difference))
+              .context("divisibility out of range")?,
+          )
+          .context("amount out of range")?,
+      ),
+      None => bail!("excessive precision"),
     }
   }
 }
 
-impl Display for Decimal {
-  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-    write!(f, "{}.{}", self.height, self.offset)
+impl FromStr for Decimal {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    if let Some((integer, decimal)) = s.split_once('.') {
+      if integer.is_empty() && decimal.is_empty() {
+        bail!("empty decimal");
+      }
+
+      let integer = if integer.is_empty() {
+        0
+      } else {
+        integer.parse::<u128>()?
+      };
+
+      let decimal = decimal.trim_end_matches('0');
+
+      let (decimal, scale) = if decimal.is_empty() {
+        (0, 0)
+      } else {
+        if !decimal.chars().all(|c| c.is_ascii_digit()) {
+          bail!("invalid decimal: {decimal}");
+        }
+        (decimal.parse::<u128>()?, u8::try_from(decimal.len())?)
+      };
+
+      Ok(Self {
+        value: integer
+          .checked_mul(10u128.pow(u32::from(scale)))
+          .context("amount out of range")?
+          .checked_add(decimal)
+          .context("amount out of range")?,
+        scale,
+      })
+    } else {
+      Ok(Self {
+        value: s.parse::<u128>()?,
+        scale: 0,
+      })
+    }
   }
 }
 
@@ -26,27 +72,56 @@ mod tests {
   use super::*;
 
   #[test]
-  fn decimal() {
-    assert_eq!(
-      Sat(0).decimal(),
-      Decimal {
-        height: Height(0),
-        offset: 0
-      }
-    );
-    assert_eq!(
-      Sat(1).decimal(),
-      Decimal {
-        height: Height(0),
-        offset: 1
-      }
-    );
+  fn from_str() {
+    #[track_caller]
+    fn case(s: &str, value: u128, scale: u8) {
+      assert_eq!(s.parse::<Decimal>().unwrap(), Decimal { value, scale });
+    }
+
+    assert!("".parse::<Decimal>().is_err());
+    assert!(".".parse::<Decimal>().is_err());
+    assert!("a.b".parse::<Decimal>().is_err());
+    assert!("1.-1".parse::<Decimal>().is_err());
+
+    case("0", 0, 0);
+    case("0.00000", 0, 0);
+    case("1.0", 1, 0);
+    case("1.1", 11, 1);
+    case("1.10", 11, 1);
+    case(".1", 1, 1);
+    case("1.", 1, 0);
+    case("123.456", 123456, 3);
+  }
+
+  #[test]
+  fn to_amount() {
+    #[track_caller]
+    fn case(s: &str, divisibility: u8, amount: u128) {
+      assert_eq!(
+        s.parse::<Decimal>()
+          .unwrap()
+          .to_amount(divisibility)
+          .unwrap(),
+        amount,
+      );
+    }
+
     assert_eq!(
-      Sat(2099999997689999).decimal(),
-      Decimal {
-        height: Height(6929999),
-        offset: 0
-      }
+      "1.1"
+        .parse::<Decimal>()
+        .unwrap()
+        .to_amount(0)
+        .unwrap_err()
+        .to_string(),
+      "excessive precision",
     );
+
+    case("1", 0, 1);
+    case("1.0", 0, 1);
+    case("1.0", 1, 10);
+    case("1.2", 1, 12);
+    case("1.2", 2, 120);
+    case("123.456", 3, 123456);
+    case("123.456", 6, 123456000);
   }
 }
@@ -14,6 +14,8 @@ pub(crate) const POINTER_TAG: [u8; 1] = [2];
 pub(crate) const PARENT_TAG: [u8; 1] = [3];
 pub(crate) const METADATA_TAG: [u8; 1] = [5];
 pub(crate) const METAPROTOCOL_TAG: [u8; 1] = [7];
+pub(crate) const CONTENT_ENCODING_TAG: [u8; 1] = [9];
+pub(crate) const DELEGATE_TAG: [u8; 1] = [11];
 
 type Result<T> = std::result::Result<T, script::Error>;
 type RawEnvelope = Envelope<Vec<Vec<u8>>>;
@@ -82,6 +84,8 @@ impl From<RawEnvelope> for ParsedEnvelope {
     let pointer = remove_field(&mut fields, &POINTER_TAG);
     let metaprotocol = remove_field(&mut fields, &METAPROTOCOL_TAG);
     let metadata = remove_and_concatenate_field(&mut fields, &METADATA_TAG);
+    let content_encoding = remove_field(&mut fields, &CONTENT_ENCODING_TAG);
+    let delegate = remove_field(&mut fields, &DELEGATE_TAG);
 
     let unrecognized_even_field = fields
       .keys()
@@ -96,7 +100,9 @@ impl From<RawEnvelope> for ParsedEnvelope {
             .cloned()
             .collect()
         }),
+        content_encoding,
         content_type,
+        delegate,
         parent,
         pointer,
         unrecognized_even_field,
@@ -400,7 +406,7 @@ mod tests {
         b"ord",
         &[1],
         b"text/plain;charset=utf-8",
-        &[9],
+        &[13],
         b"bar",
         &[],
         b"ord",
@@ -730,7 +736,7 @@ mod tests {
   #[test]
   fn unknown_odd_fields_are_ignored() {
     assert_eq!(
-      parse(&[envelope(&[b"ord", &[9], &[0]])]),
+      parse(&[envelope(&[b"ord", &[13], &[0]])]),
       vec![ParsedEnvelope {
         payload: Inscription::default(),
         ..Default::default()
@@ -766,6 +772,36 @@ mod tests {
     );
   }
 
+  #[test]
+  fn content_encoding_field_is_recognized() {
+    assert_eq!(
+      parse(&[envelope(&[b"ord", &[9], b"br"])]),
+      vec![ParsedEnvelope {
+        payload: Inscription {
+          content_encoding: Some(b"br".to_vec()),
+          ..Default::default()
+        },
+        ..Default::default()
+      }],
+    );
+  }
+
+  #[test]
+  fn delegate_field_is_recognized() {
+    let delegate = inscription_id(1).value();
+
+    assert_eq!(
+      parse(&[envelope(&[b"ord", &[11], &delegate])]),
+      vec![ParsedEnvelope {
+        payload: Inscription {
+          delegate: Some(delegate),
+          ..Default::default()
+        },
+        ..Default::default()
+      }],
+    );
+  }
+
   #[test]
   fn duplicate_pointer_field_makes_inscription_unbound() {
     assert_eq!(
@@ -1,15 +1,20 @@
 use {
   self::{
     entry::{
-      BlockHashValue, Entry, InscriptionEntry, InscriptionEntryValue, InscriptionIdValue,
-      OutPointValue, RuneEntryValue, RuneIdValue, SatPointValue, SatRange, TxidValue,
+      BlockHashValue, CollectionEntry, CollectionEntryValue, Entry, InscriptionEntry,
+      InscriptionEntryValue, InscriptionIdValue, OutPointValue, RuneEntryValue, RuneIdValue,
+      SatPointValue, SatRange, TransferEntryValue, TxidValue,
     },
+    event::Event,
     reorg::*,
     runes::{Rune, RuneId},
     updater::Updater,
   },
   super::*,
-  crate::subcommand::find::FindRangeOutput,
+  crate::subcommand::{
+    find::FindRangeOutput,
+    provenance::{self, Descendant},
+  },
   crate::wallet::Wallet,
   bitcoin::block::Header,
   bitcoincore_rpc::{json::GetBlockHeaderResult, Client},
@@ -17,25 +22,30 @@ use {
   indicatif::{ProgressBar, ProgressStyle},
   log::log_enabled,
   redb::{
-    Database, MultimapTable, MultimapTableDefinition, ReadableMultimapTable, ReadableTable, Table,
-    TableDefinition, WriteTransaction,
+    Database, MultimapTable, MultimapTableDefinition, ReadOnlyMultimapTable, ReadableMultimapTable,
+    ReadableTable, Table, TableDefinition, WriteTransaction,
   },
   std::collections::{BTreeSet, HashMap},
   std::io::{BufWriter, Read, Write},
+  tokio::sync::broadcast,
 };
 
-pub(crate) use self::entry::RuneEntry;
+pub(crate) use self::entry::{RuneEntry, TransferEntry};
 
 pub(crate) mod entry;
+pub(crate) mod event;
 mod fetcher;
 mod reorg;
 mod rtx;
+pub(crate) mod text;
 mod updater;
 
 #[cfg(test)]
 pub(crate) mod testing;
 
-const SCHEMA_VERSION: u64 = 10;
+const SCHEMA_VERSION: u64 = 22;
+
+const MAX_PROVENANCE_CHILDREN: usize = 8;
 
 macro_rules! define_table {
   ($name:ident, $key:ty, $value:ty) => {
@@ -50,9 +60,17 @@ macro_rules! define_multimap_table {
   };
 }
 
+define_multimap_table! { ADDRESS_TO_OUTPOINTS, &[u8], &OutPointValue }
+define_multimap_table! { CONTENT_HASH_TO_SEQUENCE_NUMBER, &[u8; 32], u64 }
+define_multimap_table! { CONTENT_TYPE_TO_SEQUENCE_NUMBER, &str, u64 }
 define_multimap_table! { INSCRIPTION_ID_TO_CHILDREN, &InscriptionIdValue, &InscriptionIdValue }
+define_multimap_table! { METAPROTOCOL_TO_SEQUENCE_NUMBER, &str, u64 }
+define_multimap_table! { RUNE_ID_TO_OUTPOINTS, RuneIdValue, &OutPointValue }
 define_multimap_table! { SATPOINT_TO_INSCRIPTION_ID, &SatPointValue, &InscriptionIdValue }
+define_multimap_table! { SAT_RARITY_TO_SEQUENCE_NUMBER, u8, u64 }
 define_multimap_table! { SAT_TO_INSCRIPTION_ID, u64, &InscriptionIdValue }
+define_multimap_table! { WORD_TO_SEQUENCE_NUMBER, &str, u64 }
+define_table! { CONTENT_HASH_TO_INSCRIPTION_COUNT, &[u8; 32], u64 }
 define_table! { HEIGHT_TO_BLOCK_HASH, u64, &BlockHashValue }
 define_table! { HEIGHT_TO_LAST_SEQUENCE_NUMBER, u64, u64 }
 define_table! { HOME_INSCRIPTIONS, u64, &InscriptionIdValue }
@@ -60,12 +78,17 @@ define_table! { INSCRIPTION_ID_TO_INSCRIPTION_ENTRY, &InscriptionIdValue, Inscri
 define_table! { INSCRIPTION_ID_TO_RUNE, &InscriptionIdValue, u128 }
 define_table! { INSCRIPTION_ID_TO_SATPOINT, &InscriptionIdValue, &SatPointValue }
 define_table! { INSCRIPTION_NUMBER_TO_INSCRIPTION_ID, i64, &InscriptionIdValue }
+define_table! { METAPROTOCOL_TO_INSCRIPTION_COUNT, &str, u64 }
 define_table! { OUTPOINT_TO_RUNE_BALANCES, &OutPointValue, &[u8] }
 define_table! { OUTPOINT_TO_SAT_RANGES, &OutPointValue, &[u8] }
+define_table! { OUTPOINT_TO_TX_OUT, &OutPointValue, (&[u8], u64) }
 define_table! { OUTPOINT_TO_VALUE, &OutPointValue, u64}
+define_table! { PARENT_TO_COLLECTION_ENTRY, &InscriptionIdValue, CollectionEntryValue }
+define_table! { PARENT_TRAIT_TO_COUNT, (&InscriptionIdValue, &str, &str), u64 }
 define_table! { RUNE_ID_TO_RUNE_ENTRY, RuneIdValue, RuneEntryValue }
 define_table! { RUNE_TO_RUNE_ID, u128, RuneIdValue }
 define_table! { SAT_TO_SATPOINT, u64, &SatPointValue }
+define_table! { SEQUENCE_NUMBER_AND_INDEX_TO_TRANSFER, (u64, u32), &TransferEntryValue }
 define_table! { SEQUENCE_NUMBER_TO_INSCRIPTION_ID, u64, &InscriptionIdValue }
 define_table! { STATISTIC_TO_COUNT, u64, u64 }
 define_table! { TRANSACTION_ID_TO_RUNE, &TxidValue, u128 }
@@ -83,8 +106,10 @@ pub(crate) enum Statistic {
   BlessedInscriptions,
   Commits,
   CursedInscriptions,
+  IndexAddresses,
   IndexRunes,
   IndexSats,
+  IndexText,
   LostSats,
   OutputsTraversed,
   Runes,
@@ -128,7 +153,62 @@ pub(crate) struct TransactionInfo {
   pub(crate) starting_timestamp: u128,
 }
 
-trait BitcoinCoreRpcResultExt<T> {
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct Collection {
+  pub(crate) child_count: u64,
+  pub(crate) first_height: Option<u64>,
+  pub(crate) floor_rarity: Option<Rarity>,
+  pub(crate) last_height: Option<u64>,
+  pub(crate) traits: BTreeMap<String, BTreeMap<String, u64>>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum InscriptionOrder {
+  #[default]
+  Newest,
+  Oldest,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct RuneHolder {
+  pub(crate) address: Option<Address>,
+  pub(crate) amount: u128,
+  pub(crate) outpoints: Vec<OutPoint>,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+pub(crate) struct InscriptionFilter {
+  pub(crate) content_type: Option<String>,
+  pub(crate) height: Option<u64>,
+  pub(crate) metaprotocol: Option<String>,
+  pub(crate) order: Option<InscriptionOrder>,
+  pub(crate) sat_rarity: Option<Rarity>,
+}
+
+impl InscriptionFilter {
+  fn matches(&self, entry: &InscriptionEntry) -> bool {
+    if let Some(content_type) = &self.content_type {
+      if !entry
+        .content_type()
+        .map(|actual| actual.starts_with(content_type.as_str()))
+        .unwrap_or_default()
+      {
+        return false;
+      }
+    }
+
+    if let Some(sat_rarity) = self.sat_rarity {
+      if entry.sat.map(Rarity::from) != Some(sat_rarity) {
+        return false;
+      }
+    }
+
+    true
+  }
+}
+
+pub(crate) trait BitcoinCoreRpcResultExt<T> {
   fn into_option(self) -> Result<Option<T>>;
 }
 
@@ -137,7 +217,7 @@ impl<T> BitcoinCoreRpcResultExt<T> for Result<T, bitcoincore_rpc::Error> {
     match self {
       Ok(ok) => Ok(Some(ok)),
       Err(bitcoincore_rpc::Error::JsonRpc(bitcoincore_rpc::jsonrpc::error::Error::Rpc(
-        bitcoincore_rpc::jsonrpc::error::RpcError { code: -8, .. },
+        bitcoincore_rpc::jsonrpc::error::RpcError { code: -5 | -8, .. },
       ))) => Ok(None),
       Err(bitcoincore_rpc::Error::JsonRpc(bitcoincore_rpc::jsonrpc::error::Error::Rpc(
         bitcoincore_rpc::jsonrpc::error::RpcError { message, .. },
@@ -155,12 +235,15 @@ pub(crate) struct Index {
   client: Client,
   database: Database,
   durability: redb::Durability,
+  event_sender: broadcast::Sender<Event>,
   first_inscription_height: u64,
   genesis_block_coinbase_transaction: Transaction,
   genesis_block_coinbase_txid: Txid,
   height_limit: Option<u64>,
+  index_addresses: bool,
   index_runes: bool,
   index_sats: bool,
+  index_text: bool,
   options: Options,
   path: PathBuf,
   unrecoverably_reorged: AtomicBool,
@@ -214,8 +297,10 @@ impl Index {
       redb::Durability::Immediate
     };
 
+    let index_addresses;
     let index_runes;
     let index_sats;
+    let index_text;
 
     let database = match Database::builder()
       .set_cache_size(db_cache_size)
@@ -247,6 +332,11 @@ impl Index {
 
           let statistics = tx.open_table(STATISTIC_TO_COUNT)?;
 
+          index_addresses = statistics
+            .get(&Statistic::IndexAddresses.key())?
+            .unwrap()
+            .value()
+            != 0;
           index_runes = statistics
             .get(&Statistic::IndexRunes.key())?
             .unwrap()
@@ -257,6 +347,11 @@ impl Index {
             .unwrap()
             .value()
             != 0;
+          index_text = statistics
+            .get(&Statistic::IndexText.key())?
+            .unwrap()
+            .value()
+            != 0;
         }
 
         database
@@ -270,9 +365,17 @@ impl Index {
 
         tx.set_durability(durability);
 
+        tx.open_multimap_table(ADDRESS_TO_OUTPOINTS)?;
+        tx.open_multimap_table(CONTENT_HASH_TO_SEQUENCE_NUMBER)?;
+        tx.open_multimap_table(CONTENT_TYPE_TO_SEQUENCE_NUMBER)?;
         tx.open_multimap_table(INSCRIPTION_ID_TO_CHILDREN)?;
+        tx.open_multimap_table(METAPROTOCOL_TO_SEQUENCE_NUMBER)?;
+        tx.open_multimap_table(RUNE_ID_TO_OUTPOINTS)?;
         tx.open_multimap_table(SATPOINT_TO_INSCRIPTION_ID)?;
+        tx.open_multimap_table(SAT_RARITY_TO_SEQUENCE_NUMBER)?;
         tx.open_multimap_table(SAT_TO_INSCRIPTION_ID)?;
+        tx.open_multimap_table(WORD_TO_SEQUENCE_NUMBER)?;
+        tx.open_table(CONTENT_HASH_TO_INSCRIPTION_COUNT)?;
         tx.open_table(HEIGHT_TO_BLOCK_HASH)?;
         tx.open_table(HEIGHT_TO_LAST_SEQUENCE_NUMBER)?;
         tx.open_table(HOME_INSCRIPTIONS)?;
@@ -280,11 +383,16 @@ impl Index {
         tx.open_table(INSCRIPTION_ID_TO_RUNE)?;
         tx.open_table(INSCRIPTION_ID_TO_SATPOINT)?;
         tx.open_table(INSCRIPTION_NUMBER_TO_INSCRIPTION_ID)?;
+        tx.open_table(METAPROTOCOL_TO_INSCRIPTION_COUNT)?;
         tx.open_table(OUTPOINT_TO_RUNE_BALANCES)?;
+        tx.open_table(OUTPOINT_TO_TX_OUT)?;
         tx.open_table(OUTPOINT_TO_VALUE)?;
+        tx.open_table(PARENT_TO_COLLECTION_ENTRY)?;
+        tx.open_table(PARENT_TRAIT_TO_COUNT)?;
         tx.open_table(RUNE_ID_TO_RUNE_ENTRY)?;
         tx.open_table(RUNE_TO_RUNE_ID)?;
         tx.open_table(SAT_TO_SATPOINT)?;
+        tx.open_table(SEQUENCE_NUMBER_AND_INDEX_TO_TRANSFER)?;
         tx.open_table(SEQUENCE_NUMBER_TO_INSCRIPTION_ID)?;
         tx.open_table(TRANSACTION_ID_TO_RUNE)?;
         tx.open_table(WRITE_TRANSACTION_STARTING_BLOCK_COUNT_TO_TIMESTAMP)?;
@@ -297,14 +405,21 @@ impl Index {
             outpoint_to_sat_ranges.insert(&OutPoint::null().store(), [].as_slice())?;
           }
 
+          index_addresses = options.index_addresses;
           index_runes = options.index_runes();
           index_sats = options.index_sats;
+          index_text = options.index_text;
 
+          statistics.insert(
+            &Statistic::IndexAddresses.key(),
+            &u64::from(options.index_addresses),
+          )?;
           statistics.insert(
             &Statistic::IndexRunes.key(),
             &u64::from(options.index_runes()),
           )?;
           statistics.insert(&Statistic::IndexSats.key(), &u64::from(options.index_sats))?;
+          statistics.insert(&Statistic::IndexText.key(), &u64::from(options.index_text))?;
           statistics.insert(&Statistic::Schema.key(), &SCHEMA_VERSION)?;
         }
 
@@ -322,12 +437,15 @@ impl Index {
       client,
       database,
       durability,
+      event_sender: broadcast::channel(1024).0,
       first_inscription_height: options.first_inscription_height(),
       genesis_block_coinbase_transaction,
       height_limit: options.height_limit,
       options: options.clone(),
+      index_addresses,
       index_runes,
       index_sats,
+      index_text,
       path,
       unrecoverably_reorged: AtomicBool::new(false),
     })
@@ -411,10 +529,22 @@ impl Index {
       .collect()
   }
 
+  pub(crate) fn has_address_index(&self) -> bool {
+    self.index_addresses
+  }
+
+  pub(crate) fn has_rune_index(&self) -> bool {
+    self.index_runes
+  }
+
   pub(crate) fn has_sat_index(&self) -> bool {
     self.index_sats
   }
 
+  pub(crate) fn has_text_index(&self) -> bool {
+    self.index_text
+  }
+
   pub(crate) fn info(&self) -> Result<Info> {
     let wtx = self.begin_write()?;
 
@@ -563,6 +693,10 @@ impl Index {
     Ok(())
   }
 
+  pub(crate) fn subscribe(&self) -> broadcast::Receiver<Event> {
+    self.event_sender.subscribe()
+  }
+
   pub(crate) fn is_unrecoverably_reorged(&self) -> bool {
     self.unrecoverably_reorged.load(atomic::Ordering::Relaxed)
   }
@@ -698,6 +832,40 @@ impl Index {
     Ok(entries)
   }
 
+  pub(crate) fn get_metaprotocols(&self) -> Result<Vec<(String, u64)>> {
+    let mut metaprotocols = Vec::new();
+
+    for result in self
+      .database
+      .begin_read()?
+      .open_table(METAPROTOCOL_TO_INSCRIPTION_COUNT)?
+      .iter()?
+    {
+      let (metaprotocol, count) = result?;
+      metaprotocols.push((metaprotocol.value().to_string(), count.value()));
+    }
+
+    metaprotocols.sort_by(|(a_name, a_count), (b_name, b_count)| {
+      b_count.cmp(a_count).then_with(|| a_name.cmp(b_name))
+    });
+
+    Ok(metaprotocols)
+  }
+
+  pub(crate) fn get_metaprotocol_inscription_count(
+    &self,
+    metaprotocol: &str,
+  ) -> Result<Option<u64>> {
+    Ok(
+      self
+        .database
+        .begin_read()?
+        .open_table(METAPROTOCOL_TO_INSCRIPTION_COUNT)?
+        .get(metaprotocol)?
+        .map(|count| count.value()),
+    )
+  }
+
   pub(crate) fn get_rune_balances_for_outpoint(
     &self,
     outpoint: OutPoint,
@@ -739,6 +907,106 @@ impl Index {
     Ok(balances)
   }
 
+  /// Holders of rune `id`, paged by output so a request only reads
+  /// `page_size` outputs. Outputs on a page are grouped by script pubkey and
+  /// ordered by descending balance. Without `--index-addresses` script
+  /// pubkeys are not known, so each output is its own holder.
+  pub(crate) fn get_rune_holders_paginated(
+    &self,
+    id: RuneId,
+    page_size: usize,
+    page_index: usize,
+  ) -> Result<(Vec<RuneHolder>, bool)> {
+    #[derive(Eq, Ord, PartialEq, PartialOrd)]
+    enum Key {
+      ScriptPubkey(Vec<u8>),
+      OutPoint(OutPoint),
+    }
+
+    let rtx = self.database.begin_read()?;
+
+    let outpoint_to_balances = rtx.open_table(OUTPOINT_TO_RUNE_BALANCES)?;
+
+    let outpoint_to_tx_out = rtx.open_table(OUTPOINT_TO_TX_OUT)?;
+
+    let mut outpoints = Vec::new();
+
+    for result in rtx
+      .open_multimap_table(RUNE_ID_TO_OUTPOINTS)?
+      .get(id.store())?
+      .skip(page_index.saturating_mul(page_size))
+      .take(page_size.saturating_add(1))
+    {
+      outpoints.push(OutPoint::load(*result?.value()));
+    }
+
+    let more = outpoints.len() > page_size;
+
+    if more {
+      outpoints.pop();
+    }
+
+    let mut holders = BTreeMap::<Key, (u128, Vec<OutPoint>)>::new();
+
+    for outpoint in outpoints {
+      let mut amount = 0;
+
+      if let Some(balances) = outpoint_to_balances.get(&outpoint.store())? {
+        let buffer = balances.value();
+        let mut i = 0;
+        while i < buffer.len() {
+          let (balance_id, length) = runes::varint::decode(&buffer[i..])
+            .with_context(|| format!("failed to decode rune balances for output {outpoint}"))?;
+          i += length;
+          let (balance, length) = runes::varint::decode(&buffer[i..])
+            .with_context(|| format!("failed to decode rune balances for output {outpoint}"))?;
+          i += length;
+
+          if balance_id == u128::from(id) {
+            amount = balance;
+            break;
+          }
+        }
+      }
+
+      let key = match outpoint_to_tx_out.get(&outpoint.store())? {
+        Some(tx_out) => Key::ScriptPubkey(tx_out.value().0.to_vec()),
+        None => Key::OutPoint(outpoint),
+      };
+
+      let (total, outpoints) = holders.entry(key).or_default();
+
+      *total += amount;
+      outpoints.push(outpoint);
+    }
+
+    let mut holders = holders
+      .into_iter()
+      .collect::<Vec<(Key, (u128, Vec<OutPoint>))>>();
+
+    holders.sort_by(|(a_key, (a_amount, _)), (b_key, (b_amount, _))| {
+      b_amount.cmp(a_amount).then_with(|| a_key.cmp(b_key))
+    });
+
+    let chain = self.options.chain();
+
+    let holders = holders
+      .into_iter()
+      .map(|(key, (amount, outpoints))| RuneHolder {
+        address: match key {
+          Key::ScriptPubkey(script_pubkey) => chain
+            .address_from_script(Script::from_bytes(&script_pubkey))
+            .ok(),
+          Key::OutPoint(_) => None,
+        },
+        amount,
+        outpoints,
+      })
+      .collect::<Vec<RuneHolder>>();
+
+    Ok((holders, more))
+  }
+
   #[cfg(test)]
   pub(crate) fn get_rune_balances(&self) -> Vec<(OutPoint, Vec<(RuneId, u128)>)> {
     let mut result = Vec::new();
@@ -842,6 +1110,181 @@ impl Index {
     Ok((children, more))
   }
 
+  pub(crate) fn get_provenance(
+    &self,
+    inscription_id: InscriptionId,
+    depth: usize,
+  ) -> Result<Option<provenance::Output>> {
+    let rtx = self.database.begin_read()?;
+
+    let id_to_entry = rtx.open_table(INSCRIPTION_ID_TO_INSCRIPTION_ENTRY)?;
+
+    let Some(entry) = id_to_entry.get(&inscription_id.store())? else {
+      return Ok(None);
+    };
+
+    let mut visited = HashSet::from([inscription_id]);
+
+    let mut ancestors = Vec::new();
+    let mut ancestors_truncated = false;
+    let mut next = InscriptionEntry::load(entry.value()).parent;
+
+    while let Some(parent) = next {
+      if !visited.insert(parent) {
+        break;
+      }
+
+      if ancestors.len() == depth {
+        ancestors_truncated = true;
+        break;
+      }
+
+      ancestors.push(parent);
+
+      next = id_to_entry
+        .get(&parent.store())?
+        .and_then(|entry| InscriptionEntry::load(entry.value()).parent);
+    }
+
+    let id_to_children = rtx.open_multimap_table(INSCRIPTION_ID_TO_CHILDREN)?;
+
+    let (descendants, descendants_truncated) = if depth == 0 {
+      (
+        Vec::new(),
+        id_to_children
+          .get(&inscription_id.store())?
+          .next()
+          .is_some(),
+      )
+    } else {
+      Self::get_descendants(&id_to_children, inscription_id, depth - 1, &mut visited)?
+    };
+
+    Ok(Some(provenance::Output {
+      ancestors,
+      ancestors_truncated,
+      descendants,
+      descendants_truncated,
+      inscription: inscription_id,
+    }))
+  }
+
+  /// Returns at most `MAX_PROVENANCE_CHILDREN` children of `inscription_id`,
+  /// along with whether any children were left out because of that limit.
+  fn get_descendants(
+    id_to_children: &ReadOnlyMultimapTable<&InscriptionIdValue, &InscriptionIdValue>,
+    inscription_id: InscriptionId,
+    depth: usize,
+    visited: &mut HashSet<InscriptionId>,
+  ) -> Result<(Vec<Descendant>, bool)> {
+    let mut descendants = Vec::new();
+
+    for result in id_to_children.get(&inscription_id.store())? {
+      let child = InscriptionId::load(*result?.value());
+
+      if !visited.insert(child) {
+        continue;
+      }
+
+      if descendants.len() == MAX_PROVENANCE_CHILDREN {
+        return Ok((descendants, true));
+      }
+
+      let (children, truncated) = if depth == 0 {
+        (
+          Vec::new(),
+          id_to_children.get(&child.store())?.next().is_some(),
+        )
+      } else {
+        Self::get_descendants(id_to_children, child, depth - 1, visited)?
+      };
+
+      descendants.push(Descendant {
+        children,
+        id: child,
+        truncated,
+      });
+    }
+
+    Ok((descendants, false))
+  }
+
+  pub(crate) fn get_collection(&self, inscription_id: InscriptionId) -> Result<Collection> {
+    let rtx = self.database.begin_read()?;
+
+    let parent = inscription_id.store();
+
+    let mut collection = Collection::default();
+
+    if let Some(entry) = rtx
+      .open_table(PARENT_TO_COLLECTION_ENTRY)?
+      .get(&parent)?
+      .map(|entry| CollectionEntry::load(entry.value()))
+    {
+      collection.child_count = entry.child_count;
+      collection.first_height = Some(entry.first_height);
+      collection.floor_rarity = entry.floor_rarity;
+      collection.last_height = Some(entry.last_height);
+    }
+
+    for result in rtx
+      .open_table(PARENT_TRAIT_TO_COUNT)?
+      .range((&parent, "", "")..)?
+    {
+      let (key, count) = result?;
+      let (key_parent, trait_type, value) = key.value();
+
+      if *key_parent != parent {
+        break;
+      }
+
+      collection
+        .traits
+        .entry(trait_type.into())
+        .or_default()
+        .insert(value.into(), count.value());
+    }
+
+    Ok(collection)
+  }
+
+  pub(crate) fn get_transfers_by_inscription_id_paginated(
+    &self,
+    inscription_id: InscriptionId,
+    page_size: usize,
+    page_index: usize,
+  ) -> Result<Option<(Vec<TransferEntry>, bool)>> {
+    let rtx = self.database.begin_read()?;
+
+    let Some(entry) = rtx
+      .open_table(INSCRIPTION_ID_TO_INSCRIPTION_ENTRY)?
+      .get(&inscription_id.store())?
+      .map(|entry| InscriptionEntry::load(entry.value()))
+    else {
+      return Ok(None);
+    };
+
+    let mut transfers = rtx
+      .open_table(SEQUENCE_NUMBER_AND_INDEX_TO_TRANSFER)?
+      .range((entry.sequence_number, 0)..=(entry.sequence_number, u32::MAX))?
+      .skip(page_index * page_size)
+      .take(page_size + 1)
+      .map(|result| {
+        result
+          .map(|(_key, transfer)| TransferEntry::load(*transfer.value()))
+          .map_err(|err| err.into())
+      })
+      .collect::<Result<Vec<TransferEntry>>>()?;
+
+    let more = transfers.len() > page_size;
+
+    if more {
+      transfers.pop();
+    }
+
+    Ok(Some((transfers, more)))
+  }
+
   pub(crate) fn get_etching(&self, txid: Txid) -> Result<Option<Rune>> {
     Ok(
       self
@@ -904,6 +1347,28 @@ impl Index {
     }
   }
 
+  pub(crate) fn get_inscription_ids_by_sat_paginated(
+    &self,
+    sat: Sat,
+    page_size: usize,
+    page_index: usize,
+  ) -> Result<(Vec<InscriptionId>, bool)> {
+    let mut ids = self
+      .get_inscription_ids_by_sat(sat)?
+      .into_iter()
+      .skip(page_index * page_size)
+      .take(page_size + 1)
+      .collect::<Vec<InscriptionId>>();
+
+    let more = ids.len() > page_size;
+
+    if more {
+      ids.pop();
+    }
+
+    Ok((ids, more))
+  }
+
   pub(crate) fn get_inscription_id_by_sequence_number(
     &self,
     n: u64,
@@ -979,6 +1444,29 @@ impl Index {
     )
   }
 
+  pub(crate) fn get_address_outputs(&self, address: &Address) -> Result<Vec<(OutPoint, u64)>> {
+    let rtx = self.database.begin_read()?;
+
+    let outpoint_to_tx_out = rtx.open_table(OUTPOINT_TO_TX_OUT)?;
+
+    let outputs = rtx
+      .open_multimap_table(ADDRESS_TO_OUTPOINTS)?
+      .get(address.script_pubkey().as_bytes())?
+      .map(|result| {
+        let outpoint = *result?.value();
+
+        let value = outpoint_to_tx_out
+          .get(&outpoint)?
+          .map(|tx_out| tx_out.value().1)
+          .unwrap_or_default();
+
+        Ok((OutPoint::load(outpoint), value))
+      })
+      .collect();
+
+    outputs
+  }
+
   pub(crate) fn get_inscriptions_on_output_with_satpoints(
     &self,
     outpoint: OutPoint,
@@ -1250,54 +1738,381 @@ impl Index {
     Ok((inscriptions, prev, next, lowest, highest))
   }
 
-  pub(crate) fn get_inscriptions_in_block(&self, block_height: u64) -> Result<Vec<InscriptionId>> {
+  /// Inscriptions matching `filter` are found by walking the metaprotocol or
+  /// sat rarity posting list, or by merging the posting lists of every content
+  /// type with the `filter.content_type` prefix, falling back to the sequence
+  /// number range of `filter.height`, and checking the remaining filters
+  /// against each inscription's entry.
+  pub(crate) fn get_inscriptions_filtered(
+    &self,
+    filter: &InscriptionFilter,
+    page_size: usize,
+    page_index: usize,
+  ) -> Result<(Vec<InscriptionId>, bool)> {
     let rtx = self.database.begin_read()?;
 
+    let content_type_to_sequence_number =
+      rtx.open_multimap_table(CONTENT_TYPE_TO_SEQUENCE_NUMBER)?;
     let height_to_last_sequence_number = rtx.open_table(HEIGHT_TO_LAST_SEQUENCE_NUMBER)?;
+    let inscription_id_to_inscription_entry =
+      rtx.open_table(INSCRIPTION_ID_TO_INSCRIPTION_ENTRY)?;
+    let metaprotocol_to_sequence_number =
+      rtx.open_multimap_table(METAPROTOCOL_TO_SEQUENCE_NUMBER)?;
+    let sat_rarity_to_sequence_number = rtx.open_multimap_table(SAT_RARITY_TO_SEQUENCE_NUMBER)?;
     let sequence_number_to_inscription_id = rtx.open_table(SEQUENCE_NUMBER_TO_INSCRIPTION_ID)?;
 
-    let Some(newest_sequence_number) = height_to_last_sequence_number
-      .get(&block_height)?
-      .map(|ag| ag.value())
-    else {
-      return Ok(Vec::new());
+    let (start, end) = match filter.height {
+      Some(height) => {
+        let last_sequence_number = |range| -> Result<u64> {
+          Ok(
+            height_to_last_sequence_number
+              .range(range)?
+              .next_back()
+              .transpose()?
+              .map(|(_height, sequence_number)| sequence_number.value())
+              .unwrap_or(0),
+          )
+        };
+
+        (
+          last_sequence_number(..height)?,
+          last_sequence_number(..height + 1)?,
+        )
+      }
+      None => (0, u64::MAX),
     };
 
-    let oldest_sequence_number = height_to_last_sequence_number
-      .get(block_height.saturating_sub(1))?
-      .map(|ag| ag.value())
-      .unwrap_or(0);
+    if start >= end {
+      return Ok((Vec::new(), false));
+    }
 
-    (oldest_sequence_number..newest_sequence_number)
-      .map(|num| match sequence_number_to_inscription_id.get(&num) {
-        Ok(Some(inscription_id)) => Ok(InscriptionId::load(*inscription_id.value())),
-        Ok(None) => Err(anyhow!(
-          "could not find inscription for inscription number {num}"
-        )),
-        Err(err) => Err(anyhow!(err)),
-      })
-      .collect::<Result<Vec<InscriptionId>>>()
-  }
+    let newest = filter.order.unwrap_or_default() == InscriptionOrder::Newest;
 
-  pub(crate) fn get_highest_paying_inscriptions_in_block(
-    &self,
-    block_height: u64,
-    n: usize,
-  ) -> Result<(Vec<InscriptionId>, usize)> {
-    let inscription_ids = self.get_inscriptions_in_block(block_height)?;
+    let sequence_numbers: Box<dyn Iterator<Item = Result<u64>>> =
+      if let Some(metaprotocol) = &filter.metaprotocol {
+        Self::posting_list(
+          metaprotocol_to_sequence_number.get(metaprotocol.as_str())?,
+          newest,
+        )
+      } else if let Some(sat_rarity) = filter.sat_rarity {
+        Self::posting_list(
+          sat_rarity_to_sequence_number.get(u8::from(sat_rarity))?,
+          newest,
+        )
+      } else if let Some(content_type) = &filter.content_type {
+        let mut posting_lists = Vec::new();
 
-    let mut inscription_to_fee: Vec<(InscriptionId, u64)> = Vec::new();
-    for id in &inscription_ids {
-      inscription_to_fee.push((
-        *id,
-        self
-          .get_inscription_entry(*id)?
-          .ok_or_else(|| anyhow!("could not get entry for inscription {id}"))?
-          .fee,
-      ));
-    }
+        for result in content_type_to_sequence_number.range(content_type.as_str()..)? {
+          let (key, sequence_numbers) = result?;
 
-    inscription_to_fee.sort_by_key(|(_, fee)| *fee);
+          if !key.value().starts_with(content_type.as_str()) {
+            break;
+          }
+
+          posting_lists.push(Self::posting_list(sequence_numbers, newest).peekable());
+        }
+
+        Box::new(std::iter::from_fn(move || {
+          let mut next: Option<(usize, u64)> = None;
+
+          for (i, posting_list) in posting_lists.iter_mut().enumerate() {
+            match posting_list.peek() {
+              Some(Ok(sequence_number))
+                if next.map_or(true, |(_, next)| {
+                  if newest {
+                    *sequence_number > next
+                  } else {
+                    *sequence_number < next
+                  }
+                }) =>
+              {
+                next = Some((i, *sequence_number));
+              }
+              Some(Ok(_)) => {}
+              Some(Err(_)) => return posting_list.next(),
+              None => {}
+            }
+          }
+
+          next.and_then(|(i, _)| posting_lists[i].next())
+        }))
+      } else {
+        let sequence_numbers = sequence_number_to_inscription_id
+          .range(start..end)?
+          .map(|result| {
+            result
+              .map(|(sequence_number, _id)| sequence_number.value())
+              .map_err(|err| err.into())
+          });
+
+        if newest {
+          Box::new(sequence_numbers.rev())
+        } else {
+          Box::new(sequence_numbers)
+        }
+      };
+
+    let sequence_numbers = sequence_numbers
+      .skip_while(|result| matches!(result, Ok(n) if if newest { *n >= end } else { *n < start }))
+      .take_while(|result| !matches!(result, Ok(n) if if newest { *n < start } else { *n >= end }));
+
+    let mut skip = page_index.saturating_mul(page_size);
+    let mut inscriptions = Vec::new();
+
+    for sequence_number in sequence_numbers {
+      let sequence_number = sequence_number?;
+
+      let inscription_id = sequence_number_to_inscription_id
+        .get(sequence_number)?
+        .ok_or_else(|| anyhow!("could not find inscription for sequence number {sequence_number}"))?
+        .value()
+        .to_owned();
+
+      let entry = InscriptionEntry::load(
+        inscription_id_to_inscription_entry
+          .get(&inscription_id)?
+          .ok_or_else(|| anyhow!("could not get entry for sequence number {sequence_number}"))?
+          .value(),
+      );
+
+      if !filter.matches(&entry) {
+        continue;
+      }
+
+      if skip > 0 {
+        skip -= 1;
+        continue;
+      }
+
+      if inscriptions.len() == page_size {
+        return Ok((inscriptions, true));
+      }
+
+      inscriptions.push(InscriptionId::load(inscription_id));
+    }
+
+    Ok((inscriptions, false))
+  }
+
+  fn posting_list(
+    sequence_numbers: redb::MultimapValue<u64>,
+    newest: bool,
+  ) -> Box<dyn Iterator<Item = Result<u64>> + '_> {
+    let sequence_numbers = sequence_numbers.map(|result| {
+      result
+        .map(|sequence_number| sequence_number.value())
+        .map_err(|err| err.into())
+    });
+
+    if newest {
+      Box::new(sequence_numbers.rev())
+    } else {
+      Box::new(sequence_numbers)
+    }
+  }
+
+  pub(crate) fn get_content_hash_inscription_count(
+    &self,
+    content_hash: sha256::Hash,
+  ) -> Result<u64> {
+    Ok(
+      self
+        .database
+        .begin_read()?
+        .open_table(CONTENT_HASH_TO_INSCRIPTION_COUNT)?
+        .get(&content_hash.to_byte_array())?
+        .map(|count| count.value())
+        .unwrap_or_default(),
+    )
+  }
+
+  pub(crate) fn get_inscriptions_by_content_hash_paginated(
+    &self,
+    content_hash: sha256::Hash,
+    page_size: usize,
+    page_index: usize,
+  ) -> Result<(Vec<InscriptionId>, bool)> {
+    let rtx = self.database.begin_read()?;
+
+    let content_hash_to_sequence_number =
+      rtx.open_multimap_table(CONTENT_HASH_TO_SEQUENCE_NUMBER)?;
+    let sequence_number_to_inscription_id = rtx.open_table(SEQUENCE_NUMBER_TO_INSCRIPTION_ID)?;
+
+    let mut inscriptions = content_hash_to_sequence_number
+      .get(&content_hash.to_byte_array())?
+      .skip(page_index.saturating_mul(page_size))
+      .take(page_size.saturating_add(1))
+      .map(|result| {
+        let sequence_number = result?.value();
+
+        Ok(InscriptionId::load(
+          *sequence_number_to_inscription_id
+            .get(sequence_number)?
+            .ok_or_else(|| {
+              anyhow!("could not find inscription for sequence number {sequence_number}")
+            })?
+            .value(),
+        ))
+      })
+      .collect::<Result<Vec<InscriptionId>>>()?;
+
+    let more = inscriptions.len() > page_size;
+
+    if more {
+      inscriptions.pop();
+    }
+
+    Ok((inscriptions, more))
+  }
+
+  /// Posting lists for each query word are walked in lockstep from newest
+  /// to oldest, stopping once enough matches have been found to fill the
+  /// requested page and determine whether there is another.
+  pub(crate) fn search_text(
+    &self,
+    query: &str,
+    page_size: usize,
+    page_index: usize,
+  ) -> Result<(Vec<InscriptionId>, bool)> {
+    let words = text::words(query);
+
+    if words.len() > text::MAX_QUERY_WORDS {
+      bail!(
+        "search query has {} words, but at most {} are allowed",
+        words.len(),
+        text::MAX_QUERY_WORDS
+      );
+    }
+
+    if words.is_empty() {
+      return Ok((Vec::new(), false));
+    }
+
+    let rtx = self.database.begin_read()?;
+
+    let sequence_number_to_inscription_id = rtx.open_table(SEQUENCE_NUMBER_TO_INSCRIPTION_ID)?;
+    let word_to_sequence_number = rtx.open_multimap_table(WORD_TO_SEQUENCE_NUMBER)?;
+
+    let mut posting_lists = words
+      .iter()
+      .map(|word| {
+        Ok(Self::posting_list(word_to_sequence_number.get(word.as_str())?, true).peekable())
+      })
+      .collect::<Result<Vec<_>>>()?;
+
+    let limit = page_index
+      .saturating_add(1)
+      .saturating_mul(page_size)
+      .saturating_add(1);
+
+    let mut matches = Vec::new();
+
+    'outer: while matches.len() < limit {
+      let mut candidate = u64::MAX;
+
+      for posting_list in &mut posting_lists {
+        match posting_list.peek() {
+          Some(Ok(sequence_number)) => candidate = candidate.min(*sequence_number),
+          Some(Err(_)) => return Err(posting_list.next().unwrap().unwrap_err()),
+          None => break 'outer,
+        }
+      }
+
+      let mut matched = true;
+
+      for posting_list in &mut posting_lists {
+        while posting_list
+          .next_if(|result| matches!(result, Ok(sequence_number) if *sequence_number > candidate))
+          .is_some()
+        {}
+
+        if !matches!(posting_list.peek(), Some(Ok(sequence_number)) if *sequence_number == candidate)
+        {
+          matched = false;
+        }
+      }
+
+      if matched {
+        matches.push(candidate);
+
+        for posting_list in &mut posting_lists {
+          posting_list.next();
+        }
+      }
+    }
+
+    let mut inscriptions = matches
+      .into_iter()
+      .skip(page_index.saturating_mul(page_size))
+      .map(|sequence_number| {
+        Ok(InscriptionId::load(
+          *sequence_number_to_inscription_id
+            .get(sequence_number)?
+            .ok_or_else(|| {
+              anyhow!("could not find inscription for sequence number {sequence_number}")
+            })?
+            .value(),
+        ))
+      })
+      .collect::<Result<Vec<InscriptionId>>>()?;
+
+    let more = inscriptions.len() > page_size;
+
+    if more {
+      inscriptions.pop();
+    }
+
+    Ok((inscriptions, more))
+  }
+
+  pub(crate) fn get_inscriptions_in_block(&self, block_height: u64) -> Result<Vec<InscriptionId>> {
+    let rtx = self.database.begin_read()?;
+
+    let height_to_last_sequence_number = rtx.open_table(HEIGHT_TO_LAST_SEQUENCE_NUMBER)?;
+    let sequence_number_to_inscription_id = rtx.open_table(SEQUENCE_NUMBER_TO_INSCRIPTION_ID)?;
+
+    let Some(newest_sequence_number) = height_to_last_sequence_number
+      .get(&block_height)?
+      .map(|ag| ag.value())
+    else {
+      return Ok(Vec::new());
+    };
+
+    let oldest_sequence_number = height_to_last_sequence_number
+      .get(block_height.saturating_sub(1))?
+      .map(|ag| ag.value())
+      .unwrap_or(0);
+
+    (oldest_sequence_number..newest_sequence_number)
+      .map(|num| match sequence_number_to_inscription_id.get(&num) {
+        Ok(Some(inscription_id)) => Ok(InscriptionId::load(*inscription_id.value())),
+        Ok(None) => Err(anyhow!(
+          "could not find inscription for inscription number {num}"
+        )),
+        Err(err) => Err(anyhow!(err)),
+      })
+      .collect::<Result<Vec<InscriptionId>>>()
+  }
+
+  pub(crate) fn get_highest_paying_inscriptions_in_block(
+    &self,
+    block_height: u64,
+    n: usize,
+  ) -> Result<(Vec<InscriptionId>, usize)> {
+    let inscription_ids = self.get_inscriptions_in_block(block_height)?;
+
+    let mut inscription_to_fee: Vec<(InscriptionId, u64)> = Vec::new();
+    for id in &inscription_ids {
+      inscription_to_fee.push((
+        *id,
+        self
+          .get_inscription_entry(*id)?
+          .ok_or_else(|| anyhow!("could not get entry for inscription {id}"))?
+          .fee,
+      ));
+    }
+
+    inscription_to_fee.sort_by_key(|(_, fee)| *fee);
 
     Ok((
       inscription_to_fee
@@ -1843,95 +2658,317 @@ mod tests {
   }
 
   #[test]
-  fn find_first_sat() {
-    let context = Context::builder().arg("--index-sats").build();
-    assert_eq!(
-      context.index.find(0).unwrap().unwrap(),
-      SatPoint {
-        outpoint: "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0"
-          .parse()
-          .unwrap(),
-        offset: 0,
-      }
-    )
-  }
+  fn address_index_tracks_unspent_outputs() {
+    let context = Context::builder().arg("--index-addresses").build();
+    assert!(context.index.has_address_index());
+
+    context.mine_blocks(1);
+
+    let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Default::default())],
+      outputs: 2,
+      recipient: Some(recipient()),
+      ..Default::default()
+    });
+
+    context.mine_blocks(1);
+
+    let tx = context.index.get_transaction(txid).unwrap().unwrap();
 
-  #[test]
-  fn find_second_sat() {
-    let context = Context::builder().arg("--index-sats").build();
     assert_eq!(
-      context.index.find(1).unwrap().unwrap(),
-      SatPoint {
-        outpoint: "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0"
-          .parse()
-          .unwrap(),
-        offset: 1,
-      }
-    )
-  }
+      context.index.get_address_outputs(&recipient()).unwrap(),
+      [
+        (OutPoint::new(txid, 0), tx.output[0].value),
+        (OutPoint::new(txid, 1), tx.output[1].value)
+      ]
+    );
+
+    context.rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 1, 0, Default::default())],
+      ..Default::default()
+    });
 
-  #[test]
-  fn find_first_sat_of_second_block() {
-    let context = Context::builder().arg("--index-sats").build();
     context.mine_blocks(1);
+
     assert_eq!(
-      context.index.find(50 * COIN_VALUE).unwrap().unwrap(),
-      SatPoint {
-        outpoint: "30f2f037629c6a21c1f40ed39b9bd6278df39762d68d07f49582b23bcb23386a:0"
-          .parse()
-          .unwrap(),
-        offset: 0,
-      }
-    )
+      context.index.get_address_outputs(&recipient()).unwrap(),
+      [(OutPoint::new(txid, 1), tx.output[1].value)]
+    );
   }
 
   #[test]
-  fn find_unmined_sat() {
-    let context = Context::builder().arg("--index-sats").build();
-    assert_eq!(context.index.find(50 * COIN_VALUE).unwrap(), None);
-  }
+  fn address_index_is_disabled_by_default() {
+    let context = Context::builder().build();
+    assert!(!context.index.has_address_index());
 
-  #[test]
-  fn find_first_sat_spent_in_second_block() {
-    let context = Context::builder().arg("--index-sats").build();
     context.mine_blocks(1);
-    let spend_txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+
+    context.rpc_server.broadcast_tx(TransactionTemplate {
       inputs: &[(1, 0, 0, Default::default())],
-      fee: 0,
+      recipient: Some(recipient()),
       ..Default::default()
     });
+
     context.mine_blocks(1);
-    assert_eq!(
-      context.index.find(50 * COIN_VALUE).unwrap().unwrap(),
-      SatPoint {
-        outpoint: OutPoint::new(spend_txid, 0),
-        offset: 0,
-      }
-    )
+
+    assert!(context
+      .index
+      .get_address_outputs(&recipient())
+      .unwrap()
+      .is_empty());
   }
 
   #[test]
-  fn inscriptions_are_tracked_correctly() {
-    for context in Context::configurations() {
-      context.mine_blocks(1);
+  fn updater_sends_events_after_commit() {
+    let context = Context::builder().build();
 
-      let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
-        inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
-        ..Default::default()
-      });
-      let inscription_id = InscriptionId { txid, index: 0 };
+    let mut events = context.index.subscribe();
 
-      context.mine_blocks(1);
+    let blocks = context.mine_blocks(1);
 
-      context.index.assert_inscription_location(
+    assert_eq!(
+      events.try_recv().unwrap(),
+      Event::BlockCommitted {
+        hash: blocks[0].block_hash(),
+        height: 1,
+      }
+    );
+
+    let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      ..Default::default()
+    });
+    let inscription_id = InscriptionId { txid, index: 0 };
+
+    context.mine_blocks(1);
+
+    assert_eq!(
+      events.try_recv().unwrap(),
+      Event::InscriptionCreated {
+        block_height: 2,
         inscription_id,
-        SatPoint {
+        number: 0,
+        sat: None,
+        satpoint: SatPoint {
           outpoint: OutPoint { txid, vout: 0 },
           offset: 0,
         },
-        Some(50 * COIN_VALUE),
-      );
-    }
+      }
+    );
+
+    assert!(matches!(
+      events.try_recv().unwrap(),
+      Event::BlockCommitted { height: 2, .. }
+    ));
+
+    let send_txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 1, 0, Default::default())],
+      ..Default::default()
+    });
+
+    context.mine_blocks(1);
+
+    assert_eq!(
+      events.try_recv().unwrap(),
+      Event::InscriptionTransferred {
+        block_height: 3,
+        inscription_id,
+        new_satpoint: SatPoint {
+          outpoint: OutPoint {
+            txid: send_txid,
+            vout: 0,
+          },
+          offset: 0,
+        },
+        old_satpoint: SatPoint {
+          outpoint: OutPoint { txid, vout: 0 },
+          offset: 0,
+        },
+      }
+    );
+
+    assert!(matches!(
+      events.try_recv().unwrap(),
+      Event::BlockCommitted { height: 3, .. }
+    ));
+
+    assert!(events.try_recv().is_err());
+  }
+
+  #[test]
+  fn inscription_transfers_are_recorded() {
+    for context in Context::configurations() {
+      context.mine_blocks(1);
+
+      let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+        ..Default::default()
+      });
+      let inscription_id = InscriptionId { txid, index: 0 };
+
+      context.mine_blocks(1);
+
+      let send_txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(2, 1, 0, Default::default())],
+        ..Default::default()
+      });
+
+      context.mine_blocks(1);
+
+      let genesis = TransferEntry {
+        height: 2,
+        new_satpoint: SatPoint {
+          outpoint: OutPoint { txid, vout: 0 },
+          offset: 0,
+        },
+        old_satpoint: None,
+        txid,
+      };
+
+      let transfer = TransferEntry {
+        height: 3,
+        new_satpoint: SatPoint {
+          outpoint: OutPoint {
+            txid: send_txid,
+            vout: 0,
+          },
+          offset: 0,
+        },
+        old_satpoint: Some(genesis.new_satpoint),
+        txid: send_txid,
+      };
+
+      assert_eq!(
+        context
+          .index
+          .get_transfers_by_inscription_id_paginated(inscription_id, 100, 0)
+          .unwrap(),
+        Some((vec![genesis, transfer], false)),
+      );
+
+      assert_eq!(
+        context
+          .index
+          .get_transfers_by_inscription_id_paginated(inscription_id, 1, 0)
+          .unwrap(),
+        Some((vec![genesis], true)),
+      );
+
+      assert_eq!(
+        context
+          .index
+          .get_transfers_by_inscription_id_paginated(inscription_id, 1, 1)
+          .unwrap(),
+        Some((vec![transfer], false)),
+      );
+
+      assert_eq!(
+        context
+          .index
+          .get_transfers_by_inscription_id_paginated(
+            InscriptionId {
+              txid: send_txid,
+              index: 0,
+            },
+            100,
+            0
+          )
+          .unwrap(),
+        None,
+      );
+    }
+  }
+
+  #[test]
+  fn find_first_sat() {
+    let context = Context::builder().arg("--index-sats").build();
+    assert_eq!(
+      context.index.find(0).unwrap().unwrap(),
+      SatPoint {
+        outpoint: "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0"
+          .parse()
+          .unwrap(),
+        offset: 0,
+      }
+    )
+  }
+
+  #[test]
+  fn find_second_sat() {
+    let context = Context::builder().arg("--index-sats").build();
+    assert_eq!(
+      context.index.find(1).unwrap().unwrap(),
+      SatPoint {
+        outpoint: "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0"
+          .parse()
+          .unwrap(),
+        offset: 1,
+      }
+    )
+  }
+
+  #[test]
+  fn find_first_sat_of_second_block() {
+    let context = Context::builder().arg("--index-sats").build();
+    context.mine_blocks(1);
+    assert_eq!(
+      context.index.find(50 * COIN_VALUE).unwrap().unwrap(),
+      SatPoint {
+        outpoint: "30f2f037629c6a21c1f40ed39b9bd6278df39762d68d07f49582b23bcb23386a:0"
+          .parse()
+          .unwrap(),
+        offset: 0,
+      }
+    )
+  }
+
+  #[test]
+  fn find_unmined_sat() {
+    let context = Context::builder().arg("--index-sats").build();
+    assert_eq!(context.index.find(50 * COIN_VALUE).unwrap(), None);
+  }
+
+  #[test]
+  fn find_first_sat_spent_in_second_block() {
+    let context = Context::builder().arg("--index-sats").build();
+    context.mine_blocks(1);
+    let spend_txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Default::default())],
+      fee: 0,
+      ..Default::default()
+    });
+    context.mine_blocks(1);
+    assert_eq!(
+      context.index.find(50 * COIN_VALUE).unwrap().unwrap(),
+      SatPoint {
+        outpoint: OutPoint::new(spend_txid, 0),
+        offset: 0,
+      }
+    )
+  }
+
+  #[test]
+  fn inscriptions_are_tracked_correctly() {
+    for context in Context::configurations() {
+      context.mine_blocks(1);
+
+      let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+        ..Default::default()
+      });
+      let inscription_id = InscriptionId { txid, index: 0 };
+
+      context.mine_blocks(1);
+
+      context.index.assert_inscription_location(
+        inscription_id,
+        SatPoint {
+          outpoint: OutPoint { txid, vout: 0 },
+          offset: 0,
+        },
+        Some(50 * COIN_VALUE),
+      );
+    }
   }
 
   #[test]
@@ -4094,7 +5131,7 @@ mod tests {
   }
 
   #[test]
-  fn parents_can_be_in_preceding_input() {
+  fn collection_aggregates_children() {
     for context in Context::configurations() {
       context.mine_blocks(1);
 
@@ -4103,82 +5140,314 @@ mod tests {
         ..Default::default()
       });
 
-      context.mine_blocks(2);
+      context.mine_blocks(1);
 
       let parent_inscription_id = InscriptionId {
         txid: parent_txid,
         index: 0,
       };
 
-      let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
-        inputs: &[
-          (2, 1, 0, Default::default()),
-          (
-            3,
-            0,
-            0,
-            Inscription {
-              content_type: Some("text/plain".into()),
-              body: Some("hello".into()),
-              parent: Some(parent_inscription_id.parent_value()),
-              ..Default::default()
-            }
-            .to_witness(),
-          ),
-        ],
+      assert_eq!(
+        context.index.get_collection(parent_inscription_id).unwrap(),
+        Collection::default(),
+      );
+
+      let child = |color: &str| {
+        let mut metadata = Vec::new();
+        ciborium::into_writer(
+          &Value::Map(vec![(
+            Value::Text("color".into()),
+            Value::Text(color.into()),
+          )]),
+          &mut metadata,
+        )
+        .unwrap();
+
+        Inscription {
+          content_type: Some("text/plain".into()),
+          body: Some("hello".into()),
+          parent: Some(parent_inscription_id.parent_value()),
+          metadata: Some(metadata),
+          ..Default::default()
+        }
+        .to_witness()
+      };
+
+      let first_txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(2, 1, 0, child("red"))],
         ..Default::default()
       });
 
       context.mine_blocks(1);
 
-      let inscription_id = InscriptionId { txid, index: 0 };
+      context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(3, 1, 0, child("red"))],
+        ..Default::default()
+      });
+
+      context.mine_blocks(1);
+
+      context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(4, 1, 0, child("blue"))],
+        ..Default::default()
+      });
+
+      context.mine_blocks(1);
+
+      let collection = context.index.get_collection(parent_inscription_id).unwrap();
 
+      assert_eq!(collection.child_count, 3);
+      assert_eq!(collection.first_height, Some(3));
+      assert_eq!(collection.last_height, Some(5));
       assert_eq!(
-        context
-          .index
-          .get_inscription_entry(inscription_id)
-          .unwrap()
-          .unwrap()
-          .parent
-          .unwrap(),
-        parent_inscription_id
+        collection.traits,
+        [(
+          "color".to_string(),
+          [("blue".to_string(), 1), ("red".to_string(), 2)].into()
+        )]
+        .into(),
       );
 
+      if context.index.has_sat_index() {
+        assert_eq!(collection.floor_rarity, Some(Rarity::Uncommon));
+      } else {
+        assert_eq!(collection.floor_rarity, None);
+      }
+
       assert_eq!(
         context
           .index
-          .get_children_by_inscription_id(parent_inscription_id)
+          .get_collection(InscriptionId {
+            txid: first_txid,
+            index: 0,
+          })
           .unwrap(),
-        vec![inscription_id]
+        Collection::default(),
       );
     }
   }
 
   #[test]
-  fn parents_can_be_in_following_input() {
-    for context in Context::configurations() {
-      context.mine_blocks(1);
+  fn provenance_lists_at_most_eight_children_per_inscription() {
+    let context = Context::builder().build();
 
-      let parent_txid = context.rpc_server.broadcast_tx(TransactionTemplate {
-        inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
-        ..Default::default()
-      });
+    context.mine_blocks(1);
 
-      context.mine_blocks(2);
+    let parent_txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "parent").to_witness())],
+      ..Default::default()
+    });
 
-      let parent_inscription_id = InscriptionId {
-        txid: parent_txid,
-        index: 0,
-      };
+    context.mine_blocks(MAX_PROVENANCE_CHILDREN as u64 + 2);
 
-      let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
-        inputs: &[
-          (
-            3,
-            0,
-            0,
-            Inscription {
-              content_type: Some("text/plain".into()),
+    let parent = InscriptionId {
+      txid: parent_txid,
+      index: 0,
+    };
+
+    let child = Inscription {
+      content_type: Some("text/plain".into()),
+      body: Some("child".into()),
+      parent: Some(parent.parent_value()),
+      ..Default::default()
+    }
+    .to_witness();
+
+    let mut inputs = vec![(2, 1, 0, Default::default())];
+
+    for i in 0..=MAX_PROVENANCE_CHILDREN {
+      inputs.push((i + 3, 0, 0, child.clone()));
+    }
+
+    context.rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &inputs,
+      ..Default::default()
+    });
+
+    context.mine_blocks(1);
+
+    assert_eq!(
+      context
+        .index
+        .get_children_by_inscription_id(parent)
+        .unwrap()
+        .len(),
+      MAX_PROVENANCE_CHILDREN + 1
+    );
+
+    let output = context.index.get_provenance(parent, 16).unwrap().unwrap();
+
+    assert_eq!(output.descendants.len(), MAX_PROVENANCE_CHILDREN);
+    assert!(output.descendants_truncated);
+  }
+
+  #[test]
+  fn provenance_includes_grandparents_and_grandchildren() {
+    for context in Context::configurations() {
+      context.mine_blocks(1);
+
+      let mut ids = Vec::new();
+
+      for i in 0..4 {
+        let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+          inputs: &[(
+            i + 1,
+            if i == 0 { 0 } else { 1 },
+            0,
+            Inscription {
+              content_type: Some("text/plain".into()),
+              body: Some("hello".into()),
+              parent: ids.last().map(|id: &InscriptionId| id.parent_value()),
+              ..Default::default()
+            }
+            .to_witness(),
+          )],
+          ..Default::default()
+        });
+
+        context.mine_blocks(1);
+
+        ids.push(InscriptionId { txid, index: 0 });
+      }
+
+      assert_eq!(
+        context.index.get_provenance(ids[2], 16).unwrap().unwrap(),
+        provenance::Output {
+          ancestors: vec![ids[1], ids[0]],
+          ancestors_truncated: false,
+          descendants: vec![Descendant {
+            children: Vec::new(),
+            id: ids[3],
+            truncated: false,
+          }],
+          descendants_truncated: false,
+          inscription: ids[2],
+        }
+      );
+
+      assert_eq!(
+        context.index.get_provenance(ids[0], 2).unwrap().unwrap(),
+        provenance::Output {
+          ancestors: Vec::new(),
+          ancestors_truncated: false,
+          descendants: vec![Descendant {
+            children: vec![Descendant {
+              children: Vec::new(),
+              id: ids[2],
+              truncated: true,
+            }],
+            id: ids[1],
+            truncated: false,
+          }],
+          descendants_truncated: false,
+          inscription: ids[0],
+        }
+      );
+
+      assert_eq!(
+        context.index.get_provenance(ids[3], 1).unwrap().unwrap(),
+        provenance::Output {
+          ancestors: vec![ids[2]],
+          ancestors_truncated: true,
+          descendants: Vec::new(),
+          descendants_truncated: false,
+          inscription: ids[3],
+        }
+      );
+
+      assert_eq!(
+        context.index.get_provenance(inscription_id(1), 16).unwrap(),
+        None
+      );
+    }
+  }
+
+  #[test]
+  fn parents_can_be_in_preceding_input() {
+    for context in Context::configurations() {
+      context.mine_blocks(1);
+
+      let parent_txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+        ..Default::default()
+      });
+
+      context.mine_blocks(2);
+
+      let parent_inscription_id = InscriptionId {
+        txid: parent_txid,
+        index: 0,
+      };
+
+      let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[
+          (2, 1, 0, Default::default()),
+          (
+            3,
+            0,
+            0,
+            Inscription {
+              content_type: Some("text/plain".into()),
+              body: Some("hello".into()),
+              parent: Some(parent_inscription_id.parent_value()),
+              ..Default::default()
+            }
+            .to_witness(),
+          ),
+        ],
+        ..Default::default()
+      });
+
+      context.mine_blocks(1);
+
+      let inscription_id = InscriptionId { txid, index: 0 };
+
+      assert_eq!(
+        context
+          .index
+          .get_inscription_entry(inscription_id)
+          .unwrap()
+          .unwrap()
+          .parent
+          .unwrap(),
+        parent_inscription_id
+      );
+
+      assert_eq!(
+        context
+          .index
+          .get_children_by_inscription_id(parent_inscription_id)
+          .unwrap(),
+        vec![inscription_id]
+      );
+    }
+  }
+
+  #[test]
+  fn parents_can_be_in_following_input() {
+    for context in Context::configurations() {
+      context.mine_blocks(1);
+
+      let parent_txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+        ..Default::default()
+      });
+
+      context.mine_blocks(2);
+
+      let parent_inscription_id = InscriptionId {
+        txid: parent_txid,
+        index: 0,
+      };
+
+      let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[
+          (
+            3,
+            0,
+            0,
+            Inscription {
+              content_type: Some("text/plain".into()),
               body: Some("hello".into()),
               parent: Some(parent_inscription_id.parent_value()),
               ..Default::default()
@@ -4899,4 +6168,467 @@ mod tests {
       );
     }
   }
+
+  #[test]
+  fn inscription_entry_records_content_type_and_length() {
+    for context in Context::configurations() {
+      context.mine_blocks(1);
+
+      let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+        ..Default::default()
+      });
+
+      context.mine_blocks(1);
+
+      let entry = context
+        .index
+        .get_inscription_entry(InscriptionId { txid, index: 0 })
+        .unwrap()
+        .unwrap();
+
+      assert_eq!(entry.content_type(), Some("text/plain"));
+      assert_eq!(entry.content_length, Some(5));
+      assert_eq!(entry.charms, 0);
+
+      let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(2, 0, 0, Inscription::default().to_witness())],
+        ..Default::default()
+      });
+
+      context.mine_blocks(1);
+
+      let entry = context
+        .index
+        .get_inscription_entry(InscriptionId { txid, index: 0 })
+        .unwrap()
+        .unwrap();
+
+      assert_eq!(entry.content_type, None);
+      assert_eq!(entry.content_length, None);
+    }
+  }
+
+  #[test]
+  fn inscriptions_can_be_filtered() {
+    let context = Context::builder().arg("--index-sats").build();
+
+    context.mine_blocks(1);
+
+    let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      outputs: 2,
+      ..Default::default()
+    });
+
+    let uncommon = InscriptionId { txid, index: 0 };
+
+    context.mine_blocks(1);
+
+    let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 1, 1, envelope(&[b"ord", &[1], b"foo", &[1], b"bar"]))],
+      ..Default::default()
+    });
+
+    let cursed = InscriptionId { txid, index: 0 };
+
+    context.mine_blocks(1);
+
+    let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(3, 0, 0, inscription("text/html", "hello").to_witness())],
+      ..Default::default()
+    });
+
+    let html = InscriptionId { txid, index: 0 };
+
+    context.mine_blocks(1);
+
+    let filtered = |filter: InscriptionFilter| {
+      context
+        .index
+        .get_inscriptions_filtered(&filter, 100, 0)
+        .unwrap()
+    };
+
+    assert_eq!(
+      filtered(InscriptionFilter {
+        sat_rarity: Some(Rarity::Uncommon),
+        ..Default::default()
+      }),
+      (vec![html, uncommon], false)
+    );
+
+    assert_eq!(
+      filtered(InscriptionFilter {
+        sat_rarity: Some(Rarity::Common),
+        ..Default::default()
+      }),
+      (vec![cursed], false)
+    );
+
+    assert_eq!(
+      filtered(InscriptionFilter {
+        content_type: Some("text/".into()),
+        ..Default::default()
+      }),
+      (vec![html, uncommon], false)
+    );
+
+    assert_eq!(
+      filtered(InscriptionFilter {
+        content_type: Some("text/".into()),
+        order: Some(InscriptionOrder::Oldest),
+        ..Default::default()
+      }),
+      (vec![uncommon, html], false)
+    );
+
+    assert_eq!(
+      filtered(InscriptionFilter {
+        content_type: Some("text/plain".into()),
+        ..Default::default()
+      }),
+      (vec![uncommon], false)
+    );
+
+    assert_eq!(
+      filtered(InscriptionFilter {
+        height: Some(3),
+        ..Default::default()
+      }),
+      (vec![cursed], false)
+    );
+
+    assert_eq!(
+      filtered(InscriptionFilter {
+        height: Some(4),
+        sat_rarity: Some(Rarity::Uncommon),
+        ..Default::default()
+      }),
+      (vec![html], false)
+    );
+
+    assert_eq!(
+      filtered(InscriptionFilter {
+        content_type: Some("text/plain".into()),
+        sat_rarity: Some(Rarity::Common),
+        ..Default::default()
+      }),
+      (Vec::new(), false)
+    );
+
+    assert_eq!(
+      filtered(InscriptionFilter {
+        order: Some(InscriptionOrder::Oldest),
+        ..Default::default()
+      }),
+      (vec![uncommon, cursed, html], false)
+    );
+
+    assert_eq!(
+      context
+        .index
+        .get_inscriptions_filtered(&InscriptionFilter::default(), 1, 0)
+        .unwrap(),
+      (vec![html], true)
+    );
+
+    assert_eq!(
+      context
+        .index
+        .get_inscriptions_filtered(&InscriptionFilter::default(), 1, 2)
+        .unwrap(),
+      (vec![uncommon], false)
+    );
+  }
+
+  #[test]
+  fn text_index_matches_all_query_words() {
+    let context = Context::builder().arg("--index-text").build();
+
+    let mut ids = Vec::new();
+
+    for (i, (content_type, body)) in [
+      ("text/plain", "Hello, world!"),
+      ("application/json", "{\"hello\":\"there\"}"),
+      ("image/png", "hello"),
+    ]
+    .iter()
+    .enumerate()
+    {
+      context.mine_blocks(1);
+      let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(i + 1, 0, 0, inscription(content_type, body).to_witness())],
+        ..Default::default()
+      });
+      ids.push(InscriptionId { txid, index: 0 });
+    }
+
+    context.mine_blocks(1);
+
+    assert_eq!(
+      context.index.search_text("HELLO", 100, 0).unwrap(),
+      (vec![ids[1], ids[0]], false)
+    );
+
+    assert_eq!(
+      context.index.search_text("hello world", 100, 0).unwrap(),
+      (vec![ids[0]], false)
+    );
+
+    assert_eq!(
+      context.index.search_text("hello", 1, 1).unwrap(),
+      (vec![ids[0]], false)
+    );
+
+    assert_eq!(
+      context.index.search_text("goodbye", 100, 0).unwrap(),
+      (Vec::new(), false)
+    );
+  }
+
+  #[test]
+  fn text_search_intersects_interleaved_posting_lists() {
+    let context = Context::builder().arg("--index-text").build();
+
+    let mut ids = Vec::new();
+
+    for (i, body) in ["foo bar", "foo", "bar", "foo bar", "bar foo baz"]
+      .iter()
+      .enumerate()
+    {
+      context.mine_blocks(1);
+      let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(i + 1, 0, 0, inscription("text/plain", body).to_witness())],
+        ..Default::default()
+      });
+      ids.push(InscriptionId { txid, index: 0 });
+    }
+
+    context.mine_blocks(1);
+
+    assert_eq!(
+      context.index.search_text("foo bar", 100, 0).unwrap(),
+      (vec![ids[4], ids[3], ids[0]], false)
+    );
+
+    assert_eq!(
+      context.index.search_text("bar foo", 2, 0).unwrap(),
+      (vec![ids[4], ids[3]], true)
+    );
+
+    assert_eq!(
+      context.index.search_text("foo bar", 2, 1).unwrap(),
+      (vec![ids[0]], false)
+    );
+
+    assert_eq!(
+      context.index.search_text("foo bar baz", 100, 0).unwrap(),
+      (vec![ids[4]], false)
+    );
+
+    assert_eq!(
+      context.index.search_text("foo qux", 100, 0).unwrap(),
+      (Vec::new(), false)
+    );
+
+    assert_eq!(
+      context
+        .index
+        .search_text("a b c d e f g h i", 100, 0)
+        .unwrap_err()
+        .to_string(),
+      "search query has 9 words, but at most 8 are allowed",
+    );
+  }
+
+  #[test]
+  fn text_index_is_only_populated_with_flag() {
+    for context in Context::configurations() {
+      context.mine_blocks(1);
+
+      context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+        ..Default::default()
+      });
+
+      context.mine_blocks(1);
+
+      assert!(!context.index.has_text_index());
+      assert_eq!(
+        context.index.search_text("hello", 100, 0).unwrap(),
+        (Vec::new(), false)
+      );
+    }
+  }
+
+  #[test]
+  fn cursed_and_unbound_charms_are_set() {
+    for context in Context::configurations() {
+      context.mine_blocks(1);
+
+      let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, envelope(&[b"ord", &[4], b"foo"]))],
+        ..Default::default()
+      });
+
+      context.mine_blocks(1);
+
+      assert_eq!(
+        Charm::charms(
+          context
+            .index
+            .get_inscription_entry(InscriptionId { txid, index: 0 })
+            .unwrap()
+            .unwrap()
+            .charms
+        ),
+        [Charm::Cursed, Charm::Unbound],
+      );
+    }
+  }
+
+  #[test]
+  fn lost_charm_is_set_when_inscription_is_created_in_fee() {
+    for context in Context::configurations() {
+      context.mine_blocks(1);
+
+      let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+        fee: 50 * COIN_VALUE,
+        ..Default::default()
+      });
+
+      context.mine_blocks_with_subsidy(1, 0);
+
+      assert_eq!(
+        Charm::charms(
+          context
+            .index
+            .get_inscription_entry(InscriptionId { txid, index: 0 })
+            .unwrap()
+            .unwrap()
+            .charms
+        ),
+        [Charm::Lost],
+      );
+    }
+  }
+
+  #[test]
+  fn lost_charm_is_set_when_inscription_is_transferred_into_fee() {
+    for context in Context::configurations() {
+      context.mine_blocks(1);
+
+      let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+        ..Default::default()
+      });
+
+      let inscription_id = InscriptionId { txid, index: 0 };
+
+      context.mine_blocks(1);
+
+      assert_eq!(
+        context
+          .index
+          .get_inscription_entry(inscription_id)
+          .unwrap()
+          .unwrap()
+          .charms,
+        0
+      );
+
+      context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(2, 1, 0, Default::default())],
+        fee: 50 * COIN_VALUE,
+        ..Default::default()
+      });
+
+      context.mine_blocks_with_subsidy(1, 0);
+
+      assert_eq!(
+        Charm::charms(
+          context
+            .index
+            .get_inscription_entry(inscription_id)
+            .unwrap()
+            .unwrap()
+            .charms
+        ),
+        [Charm::Lost],
+      );
+    }
+  }
+
+  #[test]
+  fn reinscription_and_vindicated_charms_are_set() {
+    for context in Context::configurations() {
+      context.mine_blocks(1);
+      context.mine_blocks(1);
+
+      let witness = envelope(&[b"ord", &[1], b"text/plain;charset=utf-8", &[], b"bar"]);
+
+      let cursed_txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, witness.clone()), (2, 0, 0, witness.clone())],
+        outputs: 2,
+        ..Default::default()
+      });
+
+      context.mine_blocks(1);
+
+      assert_eq!(
+        Charm::charms(
+          context
+            .index
+            .get_inscription_entry(InscriptionId {
+              txid: cursed_txid,
+              index: 1,
+            })
+            .unwrap()
+            .unwrap()
+            .charms
+        ),
+        [Charm::Cursed],
+      );
+
+      let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(3, 1, 1, witness.clone())],
+        ..Default::default()
+      });
+
+      context.mine_blocks(1);
+
+      assert_eq!(
+        Charm::charms(
+          context
+            .index
+            .get_inscription_entry(InscriptionId { txid, index: 0 })
+            .unwrap()
+            .unwrap()
+            .charms
+        ),
+        [Charm::Reinscription, Charm::Vindicated],
+      );
+
+      let txid = context.rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(4, 1, 0, witness)],
+        ..Default::default()
+      });
+
+      context.mine_blocks(1);
+
+      assert_eq!(
+        Charm::charms(
+          context
+            .index
+            .get_inscription_entry(InscriptionId { txid, index: 0 })
+            .unwrap()
+            .unwrap()
+            .charms
+        ),
+        [Charm::Cursed, Charm::Reinscription],
+      );
+    }
+  }
 }
@@ -1,4 +1,8 @@
-use super::*;
+use {
+  super::*,
+  redb::{RedbValue, TypeName},
+  std::str,
+};
 
 pub(crate) trait Entry: Sized {
   type Value;
@@ -22,6 +26,43 @@ impl Entry for BlockHash {
   }
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct CollectionEntry {
+  pub(crate) child_count: u64,
+  pub(crate) first_height: u64,
+  pub(crate) floor_rarity: Option<Rarity>,
+  pub(crate) last_height: u64,
+}
+
+pub(super) type CollectionEntryValue = (
+  u64,        // child count
+  u64,        // first height
+  Option<u8>, // floor rarity
+  u64,        // last height
+);
+
+impl Entry for CollectionEntry {
+  type Value = CollectionEntryValue;
+
+  fn load((child_count, first_height, floor_rarity, last_height): Self::Value) -> Self {
+    Self {
+      child_count,
+      first_height,
+      floor_rarity: floor_rarity.and_then(|rarity| Rarity::try_from(rarity).ok()),
+      last_height,
+    }
+  }
+
+  fn store(self) -> Self::Value {
+    (
+      self.child_count,
+      self.first_height,
+      self.floor_rarity.map(u8::from),
+      self.last_height,
+    )
+  }
+}
+
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub(crate) struct RuneEntry {
   pub(crate) burned: u128,
@@ -139,6 +180,9 @@ impl Entry for RuneId {
 
 #[derive(Debug)]
 pub(crate) struct InscriptionEntry {
+  pub(crate) charms: u16,
+  pub(crate) content_length: Option<u64>,
+  pub(crate) content_type: Option<Vec<u8>>,
   pub(crate) fee: u64,
   pub(crate) height: u64,
   pub(crate) inscription_number: i64,
@@ -148,23 +192,46 @@ pub(crate) struct InscriptionEntry {
   pub(crate) timestamp: u32,
 }
 
+impl InscriptionEntry {
+  pub(crate) fn content_type(&self) -> Option<&str> {
+    str::from_utf8(self.content_type.as_ref()?).ok()
+  }
+}
+
 pub(crate) type InscriptionEntryValue = (
-  u64,         // fee
-  u64,         // height
-  i64,         // inscription number
-  ParentValue, // parent
-  u64,         // sat
-  u64,         // sequence number
-  u32,         // timestamp
+  u16,                // charms
+  Option<u64>,        // content length
+  Option<BytesValue>, // content type
+  u64,                // fee
+  u64,                // height
+  i64,                // inscription number
+  ParentValue,        // parent
+  u64,                // sat
+  u64,                // sequence number
+  u32,                // timestamp
 );
 
 impl Entry for InscriptionEntry {
-  type Value = InscriptionEntryValue;
+  type Value = <InscriptionEntryValue as RedbValue>::SelfType<'static>;
 
   fn load(
-    (fee, height, inscription_number, parent, sat, sequence_number, timestamp): InscriptionEntryValue,
+    (
+      charms,
+      content_length,
+      content_type,
+      fee,
+      height,
+      inscription_number,
+      parent,
+      sat,
+      sequence_number,
+      timestamp,
+    ): Self::Value,
   ) -> Self {
     Self {
+      charms,
+      content_length,
+      content_type,
       fee,
       height,
       inscription_number,
@@ -181,6 +248,9 @@ impl Entry for InscriptionEntry {
 
   fn store(self) -> Self::Value {
     (
+      self.charms,
+      self.content_length,
+      self.content_type,
       self.fee,
       self.height,
       self.inscription_number,
@@ -195,6 +265,39 @@ impl Entry for InscriptionEntry {
   }
 }
 
+/// Variable-length bytes which, unlike `&[u8]`, load as an owned `Vec<u8>`,
+/// so that entries containing them do not borrow from the database.
+#[derive(Debug)]
+pub(crate) struct BytesValue;
+
+impl RedbValue for BytesValue {
+  type SelfType<'a> = Vec<u8>;
+  type AsBytes<'a> = &'a [u8];
+
+  fn fixed_width() -> Option<usize> {
+    None
+  }
+
+  fn from_bytes<'a>(data: &'a [u8]) -> Vec<u8>
+  where
+    Self: 'a,
+  {
+    data.to_vec()
+  }
+
+  fn as_bytes<'a, 'b: 'a>(value: &'a Vec<u8>) -> &'a [u8]
+  where
+    Self: 'a,
+    Self: 'b,
+  {
+    value
+  }
+
+  fn type_name() -> TypeName {
+    TypeName::new("ord::BytesValue")
+  }
+}
+
 pub(super) type InscriptionIdValue = [u8; 36];
 
 impl Entry for InscriptionId {
@@ -345,6 +448,42 @@ impl Entry for SatRange {
   }
 }
 
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub(crate) struct TransferEntry {
+  pub(crate) height: u64,
+  pub(crate) new_satpoint: SatPoint,
+  pub(crate) old_satpoint: Option<SatPoint>,
+  pub(crate) txid: Txid,
+}
+
+pub(super) type TransferEntryValue = [u8; 128];
+
+impl Entry for TransferEntry {
+  type Value = TransferEntryValue;
+
+  fn load(value: Self::Value) -> Self {
+    let old_satpoint: SatPointValue = value[52..96].try_into().unwrap();
+
+    Self {
+      height: u64::from_le_bytes(value[0..8].try_into().unwrap()),
+      new_satpoint: SatPoint::load(value[8..52].try_into().unwrap()),
+      old_satpoint: (old_satpoint != [0; 44]).then(|| SatPoint::load(old_satpoint)),
+      txid: Txid::load(value[96..128].try_into().unwrap()),
+    }
+  }
+
+  fn store(self) -> Self::Value {
+    let mut value = [0; 128];
+    value[0..8].copy_from_slice(&self.height.to_le_bytes());
+    value[8..52].copy_from_slice(&self.new_satpoint.store());
+    if let Some(old_satpoint) = self.old_satpoint {
+      value[52..96].copy_from_slice(&old_satpoint.store());
+    }
+    value[96..128].copy_from_slice(&self.txid.store());
+    value
+  }
+}
+
 pub(super) type TxidValue = [u8; 32];
 
 impl Entry for Txid {
@@ -363,6 +502,44 @@ impl Entry for Txid {
 mod tests {
   use super::*;
 
+  #[test]
+  fn collection_entry() {
+    let entry = CollectionEntry {
+      child_count: 3,
+      first_height: 1,
+      floor_rarity: None,
+      last_height: 2,
+    };
+
+    assert_eq!(CollectionEntry::load(entry.store()), entry);
+
+    let entry = CollectionEntry {
+      floor_rarity: Some(Rarity::Uncommon),
+      ..entry
+    };
+
+    assert_eq!(CollectionEntry::load(entry.store()), entry);
+  }
+
+  #[test]
+  fn transfer_entry() {
+    let entry = TransferEntry {
+      height: 1,
+      new_satpoint: satpoint(1, 2),
+      old_satpoint: None,
+      txid: txid(1),
+    };
+
+    assert_eq!(TransferEntry::load(entry.store()), entry);
+
+    let entry = TransferEntry {
+      old_satpoint: Some(satpoint(2, 3)),
+      ..entry
+    };
+
+    assert_eq!(TransferEntry::load(entry.store()), entry);
+  }
+
   #[test]
   fn parent_entry() {
     let inscription_id: Option<InscriptionId> = None;
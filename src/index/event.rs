@@ -0,0 +1,55 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum Event {
+  BlockCommitted {
+    hash: BlockHash,
+    height: u64,
+  },
+  InscriptionCreated {
+    block_height: u64,
+    inscription_id: InscriptionId,
+    number: i64,
+    sat: Option<Sat>,
+    satpoint: SatPoint,
+  },
+  InscriptionTransferred {
+    block_height: u64,
+    inscription_id: InscriptionId,
+    new_satpoint: SatPoint,
+    old_satpoint: SatPoint,
+  },
+  RuneEtched {
+    block_height: u64,
+    rune: Rune,
+    txid: Txid,
+  },
+}
+
+impl Event {
+  pub(crate) fn name(&self) -> &'static str {
+    match self {
+      Self::BlockCommitted { .. } => "block_committed",
+      Self::InscriptionCreated { .. } => "inscription_created",
+      Self::InscriptionTransferred { .. } => "inscription_transferred",
+      Self::RuneEtched { .. } => "rune_etched",
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn name_matches_serialized_type() {
+    let event = Event::RuneEtched {
+      block_height: 1,
+      rune: Rune(0),
+      txid: txid(1),
+    };
+
+    assert_eq!(serde_json::to_value(&event).unwrap()["type"], event.name());
+  }
+}
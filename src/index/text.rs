@@ -0,0 +1,79 @@
+use super::*;
+
+const MAX_WORD_LENGTH: usize = 32;
+pub(crate) const MAX_QUERY_WORDS: usize = 8;
+
+pub(crate) fn words(text: &str) -> BTreeSet<String> {
+  text
+    .split(|c: char| !c.is_alphanumeric())
+    .filter(|word| !word.is_empty() && word.len() <= MAX_WORD_LENGTH)
+    .map(str::to_lowercase)
+    .collect()
+}
+
+pub(crate) fn inscription_words(inscription: &Inscription) -> BTreeSet<String> {
+  if inscription.content_encoding().is_some() {
+    return BTreeSet::new();
+  }
+
+  let Some(content_type) = inscription.content_type() else {
+    return BTreeSet::new();
+  };
+
+  if !content_type.starts_with("text/") && !content_type.starts_with("application/json") {
+    return BTreeSet::new();
+  }
+
+  let Some(body) = inscription.body() else {
+    return BTreeSet::new();
+  };
+
+  words(&String::from_utf8_lossy(body))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn words_are_lowercased_and_deduplicated() {
+    assert_eq!(
+      words("Hello, hello world! {\"p\":\"brc-20\"}"),
+      ["20", "brc", "hello", "p", "world"]
+        .into_iter()
+        .map(str::to_string)
+        .collect()
+    );
+  }
+
+  #[test]
+  fn long_words_are_ignored() {
+    assert_eq!(
+      words(&format!("foo {}", "a".repeat(MAX_WORD_LENGTH + 1))),
+      ["foo".to_string()].into()
+    );
+  }
+
+  #[test]
+  fn only_text_and_json_inscriptions_are_indexed() {
+    assert_eq!(
+      inscription_words(&inscription("text/markdown", "# Foo")),
+      ["foo".to_string()].into()
+    );
+    assert_eq!(
+      inscription_words(&inscription("application/json", "{\"foo\":1}")),
+      ["1".to_string(), "foo".to_string()].into()
+    );
+    assert_eq!(
+      inscription_words(&inscription("image/png", "foo")),
+      BTreeSet::new()
+    );
+    assert_eq!(
+      inscription_words(&Inscription {
+        content_encoding: Some("br".into()),
+        ..inscription("text/plain", "foo")
+      }),
+      BTreeSet::new()
+    );
+  }
+}
@@ -31,6 +31,7 @@ impl From<Block> for BlockData {
 }
 
 pub(crate) struct Updater<'index> {
+  events: Vec<Event>,
   range_cache: HashMap<OutPointValue, Vec<u8>>,
   height: u64,
   index: &'index Index,
@@ -43,6 +44,7 @@ pub(crate) struct Updater<'index> {
 impl<'index> Updater<'_> {
   pub(crate) fn new(index: &'index Index) -> Result<Updater<'index>> {
     Ok(Updater {
+      events: Vec::new(),
       range_cache: HashMap::new(),
       height: index.block_count()?,
       index,
@@ -82,7 +84,11 @@ impl<'index> Updater<'_> {
       Some(progress_bar)
     };
 
-    let rx = Self::fetch_blocks_from(self.index, self.height, self.index.index_sats)?;
+    let rx = Self::fetch_blocks_from(
+      self.index,
+      self.height,
+      self.index.index_sats || self.index.index_addresses,
+    )?;
 
     let (mut outpoint_sender, mut value_receiver) = Self::spawn_fetcher(self.index)?;
 
@@ -159,7 +165,7 @@ impl<'index> Updater<'_> {
   fn fetch_blocks_from(
     index: &Index,
     mut height: u64,
-    index_sats: bool,
+    full_blocks: bool,
   ) -> Result<mpsc::Receiver<BlockData>> {
     let (tx, rx) = mpsc::sync_channel(32);
 
@@ -176,7 +182,7 @@ impl<'index> Updater<'_> {
         }
       }
 
-      match Self::get_block_with_retries(&client, height, index_sats, first_inscription_height) {
+      match Self::get_block_with_retries(&client, height, full_blocks, first_inscription_height) {
         Ok(Some(block)) => {
           if let Err(err) = tx.send(block.into()) {
             log::info!("Block receiver disconnected: {err}");
@@ -198,7 +204,7 @@ impl<'index> Updater<'_> {
   fn get_block_with_retries(
     client: &Client,
     height: u64,
-    index_sats: bool,
+    full_blocks: bool,
     first_inscription_height: u64,
   ) -> Result<Option<Block>> {
     let mut errors = 0;
@@ -209,7 +215,7 @@ impl<'index> Updater<'_> {
         .and_then(|option| {
           option
             .map(|hash| {
-              if index_sats || height >= first_inscription_height {
+              if full_blocks || height >= first_inscription_height {
                 Ok(client.get_block(&hash)?)
               } else {
                 Ok(Block {
@@ -376,6 +382,12 @@ impl<'index> Updater<'_> {
       }
     }
 
+    let mut content_hash_to_inscription_count =
+      wtx.open_table(CONTENT_HASH_TO_INSCRIPTION_COUNT)?;
+    let mut content_hash_to_sequence_number =
+      wtx.open_multimap_table(CONTENT_HASH_TO_SEQUENCE_NUMBER)?;
+    let mut content_type_to_sequence_number =
+      wtx.open_multimap_table(CONTENT_TYPE_TO_SEQUENCE_NUMBER)?;
     let mut height_to_block_hash = wtx.open_table(HEIGHT_TO_BLOCK_HASH)?;
     let mut height_to_last_sequence_number = wtx.open_table(HEIGHT_TO_LAST_SEQUENCE_NUMBER)?;
     let mut home_inscriptions = wtx.open_table(HOME_INSCRIPTIONS)?;
@@ -385,10 +397,21 @@ impl<'index> Updater<'_> {
     let mut inscription_id_to_satpoint = wtx.open_table(INSCRIPTION_ID_TO_SATPOINT)?;
     let mut inscription_number_to_inscription_id =
       wtx.open_table(INSCRIPTION_NUMBER_TO_INSCRIPTION_ID)?;
+    let mut metaprotocol_to_inscription_count =
+      wtx.open_table(METAPROTOCOL_TO_INSCRIPTION_COUNT)?;
+    let mut metaprotocol_to_sequence_number =
+      wtx.open_multimap_table(METAPROTOCOL_TO_SEQUENCE_NUMBER)?;
+    let mut parent_to_collection_entry = wtx.open_table(PARENT_TO_COLLECTION_ENTRY)?;
+    let mut parent_trait_to_count = wtx.open_table(PARENT_TRAIT_TO_COUNT)?;
+    let mut sat_rarity_to_sequence_number =
+      wtx.open_multimap_table(SAT_RARITY_TO_SEQUENCE_NUMBER)?;
     let mut sat_to_inscription_id = wtx.open_multimap_table(SAT_TO_INSCRIPTION_ID)?;
     let mut satpoint_to_inscription_id = wtx.open_multimap_table(SATPOINT_TO_INSCRIPTION_ID)?;
+    let mut sequence_number_and_index_to_transfer =
+      wtx.open_table(SEQUENCE_NUMBER_AND_INDEX_TO_TRANSFER)?;
     let mut sequence_number_to_inscription_id =
       wtx.open_table(SEQUENCE_NUMBER_TO_INSCRIPTION_ID)?;
+    let mut word_to_sequence_number = wtx.open_multimap_table(WORD_TO_SEQUENCE_NUMBER)?;
     let mut statistic_to_count = wtx.open_table(STATISTIC_TO_COUNT)?;
 
     let mut lost_sats = statistic_to_count
@@ -420,9 +443,15 @@ impl<'index> Updater<'_> {
 
     let home_inscription_count = home_inscriptions.len()?;
 
+    let mut events = Vec::new();
+
     let mut inscription_updater = InscriptionUpdater {
       blessed_inscription_count,
+      content_hash_to_inscription_count: &mut content_hash_to_inscription_count,
+      content_hash_to_sequence_number: &mut content_hash_to_sequence_number,
+      content_type_to_sequence_number: &mut content_type_to_sequence_number,
       cursed_inscription_count,
+      events: &mut events,
       flotsam: Vec::new(),
       height: self.height,
       home_inscription_count,
@@ -432,16 +461,27 @@ impl<'index> Updater<'_> {
       id_to_satpoint: &mut inscription_id_to_satpoint,
       inscription_number_to_id: &mut inscription_number_to_inscription_id,
       lost_sats,
+      metaprotocol_to_inscription_count: &mut metaprotocol_to_inscription_count,
+      metaprotocol_to_sequence_number: &mut metaprotocol_to_sequence_number,
       next_sequence_number,
       outpoint_to_value: &mut outpoint_to_value,
+      parent_to_collection_entry: &mut parent_to_collection_entry,
+      parent_trait_to_count: &mut parent_trait_to_count,
       reward: Height(self.height).subsidy(),
+      sat_rarity_to_sequence_number: &mut sat_rarity_to_sequence_number,
       sat_to_inscription_id: &mut sat_to_inscription_id,
       satpoint_to_id: &mut satpoint_to_inscription_id,
       sequence_number_to_id: &mut sequence_number_to_inscription_id,
+      sequence_number_to_transfer: &mut sequence_number_and_index_to_transfer,
       timestamp: block.header.time,
       unbound_inscriptions,
       value_cache,
       value_receiver,
+      word_to_sequence_number: if self.index.index_text {
+        Some(&mut word_to_sequence_number)
+      } else {
+        None
+      },
     };
 
     if self.index.index_sats {
@@ -571,16 +611,19 @@ impl<'index> Updater<'_> {
 
     if index.index_runes {
       let mut outpoint_to_rune_balances = wtx.open_table(OUTPOINT_TO_RUNE_BALANCES)?;
+      let mut rune_id_to_outpoints = wtx.open_multimap_table(RUNE_ID_TO_OUTPOINTS)?;
       let mut rune_id_to_rune_entry = wtx.open_table(RUNE_ID_TO_RUNE_ENTRY)?;
       let mut rune_to_rune_id = wtx.open_table(RUNE_TO_RUNE_ID)?;
       let mut inscription_id_to_rune = wtx.open_table(INSCRIPTION_ID_TO_RUNE)?;
       let mut transaction_id_to_rune = wtx.open_table(TRANSACTION_ID_TO_RUNE)?;
       let mut rune_updater = RuneUpdater::new(
+        &mut events,
         self.height,
         &mut rune_id_to_rune_entry,
         &inscription_id_to_inscription_entry,
         &mut inscription_id_to_rune,
         &mut outpoint_to_rune_balances,
+        &mut rune_id_to_outpoints,
         &mut rune_to_rune_id,
         &mut statistic_to_count,
         block.header.time,
@@ -591,8 +634,30 @@ impl<'index> Updater<'_> {
       }
     }
 
+    if index.index_addresses {
+      let mut address_to_outpoints = wtx.open_multimap_table(ADDRESS_TO_OUTPOINTS)?;
+      let mut outpoint_to_tx_out = wtx.open_table(OUTPOINT_TO_TX_OUT)?;
+      let chain = index.options.chain();
+      for (tx, txid) in &block.txdata {
+        Self::index_transaction_addresses(
+          chain,
+          tx,
+          *txid,
+          &mut address_to_outpoints,
+          &mut outpoint_to_tx_out,
+        )?;
+      }
+    }
+
     height_to_block_hash.insert(&self.height, &block.header.block_hash().store())?;
 
+    events.push(Event::BlockCommitted {
+      hash: block.header.block_hash(),
+      height: self.height,
+    });
+
+    self.events.extend(events);
+
     self.height += 1;
     self.outputs_traversed += outputs_in_block;
 
@@ -670,6 +735,39 @@ impl<'index> Updater<'_> {
     Ok(())
   }
 
+  fn index_transaction_addresses(
+    chain: Chain,
+    tx: &Transaction,
+    txid: Txid,
+    address_to_outpoints: &mut MultimapTable<&[u8], &OutPointValue>,
+    outpoint_to_tx_out: &mut Table<&OutPointValue, (&[u8], u64)>,
+  ) -> Result {
+    for input in &tx.input {
+      let outpoint = input.previous_output.store();
+      if let Some(tx_out) = outpoint_to_tx_out.remove(&outpoint)? {
+        address_to_outpoints.remove(tx_out.value().0, &outpoint)?;
+      }
+    }
+
+    for (vout, output) in tx.output.iter().enumerate() {
+      // only outputs with a standard address can be looked up
+      if chain.address_from_script(&output.script_pubkey).is_err() {
+        continue;
+      }
+
+      let outpoint = OutPoint {
+        vout: vout.try_into().unwrap(),
+        txid,
+      }
+      .store();
+
+      address_to_outpoints.insert(output.script_pubkey.as_bytes(), &outpoint)?;
+      outpoint_to_tx_out.insert(&outpoint, (output.script_pubkey.as_bytes(), output.value))?;
+    }
+
+    Ok(())
+  }
+
   fn commit(&mut self, wtx: WriteTransaction, value_cache: HashMap<OutPoint, u64>) -> Result {
     log::info!(
       "Committing at block height {}, {} outputs traversed, {} in map, {} cached",
@@ -713,6 +811,11 @@ impl<'index> Updater<'_> {
 
     Reorg::update_savepoints(self.index, self.height)?;
 
+    for event in self.events.drain(..) {
+      // sending only fails when there are no subscribers
+      self.index.event_sender.send(event).ok();
+    }
+
     Ok(())
   }
 }
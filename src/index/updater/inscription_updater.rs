@@ -10,12 +10,20 @@ pub(super) struct Flotsam {
 #[derive(Debug, Clone)]
 enum Origin {
   New {
+    content_hash: Option<sha256::Hash>,
+    content_length: Option<u64>,
+    content_type: Option<Vec<u8>>,
     cursed: bool,
     fee: u64,
     hidden: bool,
+    metaprotocol: Option<String>,
     parent: Option<InscriptionId>,
     pointer: Option<u64>,
+    reinscription: bool,
+    traits: Vec<(String, String)>,
     unbound: bool,
+    vindicated: bool,
+    words: BTreeSet<String>,
   },
   Old {
     old_satpoint: SatPoint,
@@ -23,6 +31,7 @@ enum Origin {
 }
 
 pub(super) struct InscriptionUpdater<'a, 'db, 'tx> {
+  pub(super) events: &'a mut Vec<Event>,
   pub(super) flotsam: Vec<Flotsam>,
   pub(super) height: u64,
   pub(super) home_inscription_count: u64,
@@ -35,13 +44,26 @@ pub(super) struct InscriptionUpdater<'a, 'db, 'tx> {
   pub(super) id_to_entry:
     &'a mut Table<'db, 'tx, &'static InscriptionIdValue, InscriptionEntryValue>,
   pub(super) lost_sats: u64,
+  pub(super) content_hash_to_inscription_count: &'a mut Table<'db, 'tx, &'static [u8; 32], u64>,
+  pub(super) content_hash_to_sequence_number:
+    &'a mut MultimapTable<'db, 'tx, &'static [u8; 32], u64>,
+  pub(super) content_type_to_sequence_number: &'a mut MultimapTable<'db, 'tx, &'static str, u64>,
+  pub(super) metaprotocol_to_inscription_count: &'a mut Table<'db, 'tx, &'static str, u64>,
+  pub(super) metaprotocol_to_sequence_number: &'a mut MultimapTable<'db, 'tx, &'static str, u64>,
   pub(super) cursed_inscription_count: u64,
   pub(super) blessed_inscription_count: u64,
   pub(super) next_sequence_number: u64,
   pub(super) inscription_number_to_id: &'a mut Table<'db, 'tx, i64, &'static InscriptionIdValue>,
   pub(super) sequence_number_to_id: &'a mut Table<'db, 'tx, u64, &'static InscriptionIdValue>,
+  pub(super) sequence_number_to_transfer:
+    &'a mut Table<'db, 'tx, (u64, u32), &'static TransferEntryValue>,
   pub(super) outpoint_to_value: &'a mut Table<'db, 'tx, &'static OutPointValue, u64>,
+  pub(super) parent_to_collection_entry:
+    &'a mut Table<'db, 'tx, &'static InscriptionIdValue, CollectionEntryValue>,
+  pub(super) parent_trait_to_count:
+    &'a mut Table<'db, 'tx, (&'static InscriptionIdValue, &'static str, &'static str), u64>,
   pub(super) reward: u64,
+  pub(super) sat_rarity_to_sequence_number: &'a mut MultimapTable<'db, 'tx, u8, u64>,
   pub(super) sat_to_inscription_id:
     &'a mut MultimapTable<'db, 'tx, u64, &'static InscriptionIdValue>,
   pub(super) satpoint_to_id:
@@ -49,6 +71,7 @@ pub(super) struct InscriptionUpdater<'a, 'db, 'tx> {
   pub(super) timestamp: u32,
   pub(super) unbound_inscriptions: u64,
   pub(super) value_cache: &'a mut HashMap<OutPoint, u64>,
+  pub(super) word_to_sequence_number: Option<&'a mut MultimapTable<'db, 'tx, &'static str, u64>>,
 }
 
 impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
@@ -194,12 +217,31 @@ impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
           inscription_id,
           offset,
           origin: Origin::New {
+            content_hash: inscription.payload.content_hash(),
+            content_length: inscription
+              .payload
+              .content_length()
+              .map(|content_length| content_length.try_into().unwrap()),
+            content_type: inscription.payload.content_type.clone(),
             cursed,
             fee: 0,
             hidden: inscription.payload.hidden(),
+            metaprotocol: inscription.payload.metaprotocol().map(str::to_string),
             parent: inscription.payload.parent(),
             pointer: inscription.payload.pointer(),
+            reinscription: inscribed_offsets.contains_key(&offset),
+            traits: if inscription.payload.parent().is_some() {
+              inscription.payload.traits()
+            } else {
+              Vec::new()
+            },
             unbound,
+            vindicated: curse.is_some() && !cursed,
+            words: if self.word_to_sequence_number.is_some() {
+              text::inscription_words(&inscription.payload)
+            } else {
+              BTreeSet::new()
+            },
           },
         });
 
@@ -229,40 +271,12 @@ impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
 
     // still have to normalize over inscription size
     let total_output_value = tx.output.iter().map(|txout| txout.value).sum::<u64>();
-    let mut floating_inscriptions = floating_inscriptions
-      .into_iter()
-      .map(|flotsam| {
-        if let Flotsam {
-          inscription_id,
-          offset,
-          origin:
-            Origin::New {
-              cursed,
-              fee: _,
-              hidden,
-              parent,
-              pointer,
-              unbound,
-            },
-        } = flotsam
-        {
-          Flotsam {
-            inscription_id,
-            offset,
-            origin: Origin::New {
-              cursed,
-              fee: (total_input_value - total_output_value) / u64::from(id_counter),
-              hidden,
-              parent,
-              pointer,
-              unbound,
-            },
-          }
-        } else {
-          flotsam
-        }
-      })
-      .collect::<Vec<Flotsam>>();
+
+    for flotsam in &mut floating_inscriptions {
+      if let Origin::New { fee, .. } = &mut flotsam.origin {
+        *fee = (total_input_value - total_output_value) / u64::from(id_counter);
+      }
+    }
 
     let is_coinbase = tx
       .input
@@ -334,7 +348,7 @@ impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
         _ => new_satpoint,
       };
 
-      self.update_inscription_location(input_sat_ranges, flotsam, new_satpoint)?;
+      self.update_inscription_location(txid, input_sat_ranges, flotsam, new_satpoint)?;
     }
 
     if is_coinbase {
@@ -343,7 +357,7 @@ impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
           outpoint: OutPoint::null(),
           offset: self.lost_sats + flotsam.offset - output_value,
         };
-        self.update_inscription_location(input_sat_ranges, flotsam, new_satpoint)?;
+        self.update_inscription_location(txid, input_sat_ranges, flotsam, new_satpoint)?;
       }
       self.lost_sats += self.reward - output_value;
       Ok(())
@@ -379,24 +393,75 @@ impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
 
   fn update_inscription_location(
     &mut self,
+    txid: Txid,
     input_sat_ranges: Option<&VecDeque<(u64, u64)>>,
     flotsam: Flotsam,
     new_satpoint: SatPoint,
   ) -> Result {
     let inscription_id = flotsam.inscription_id.store();
-    let unbound = match flotsam.origin {
+
+    let satpoint = if matches!(flotsam.origin, Origin::New { unbound: true, .. }) {
+      let new_unbound_satpoint = SatPoint {
+        outpoint: unbound_outpoint(),
+        offset: self.unbound_inscriptions,
+      };
+      self.unbound_inscriptions += 1;
+      new_unbound_satpoint
+    } else {
+      new_satpoint
+    };
+
+    let event = match flotsam.origin {
       Origin::Old { old_satpoint } => {
         self.satpoint_to_id.remove_all(&old_satpoint.store())?;
 
-        false
+        let mut entry = InscriptionEntry::load(
+          self
+            .id_to_entry
+            .get(&inscription_id)?
+            .ok_or_else(|| anyhow!("no entry for inscription {}", flotsam.inscription_id))?
+            .value(),
+        );
+
+        let sequence_number = entry.sequence_number;
+
+        if satpoint.outpoint == OutPoint::null() && !Charm::Lost.is_set(entry.charms) {
+          Charm::Lost.set(&mut entry.charms);
+          self.id_to_entry.insert(&inscription_id, entry.store())?;
+        }
+
+        self.record_transfer(
+          sequence_number,
+          TransferEntry {
+            height: self.height,
+            new_satpoint: satpoint,
+            old_satpoint: Some(old_satpoint),
+            txid,
+          },
+        )?;
+
+        Event::InscriptionTransferred {
+          block_height: self.height,
+          inscription_id: flotsam.inscription_id,
+          new_satpoint: satpoint,
+          old_satpoint,
+        }
       }
       Origin::New {
+        content_hash,
+        content_length,
+        content_type,
         cursed,
         fee,
+        hidden,
+        metaprotocol,
         parent,
+        pointer: _,
+        reinscription,
+        traits,
         unbound,
-        hidden,
-        ..
+        vindicated,
+        words,
       } => {
         let inscription_number = if cursed {
           let number: i64 = self.cursed_inscription_count.try_into().unwrap();
@@ -428,13 +493,93 @@ impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
           Self::calculate_sat(input_sat_ranges, flotsam.offset)
         };
 
-        if let Some(Sat(n)) = sat {
-          self.sat_to_inscription_id.insert(&n, &inscription_id)?;
+        if let Some(sat) = sat {
+          self
+            .sat_to_inscription_id
+            .insert(&sat.n(), &inscription_id)?;
+
+          self
+            .sat_rarity_to_sequence_number
+            .insert(u8::from(sat.rarity()), sequence_number)?;
+        }
+
+        if let Some(content_type) = content_type
+          .as_deref()
+          .and_then(|content_type| std::str::from_utf8(content_type).ok())
+        {
+          self
+            .content_type_to_sequence_number
+            .insert(content_type, sequence_number)?;
+        }
+
+        if let Some(content_hash) = content_hash {
+          let content_hash = content_hash.to_byte_array();
+
+          self
+            .content_hash_to_sequence_number
+            .insert(&content_hash, sequence_number)?;
+
+          let count = self
+            .content_hash_to_inscription_count
+            .get(&content_hash)?
+            .map(|count| count.value())
+            .unwrap_or_default();
+
+          self
+            .content_hash_to_inscription_count
+            .insert(&content_hash, count + 1)?;
+        }
+
+        if let Some(word_to_sequence_number) = &mut self.word_to_sequence_number {
+          for word in words {
+            word_to_sequence_number.insert(word.as_str(), sequence_number)?;
+          }
+        }
+
+        if let Some(metaprotocol) = metaprotocol {
+          self
+            .metaprotocol_to_sequence_number
+            .insert(metaprotocol.as_str(), sequence_number)?;
+
+          let count = self
+            .metaprotocol_to_inscription_count
+            .get(metaprotocol.as_str())?
+            .map(|count| count.value())
+            .unwrap_or_default();
+
+          self
+            .metaprotocol_to_inscription_count
+            .insert(metaprotocol.as_str(), count + 1)?;
+        }
+
+        let mut charms = 0;
+
+        if cursed {
+          Charm::Cursed.set(&mut charms);
+        }
+
+        if satpoint.outpoint == OutPoint::null() {
+          Charm::Lost.set(&mut charms);
+        }
+
+        if reinscription {
+          Charm::Reinscription.set(&mut charms);
+        }
+
+        if unbound {
+          Charm::Unbound.set(&mut charms);
+        }
+
+        if vindicated {
+          Charm::Vindicated.set(&mut charms);
         }
 
         self.id_to_entry.insert(
           &inscription_id,
           &InscriptionEntry {
+            charms,
+            content_length,
+            content_type,
             fee,
             height: self.height,
             inscription_number,
@@ -447,11 +592,62 @@ impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
         )?;
 
         if let Some(parent) = parent {
-          self
-            .id_to_children
-            .insert(&parent.store(), &inscription_id)?;
+          let parent = parent.store();
+
+          self.id_to_children.insert(&parent, &inscription_id)?;
+
+          let collection = self
+            .parent_to_collection_entry
+            .get(&parent)?
+            .map(|entry| CollectionEntry::load(entry.value()));
+
+          let rarity = sat.map(|sat| sat.rarity());
+
+          self.parent_to_collection_entry.insert(
+            &parent,
+            match collection {
+              Some(collection) => CollectionEntry {
+                child_count: collection.child_count + 1,
+                first_height: collection.first_height.min(self.height),
+                floor_rarity: match (collection.floor_rarity, rarity) {
+                  (Some(floor), Some(rarity)) if rarity < floor => Some(rarity),
+                  (floor, rarity) => floor.or(rarity),
+                },
+                last_height: collection.last_height.max(self.height),
+              },
+              None => CollectionEntry {
+                child_count: 1,
+                first_height: self.height,
+                floor_rarity: rarity,
+                last_height: self.height,
+              },
+            }
+            .store(),
+          )?;
+
+          for (trait_type, value) in traits {
+            let key = (&parent, trait_type.as_str(), value.as_str());
+
+            let count = self
+              .parent_trait_to_count
+              .get(key)?
+              .map(|count| count.value())
+              .unwrap_or_default();
+
+            self.parent_trait_to_count.insert(key, count + 1)?;
+          }
         }
 
+        self.record_transfer(
+          sequence_number,
+          TransferEntry {
+            height: self.height,
+            new_satpoint: satpoint,
+            old_satpoint: None,
+            txid,
+          },
+        )?;
+
         if !hidden {
           self
             .home_inscriptions
@@ -464,24 +660,39 @@ impl<'a, 'db, 'tx> InscriptionUpdater<'a, 'db, 'tx> {
           }
         }
 
-        unbound
+        Event::InscriptionCreated {
+          block_height: self.height,
+          inscription_id: flotsam.inscription_id,
+          number: inscription_number,
+          sat,
+          satpoint,
+        }
       }
     };
 
-    let satpoint = if unbound {
-      let new_unbound_satpoint = SatPoint {
-        outpoint: unbound_outpoint(),
-        offset: self.unbound_inscriptions,
-      };
-      self.unbound_inscriptions += 1;
-      new_unbound_satpoint.store()
-    } else {
-      new_satpoint.store()
-    };
+    self.events.push(event);
+
+    let satpoint = satpoint.store();
 
     self.satpoint_to_id.insert(&satpoint, &inscription_id)?;
     self.id_to_satpoint.insert(&inscription_id, &satpoint)?;
 
     Ok(())
   }
+
+  fn record_transfer(&mut self, sequence_number: u64, transfer: TransferEntry) -> Result {
+    let index = self
+      .sequence_number_to_transfer
+      .range((sequence_number, 0)..=(sequence_number, u32::MAX))?
+      .next_back()
+      .transpose()?
+      .map(|(key, _transfer)| key.value().1 + 1)
+      .unwrap_or(0);
+
+    self
+      .sequence_number_to_transfer
+      .insert((sequence_number, index), &transfer.store())?;
+
+    Ok(())
+  }
 }
@@ -18,6 +18,7 @@ struct Allocation {
 }
 
 pub(super) struct RuneUpdater<'a, 'db, 'tx> {
+  events: &'a mut Vec<Event>,
   height: u64,
   id_to_entry: &'a mut Table<'db, 'tx, RuneIdValue, RuneEntryValue>,
   inscription_id_to_inscription_entry:
@@ -25,6 +26,7 @@ pub(super) struct RuneUpdater<'a, 'db, 'tx> {
   inscription_id_to_rune: &'a mut Table<'db, 'tx, &'static InscriptionIdValue, u128>,
   minimum: Rune,
   outpoint_to_balances: &'a mut Table<'db, 'tx, &'static OutPointValue, &'static [u8]>,
+  rune_id_to_outpoints: &'a mut MultimapTable<'db, 'tx, RuneIdValue, &'static OutPointValue>,
   rune_to_id: &'a mut Table<'db, 'tx, u128, RuneIdValue>,
   runes: u64,
   statistic_to_count: &'a mut Table<'db, 'tx, u64, u64>,
@@ -34,6 +36,7 @@ pub(super) struct RuneUpdater<'a, 'db, 'tx> {
 
 impl<'a, 'db, 'tx> RuneUpdater<'a, 'db, 'tx> {
   pub(super) fn new(
+    events: &'a mut Vec<Event>,
     height: u64,
     id_to_entry: &'a mut Table<'db, 'tx, RuneIdValue, RuneEntryValue>,
     inscription_id_to_inscription_entry: &'a Table<
@@ -44,6 +47,7 @@ impl<'a, 'db, 'tx> RuneUpdater<'a, 'db, 'tx> {
     >,
     inscription_id_to_rune: &'a mut Table<'db, 'tx, &'static InscriptionIdValue, u128>,
     outpoint_to_balances: &'a mut Table<'db, 'tx, &'static OutPointValue, &'static [u8]>,
+    rune_id_to_outpoints: &'a mut MultimapTable<'db, 'tx, RuneIdValue, &'static OutPointValue>,
     rune_to_id: &'a mut Table<'db, 'tx, u128, RuneIdValue>,
     statistic_to_count: &'a mut Table<'db, 'tx, u64, u64>,
     timestamp: u32,
@@ -54,12 +58,14 @@ impl<'a, 'db, 'tx> RuneUpdater<'a, 'db, 'tx> {
       .map(|x| x.value())
       .unwrap_or(0);
     Ok(Self {
+      events,
       height,
       id_to_entry,
       inscription_id_to_inscription_entry,
       inscription_id_to_rune,
       minimum: Rune::minimum_at_height(Height(height)),
       outpoint_to_balances,
+      rune_id_to_outpoints,
       rune_to_id,
       runes,
       statistic_to_count,
@@ -88,6 +94,10 @@ impl<'a, 'db, 'tx> RuneUpdater<'a, 'db, 'tx> {
           let (balance, len) = varint::decode(&buffer[i..])?;
           i += len;
           *unallocated.entry(id).or_default() += balance;
+          self.rune_id_to_outpoints.remove(
+            RuneId::try_from(id).unwrap().store(),
+            &input.previous_output.store(),
+          )?;
         }
       }
     }
@@ -312,6 +322,12 @@ impl<'a, 'db, 'tx> RuneUpdater<'a, 'db, 'tx> {
             .inscription_id_to_rune
             .insert(&inscription_id.store(), rune.0)?;
         }
+
+        self.events.push(Event::RuneEtched {
+          block_height: self.height,
+          rune,
+          txid,
+        });
       }
     }
 
@@ -359,19 +375,23 @@ impl<'a, 'db, 'tx> RuneUpdater<'a, 'db, 'tx> {
       // Sort balances by id so tests can assert balances in a fixed order
       balances.sort();
 
+      let outpoint = OutPoint {
+        txid,
+        vout: vout.try_into().unwrap(),
+      }
+      .store();
+
       for (id, balance) in balances {
         varint::encode_to_vec(id, &mut buffer);
         varint::encode_to_vec(balance, &mut buffer);
+        self
+          .rune_id_to_outpoints
+          .insert(RuneId::try_from(id).unwrap().store(), &outpoint)?;
       }
 
-      self.outpoint_to_balances.insert(
-        &OutPoint {
-          txid,
-          vout: vout.try_into().unwrap(),
-        }
-        .store(),
-        buffer.as_slice(),
-      )?;
+      self
+        .outpoint_to_balances
+        .insert(&outpoint, buffer.as_slice())?;
     }
 
     // increment entries with burned runes
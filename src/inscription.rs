@@ -8,7 +8,7 @@ use {
     ScriptBuf,
   },
   io::Cursor,
-  std::str,
+  std::{io::Read, str},
 };
 
 #[derive(Debug, PartialEq, Clone)]
@@ -26,7 +26,9 @@ pub(crate) enum Curse {
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Eq, Default)]
 pub struct Inscription {
   pub body: Option<Vec<u8>>,
+  pub content_encoding: Option<Vec<u8>>,
   pub content_type: Option<Vec<u8>>,
+  pub delegate: Option<Vec<u8>>,
   pub duplicate_field: bool,
   pub incomplete_field: bool,
   pub metadata: Option<Vec<u8>>,
@@ -37,6 +39,8 @@ pub struct Inscription {
 }
 
 impl Inscription {
+  pub(crate) const MAX_DECODED_BODY_LEN: usize = 4 * 1024 * 1024;
+
   #[cfg(test)]
   pub(crate) fn new(content_type: Option<Vec<u8>>, body: Option<Vec<u8>>) -> Self {
     Self {
@@ -48,15 +52,56 @@ impl Inscription {
 
   pub(crate) fn from_file(
     chain: Chain,
-    path: impl AsRef<Path>,
+    path: Option<&Path>,
+    delegate: Option<InscriptionId>,
     parent: Option<InscriptionId>,
     pointer: Option<u64>,
     metaprotocol: Option<String>,
     metadata: Option<Vec<u8>>,
+    compress: bool,
   ) -> Result<Self, Error> {
-    let path = path.as_ref();
+    let mut inscription = Self {
+      delegate: delegate.map(|id| id.value()),
+      metadata,
+      metaprotocol: metaprotocol.map(|metaprotocol| metaprotocol.into_bytes()),
+      parent: parent.map(|id| id.parent_value()),
+      pointer: pointer.map(Self::pointer_value),
+      ..Default::default()
+    };
+
+    let Some(path) = path else {
+      if delegate.is_none() {
+        bail!("inscription must have a file or a delegate");
+      }
+
+      return Ok(inscription);
+    };
+
+    let mut body =
+      fs::read(path).with_context(|| format!("io error reading {}", path.display()))?;
 
-    let body = fs::read(path).with_context(|| format!("io error reading {}", path.display()))?;
+    let mut content_encoding = None;
+
+    if compress {
+      let mut compressed = Vec::new();
+
+      brotli::BrotliCompress(
+        &mut body.as_slice(),
+        &mut compressed,
+        &brotli::enc::BrotliEncoderParams {
+          lgwin: 24,
+          quality: 11,
+          size_hint: body.len(),
+          ..Default::default()
+        },
+      )?;
+
+      // only use the compressed body if compression actually saves space
+      if compressed.len() < body.len() {
+        body = compressed;
+        content_encoding = Some("br".as_bytes().to_vec());
+      }
+    }
 
     if let Some(limit) = chain.inscription_content_size_limit() {
       let len = body.len();
@@ -65,17 +110,11 @@ impl Inscription {
       }
     }
 
-    let content_type = Media::content_type_for_path(path)?;
+    inscription.body = Some(body);
+    inscription.content_encoding = content_encoding;
+    inscription.content_type = Some(Media::content_type_for_path(path)?.into());
 
-    Ok(Self {
-      body: Some(body),
-      content_type: Some(content_type.into()),
-      metadata,
-      metaprotocol: metaprotocol.map(|metaprotocol| metaprotocol.into_bytes()),
-      parent: parent.map(|id| id.parent_value()),
-      pointer: pointer.map(Self::pointer_value),
-      ..Default::default()
-    })
+    Ok(inscription)
   }
 
   fn pointer_value(pointer: u64) -> Vec<u8> {
@@ -103,6 +142,12 @@ impl Inscription {
         .push_slice(PushBytesBuf::try_from(content_type).unwrap());
     }
 
+    if let Some(content_encoding) = self.content_encoding.clone() {
+      builder = builder
+        .push_slice(envelope::CONTENT_ENCODING_TAG)
+        .push_slice(PushBytesBuf::try_from(content_encoding).unwrap());
+    }
+
     if let Some(protocol) = self.metaprotocol.clone() {
       builder = builder
         .push_slice(envelope::METAPROTOCOL_TAG)
@@ -121,6 +166,12 @@ impl Inscription {
         .push_slice(PushBytesBuf::try_from(pointer).unwrap());
     }
 
+    if let Some(delegate) = self.delegate.clone() {
+      builder = builder
+        .push_slice(envelope::DELEGATE_TAG)
+        .push_slice(PushBytesBuf::try_from(delegate).unwrap());
+    }
+
     if let Some(metadata) = &self.metadata {
       for chunk in metadata.chunks(520) {
         builder = builder.push_slice(envelope::METADATA_TAG);
@@ -185,6 +236,45 @@ impl Inscription {
     Some(self.body()?.len())
   }
 
+  pub(crate) fn content_hash(&self) -> Option<sha256::Hash> {
+    Some(sha256::Hash::hash(self.body()?))
+  }
+
+  pub(crate) fn content_encoding(&self) -> Option<&str> {
+    str::from_utf8(self.content_encoding.as_ref()?).ok()
+  }
+
+  /// Decode body according to its content encoding, failing if the decoded
+  /// body is longer than `MAX_DECODED_BODY_LEN`, so that small compressed
+  /// bodies cannot exhaust memory.
+  pub(crate) fn decoded_body(&self) -> Result<Option<Vec<u8>>> {
+    let Some(body) = self.body() else {
+      return Ok(None);
+    };
+
+    let decoder: Box<dyn Read> = match self.content_encoding() {
+      None => return Ok(Some(body.to_vec())),
+      Some("br") => Box::new(brotli::Decompressor::new(body, 4096)),
+      Some("gzip") => Box::new(flate2::read::GzDecoder::new(body)),
+      Some(content_encoding) => bail!("unsupported content encoding `{content_encoding}`"),
+    };
+
+    let mut decoded = Vec::new();
+
+    decoder
+      .take(u64::try_from(Self::MAX_DECODED_BODY_LEN).unwrap() + 1)
+      .read_to_end(&mut decoded)?;
+
+    if decoded.len() > Self::MAX_DECODED_BODY_LEN {
+      bail!(
+        "decoded body is longer than {} bytes",
+        Self::MAX_DECODED_BODY_LEN
+      );
+    }
+
+    Ok(Some(decoded))
+  }
+
   pub(crate) fn content_type(&self) -> Option<&str> {
     str::from_utf8(self.content_type.as_ref()?).ok()
   }
@@ -193,13 +283,67 @@ impl Inscription {
     ciborium::from_reader(Cursor::new(self.metadata.as_ref()?)).ok()
   }
 
+  pub(crate) fn traits(&self) -> Vec<(String, String)> {
+    let Some(Value::Map(metadata)) = self.metadata() else {
+      return Vec::new();
+    };
+
+    let attributes = metadata.iter().find_map(|(key, value)| match (key, value) {
+      (Value::Text(key), Value::Array(attributes)) if key == "attributes" => Some(attributes),
+      _ => None,
+    });
+
+    match attributes {
+      Some(attributes) => attributes
+        .iter()
+        .filter_map(|attribute| {
+          let Value::Map(attribute) = attribute else {
+            return None;
+          };
+
+          let field = |name: &str| {
+            attribute.iter().find_map(|(key, value)| match key {
+              Value::Text(key) if key == name => Self::trait_value(value),
+              _ => None,
+            })
+          };
+
+          Some((field("trait_type")?, field("value")?))
+        })
+        .collect(),
+      None => metadata
+        .iter()
+        .filter_map(|(key, value)| match key {
+          Value::Text(key) => Some((key.clone(), Self::trait_value(value)?)),
+          _ => None,
+        })
+        .collect(),
+    }
+  }
+
+  fn trait_value(value: &Value) -> Option<String> {
+    match value {
+      Value::Bool(x) => Some(x.to_string()),
+      Value::Float(x) => Some(x.to_string()),
+      Value::Integer(x) => Some(i128::from(*x).to_string()),
+      Value::Text(x) => Some(x.clone()),
+      _ => None,
+    }
+  }
+
   pub(crate) fn metaprotocol(&self) -> Option<&str> {
     str::from_utf8(self.metaprotocol.as_ref()?).ok()
   }
 
+  pub(crate) fn delegate(&self) -> Option<InscriptionId> {
+    Self::inscription_id_field(self.delegate.as_ref()?)
+  }
+
   pub(crate) fn parent(&self) -> Option<InscriptionId> {
-    let value = self.parent.as_ref()?;
+    Self::inscription_id_field(self.parent.as_ref()?)
+  }
 
+  fn inscription_id_field(value: &[u8]) -> Option<InscriptionId> {
     if value.len() < Txid::LEN {
       return None;
     }
@@ -616,6 +760,93 @@ mod tests {
     );
   }
 
+  #[test]
+  fn traits_are_read_from_attributes() {
+    let mut metadata = Vec::new();
+
+    ciborium::into_writer(
+      &Value::Map(vec![
+        (Value::Text("name".into()), Value::Text("foo".into())),
+        (
+          Value::Text("attributes".into()),
+          Value::Array(vec![
+            Value::Map(vec![
+              (
+                Value::Text("trait_type".into()),
+                Value::Text("color".into()),
+              ),
+              (Value::Text("value".into()), Value::Text("red".into())),
+            ]),
+            Value::Map(vec![
+              (Value::Text("trait_type".into()), Value::Text("eyes".into())),
+              (Value::Text("value".into()), Value::Integer(2.into())),
+            ]),
+            Value::Map(vec![(
+              Value::Text("trait_type".into()),
+              Value::Text("missing".into()),
+            )]),
+          ]),
+        ),
+      ]),
+      &mut metadata,
+    )
+    .unwrap();
+
+    assert_eq!(
+      Inscription {
+        metadata: Some(metadata),
+        ..Default::default()
+      }
+      .traits(),
+      [
+        ("color".to_string(), "red".to_string()),
+        ("eyes".to_string(), "2".to_string()),
+      ],
+    );
+  }
+
+  #[test]
+  fn traits_fall_back_to_top_level_scalars() {
+    let mut metadata = Vec::new();
+
+    ciborium::into_writer(
+      &Value::Map(vec![
+        (Value::Text("color".into()), Value::Text("red".into())),
+        (Value::Text("rare".into()), Value::Bool(true)),
+        (Value::Text("nested".into()), Value::Array(Vec::new())),
+        (Value::Integer(1.into()), Value::Text("ignored".into())),
+      ]),
+      &mut metadata,
+    )
+    .unwrap();
+
+    assert_eq!(
+      Inscription {
+        metadata: Some(metadata),
+        ..Default::default()
+      }
+      .traits(),
+      [
+        ("color".to_string(), "red".to_string()),
+        ("rare".to_string(), "true".to_string()),
+      ],
+    );
+  }
+
+  #[test]
+  fn traits_are_empty_without_map_metadata() {
+    assert_eq!(Inscription::default().traits(), []);
+
+    assert_eq!(
+      Inscription {
+        metadata: Some(vec![0x44, 0, 1, 2, 3]),
+        ..Default::default()
+      }
+      .traits(),
+      [],
+    );
+  }
+
   #[test]
   fn pointer_decode() {
     assert_eq!(
@@ -703,23 +934,59 @@ mod tests {
 
     write!(file, "foo").unwrap();
 
-    let inscription =
-      Inscription::from_file(Chain::Mainnet, file.path(), None, None, None, None).unwrap();
+    let inscription = Inscription::from_file(
+      Chain::Mainnet,
+      Some(file.path()),
+      None,
+      None,
+      None,
+      None,
+      None,
+      false,
+    )
+    .unwrap();
 
     assert_eq!(inscription.pointer, None);
 
-    let inscription =
-      Inscription::from_file(Chain::Mainnet, file.path(), None, Some(0), None, None).unwrap();
+    let inscription = Inscription::from_file(
+      Chain::Mainnet,
+      Some(file.path()),
+      None,
+      None,
+      Some(0),
+      None,
+      None,
+      false,
+    )
+    .unwrap();
 
     assert_eq!(inscription.pointer, Some(Vec::new()));
 
-    let inscription =
-      Inscription::from_file(Chain::Mainnet, file.path(), None, Some(1), None, None).unwrap();
+    let inscription = Inscription::from_file(
+      Chain::Mainnet,
+      Some(file.path()),
+      None,
+      None,
+      Some(1),
+      None,
+      None,
+      false,
+    )
+    .unwrap();
 
     assert_eq!(inscription.pointer, Some(vec![1]));
 
-    let inscription =
-      Inscription::from_file(Chain::Mainnet, file.path(), None, Some(256), None, None).unwrap();
+    let inscription = Inscription::from_file(
+      Chain::Mainnet,
+      Some(file.path()),
+      None,
+      None,
+      Some(256),
+      None,
+      None,
+      false,
+    )
+    .unwrap();
 
     assert_eq!(inscription.pointer, Some(vec![0, 1]));
   }
@@ -758,4 +1025,173 @@ mod tests {
     }
     .hidden());
   }
+
+  #[test]
+  fn from_file_compresses_body_with_brotli() {
+    let mut file = tempfile::Builder::new().suffix(".txt").tempfile().unwrap();
+
+    let content = "foo".repeat(1000);
+
+    write!(file, "{content}").unwrap();
+
+    let inscription = Inscription::from_file(
+      Chain::Mainnet,
+      Some(file.path()),
+      None,
+      None,
+      None,
+      None,
+      None,
+      true,
+    )
+    .unwrap();
+
+    assert_eq!(inscription.content_encoding(), Some("br"));
+    assert!(inscription.body().unwrap().len() < content.len());
+    assert_eq!(
+      inscription.decoded_body().unwrap().unwrap(),
+      content.as_bytes()
+    );
+  }
+
+  #[test]
+  fn from_file_does_not_compress_if_compression_does_not_save_space() {
+    let mut file = tempfile::Builder::new().suffix(".txt").tempfile().unwrap();
+
+    write!(file, "foo").unwrap();
+
+    let inscription = Inscription::from_file(
+      Chain::Mainnet,
+      Some(file.path()),
+      None,
+      None,
+      None,
+      None,
+      None,
+      true,
+    )
+    .unwrap();
+
+    assert_eq!(inscription.content_encoding, None);
+    assert_eq!(inscription.body().unwrap(), b"foo");
+  }
+
+  #[test]
+  fn content_encoding_is_included_in_reveal_script() {
+    assert_eq!(
+      Inscription {
+        content_encoding: Some("br".as_bytes().into()),
+        ..Default::default()
+      }
+      .to_witness(),
+      envelope(&[b"ord", &[9], b"br"]),
+    );
+  }
+
+  #[test]
+  fn decoded_body_rejects_unsupported_content_encoding() {
+    assert_eq!(
+      Inscription {
+        body: Some(Vec::new()),
+        content_encoding: Some("foo".as_bytes().into()),
+        ..Default::default()
+      }
+      .decoded_body()
+      .unwrap_err()
+      .to_string(),
+      "unsupported content encoding `foo`",
+    );
+  }
+
+  #[test]
+  fn decoded_body_decodes_gzip() {
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    std::io::Write::write_all(&mut encoder, b"foo").unwrap();
+
+    assert_eq!(
+      Inscription {
+        body: Some(encoder.finish().unwrap()),
+        content_encoding: Some("gzip".as_bytes().into()),
+        ..Default::default()
+      }
+      .decoded_body()
+      .unwrap()
+      .unwrap(),
+      b"foo",
+    );
+  }
+
+  #[test]
+  fn decoded_body_is_limited_in_length() {
+    let mut body = Vec::new();
+
+    brotli::BrotliCompress(
+      &mut vec![0; Inscription::MAX_DECODED_BODY_LEN + 1].as_slice(),
+      &mut body,
+      &Default::default(),
+    )
+    .unwrap();
+
+    assert!(body.len() < 1024);
+
+    assert_eq!(
+      Inscription {
+        body: Some(body),
+        content_encoding: Some("br".as_bytes().into()),
+        ..Default::default()
+      }
+      .decoded_body()
+      .unwrap_err()
+      .to_string(),
+      format!(
+        "decoded body is longer than {} bytes",
+        Inscription::MAX_DECODED_BODY_LEN
+      ),
+    );
+  }
+
+  #[test]
+  fn delegate_is_included_in_reveal_script() {
+    let delegate = inscription_id(1);
+
+    let inscription = Inscription {
+      delegate: Some(delegate.value()),
+      ..Default::default()
+    };
+
+    assert_eq!(
+      inscription.to_witness(),
+      envelope(&[b"ord", &[11], &delegate.value()]),
+    );
+
+    assert_eq!(inscription.delegate(), Some(delegate));
+  }
+
+  #[test]
+  fn from_file_with_delegate_and_no_file_has_no_body() {
+    let delegate = inscription_id(1);
+
+    let inscription = Inscription::from_file(
+      Chain::Mainnet,
+      None,
+      Some(delegate),
+      None,
+      None,
+      None,
+      None,
+      false,
+    )
+    .unwrap();
+
+    assert_eq!(inscription.delegate(), Some(delegate));
+    assert_eq!(inscription.body, None);
+    assert_eq!(inscription.content_type, None);
+
+    assert_eq!(
+      Inscription::from_file(Chain::Mainnet, None, None, None, None, None, None, false)
+        .unwrap_err()
+        .to_string(),
+      "inscription must have a file or a delegate",
+    );
+  }
 }
@@ -17,6 +17,10 @@ impl Default for InscriptionId {
 
 impl InscriptionId {
   pub(crate) fn parent_value(self) -> Vec<u8> {
+    self.value()
+  }
+
+  pub(crate) fn value(self) -> Vec<u8> {
     let index = self.index.to_le_bytes();
     let mut index_slice = index.as_slice();
 
@@ -16,12 +16,13 @@ use {
     blocktime::Blocktime,
     config::Config,
     decimal::Decimal,
+    decimal_sat::DecimalSat,
     degree::Degree,
     deserialize_from_str::DeserializeFromStr,
     envelope::ParsedEnvelope,
     epoch::Epoch,
     height::Height,
-    index::{Index, List, RuneEntry},
+    index::{Index, InscriptionFilter, List, RuneEntry, RuneHolder, TransferEntry},
     inscription_id::InscriptionId,
     media::Media,
     options::Options,
@@ -38,7 +39,7 @@ use {
     blockdata::constants::COIN_VALUE,
     consensus::{self, Decodable, Encodable},
     hash_types::BlockHash,
-    hashes::Hash,
+    hashes::{sha256, Hash},
     opcodes,
     script::{self, Instruction},
     Amount, Block, Network, OutPoint, Script, ScriptBuf, Sequence, Transaction, TxIn, TxOut, Txid,
@@ -79,6 +80,7 @@ use {
 };
 
 pub use crate::{
+  charm::Charm,
   fee_rate::FeeRate,
   inscription::Inscription,
   object::Object,
@@ -108,8 +110,10 @@ macro_rules! tprintln {
 mod arguments;
 mod blocktime;
 mod chain;
+pub mod charm;
 mod config;
 mod decimal;
+mod decimal_sat;
 mod degree;
 mod deserialize_from_str;
 mod envelope;
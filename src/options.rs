@@ -42,6 +42,8 @@ pub(crate) struct Options {
   pub(crate) height_limit: Option<u64>,
   #[arg(long, help = "Use index at <INDEX>.")]
   pub(crate) index: Option<PathBuf>,
+  #[arg(long, help = "Track unspent outputs by address.")]
+  pub(crate) index_addresses: bool,
   #[arg(
     long,
     help = "Track location of runes. RUNES ARE IN AN UNFINISHED PRE-ALPHA STATE AND SUBJECT TO CHANGE AT ANY TIME."
@@ -49,6 +51,11 @@ pub(crate) struct Options {
   pub(crate) index_runes_pre_alpha_i_agree_to_get_rekt: bool,
   #[arg(long, help = "Track location of all satoshis.")]
   pub(crate) index_sats: bool,
+  #[arg(
+    long,
+    help = "Index words in text and JSON inscriptions for full-text search."
+  )]
+  pub(crate) index_text: bool,
   #[arg(long, short, help = "Use regtest. Equivalent to `--chain regtest`.")]
   pub(crate) regtest: bool,
   #[arg(long, help = "Connect to Bitcoin Core RPC at <RPC_URL>.")]
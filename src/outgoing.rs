@@ -4,6 +4,7 @@ use super::*;
 pub(crate) enum Outgoing {
   Amount(Amount),
   InscriptionId(InscriptionId),
+  Rune { decimal: Decimal, rune: Rune },
   SatPoint(SatPoint),
 }
 
@@ -11,7 +12,16 @@ impl FromStr for Outgoing {
   type Err = Error;
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
-    Ok(if s.contains(':') {
+    lazy_static! {
+      static ref RUNE: Regex = Regex::new(r"^([0-9]*\.?[0-9]*):([A-Z]+)$").unwrap();
+    }
+
+    Ok(if let Some(captures) = RUNE.captures(s) {
+      Self::Rune {
+        decimal: captures[1].parse()?,
+        rune: captures[2].parse()?,
+      }
+    } else if s.contains(':') {
       Self::SatPoint(s.parse()?)
     } else if s.len() >= 66 {
       Self::InscriptionId(s.parse()?)
@@ -65,6 +75,23 @@ mod tests {
       Outgoing::Amount("0 sat".parse().unwrap()),
     );
 
+    assert_eq!(
+      "1.5:FOO".parse::<Outgoing>().unwrap(),
+      Outgoing::Rune {
+        decimal: "1.5".parse().unwrap(),
+        rune: "FOO".parse().unwrap(),
+      },
+    );
+
+    assert_eq!(
+      "100:FOO".parse::<Outgoing>().unwrap(),
+      Outgoing::Rune {
+        decimal: "100".parse().unwrap(),
+        rune: "FOO".parse().unwrap(),
+      },
+    );
+
     assert!("0".parse::<Outgoing>().is_err());
+    assert!(".:FOO".parse::<Outgoing>().is_err());
   }
 }
@@ -101,7 +101,6 @@ impl Runestone {
     }))
   }
 
-  #[cfg(test)]
   pub(crate) fn encipher(&self) -> ScriptBuf {
     let mut payload = Vec::new();
 
@@ -8,6 +8,7 @@ pub mod info;
 pub mod list;
 pub mod parse;
 mod preview;
+pub mod provenance;
 mod server;
 pub mod subsidy;
 pub mod supply;
@@ -33,6 +34,8 @@ pub(crate) enum Subcommand {
   Parse(parse::Parse),
   #[command(about = "Run an explorer server populated with inscriptions")]
   Preview(preview::Preview),
+  #[command(about = "Display an inscription's ancestors and descendants")]
+  Provenance(provenance::Provenance),
   #[command(about = "Run the explorer server")]
   Server(server::Server),
   #[command(about = "Display information about a block's subsidy")]
@@ -44,7 +47,7 @@ pub(crate) enum Subcommand {
   #[command(about = "Display satoshi traits")]
   Traits(traits::Traits),
   #[command(subcommand, about = "Wallet commands")]
-  Wallet(wallet::Wallet),
+  Wallet(Box<wallet::Wallet>),
 }
 
 impl Subcommand {
@@ -58,6 +61,7 @@ impl Subcommand {
       Self::List(list) => list.run(options),
       Self::Parse(parse) => parse.run(),
       Self::Preview(preview) => preview.run(),
+      Self::Provenance(provenance) => provenance.run(options),
       Self::Server(server) => {
         let index = Arc::new(Index::open(&options)?);
         let handle = axum_server::Handle::new();
@@ -77,25 +77,31 @@ impl Preview {
     for file in self.inscriptions {
       Arguments {
         options: options.clone(),
-        subcommand: Subcommand::Wallet(super::wallet::Wallet::Inscribe(
+        subcommand: Subcommand::Wallet(Box::new(super::wallet::Wallet::Inscribe(
           super::wallet::inscribe::Inscribe {
             batch: None,
             cbor_metadata: None,
+            check_duplicate: false,
             commit_fee_rate: None,
+            compress: false,
+            delegate: None,
             destination: None,
             dry_run: false,
             fee_rate: FeeRate::try_from(1.0).unwrap(),
             file: Some(file),
+            inputs: Vec::new(),
             json_metadata: None,
             metaprotocol: None,
             no_backup: true,
             no_limit: false,
             parent: None,
             postage: Some(TransactionBuilder::TARGET_POSTAGE),
+            psbt: false,
             reinscribe: false,
+            sat: None,
             satpoint: None,
           },
-        )),
+        ))),
       }
       .run()?;
 
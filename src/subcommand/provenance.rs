@@ -0,0 +1,42 @@
+use super::*;
+
+#[derive(Debug, Parser)]
+pub(crate) struct Provenance {
+  #[arg(help = "Show ancestors and descendants of <INSCRIPTION_ID>.")]
+  inscription_id: InscriptionId,
+  #[arg(
+    long,
+    default_value = "16",
+    help = "Walk at most <DEPTH> generations up and down."
+  )]
+  depth: usize,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+  pub ancestors: Vec<InscriptionId>,
+  pub ancestors_truncated: bool,
+  pub descendants: Vec<Descendant>,
+  pub descendants_truncated: bool,
+  pub inscription: InscriptionId,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Descendant {
+  pub children: Vec<Descendant>,
+  pub id: InscriptionId,
+  pub truncated: bool,
+}
+
+impl Provenance {
+  pub(crate) fn run(self, options: Options) -> SubcommandResult {
+    let index = Index::open(&options)?;
+
+    index.update()?;
+
+    match index.get_provenance(self.inscription_id, self.depth)? {
+      Some(output) => Ok(Box::new(output)),
+      None => Err(anyhow!("inscription {} not found", self.inscription_id)),
+    }
+  }
+}
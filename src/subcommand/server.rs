@@ -6,23 +6,31 @@ use {
   },
   super::*,
   crate::{
+    index::text,
     page_config::PageConfig,
     runes::Rune,
     templates::{
-      BlockHtml, BlockJson, BlocksHtml, ChildrenHtml, ClockSvg, HomeHtml, InputHtml,
-      InscriptionHtml, InscriptionJson, InscriptionsBlockHtml, InscriptionsHtml, InscriptionsJson,
-      OutputHtml, OutputJson, PageContent, PageHtml, PreviewAudioHtml, PreviewCodeHtml,
-      PreviewImageHtml, PreviewMarkdownHtml, PreviewModelHtml, PreviewPdfHtml, PreviewTextHtml,
-      PreviewUnknownHtml, PreviewVideoHtml, RangeHtml, RareTxt, RuneHtml, RunesHtml, SatHtml,
-      SatJson, TransactionHtml,
+      AddressHtml, AddressJson, BlockHtml, BlockJson, BlocksHtml, ChildrenHtml, ChildrenJson,
+      ClockSvg, CollectionHtml, CollectionJson, ContentHashHtml, ContentHashJson, HomeHtml,
+      InputHtml, InscriptionHistoryHtml, InscriptionHistoryJson, InscriptionHtml, InscriptionJson,
+      InscriptionRecursiveJson, InscriptionsBlockHtml, InscriptionsFilteredHtml,
+      InscriptionsFilteredJson, InscriptionsHtml, InscriptionsJson, MetaprotocolHtml,
+      MetaprotocolJson, MetaprotocolsHtml, MetaprotocolsJson, OutputHtml, OutputJson, PageContent,
+      PageHtml, PreviewAudioHtml, PreviewCodeHtml, PreviewImageHtml, PreviewMarkdownHtml,
+      PreviewModelHtml, PreviewPdfHtml, PreviewTextHtml, PreviewUnknownHtml, PreviewVideoHtml,
+      RangeHtml, RareTxt, RuneHoldersHtml, RuneHoldersJson, RuneHtml, RunesHtml, SatHtml,
+      SatInscriptionsJson, SatJson, SearchHtml, SearchJson, TransactionHtml,
     },
   },
   axum::{
     body,
-    extract::{Extension, Json, Path, Query},
+    extract::{Extension, Json, Path, Query, RawQuery},
     headers::UserAgent,
     http::{header, HeaderMap, HeaderValue, StatusCode, Uri},
-    response::{IntoResponse, Redirect, Response},
+    response::{
+      sse::{self, KeepAlive, Sse},
+      IntoResponse, Redirect, Response,
+    },
     routing::get,
     Router, TypedHeader,
   },
@@ -35,7 +43,7 @@ use {
     AcmeConfig,
   },
   std::{cmp::Ordering, str, sync::Arc},
-  tokio_stream::StreamExt,
+  tokio_stream::{wrappers::BroadcastStream, Stream, StreamExt},
   tower_http::{
     compression::CompressionLayer,
     cors::{Any, CorsLayer},
@@ -96,6 +104,16 @@ struct Search {
   query: String,
 }
 
+#[derive(Deserialize)]
+struct Page {
+  page: Option<usize>,
+}
+
+#[derive(Deserialize)]
+struct Depth {
+  depth: Option<usize>,
+}
+
 #[derive(RustEmbed)]
 #[folder = "static"]
 struct StaticAssets;
@@ -186,6 +204,7 @@ impl Server {
 
       let router = Router::new()
         .route("/", get(Self::home))
+        .route("/address/:address", get(Self::address))
         .route("/block/:query", get(Self::block))
         .route("/blockcount", get(Self::block_count))
         .route("/blockhash", get(Self::block_hash))
@@ -201,11 +220,30 @@ impl Server {
         )
         .route("/clock", get(Self::clock))
         .route("/content/:inscription_id", get(Self::content))
+        .route("/collection/:inscription_id", get(Self::collection))
+        .route(
+          "/collection/:inscription_id/:page",
+          get(Self::collection_paginated),
+        )
+        .route("/content-hash/:content_hash", get(Self::content_hash))
+        .route(
+          "/content-hash/:content_hash/:page",
+          get(Self::content_hash_paginated),
+        )
+        .route("/events", get(Self::events))
         .route("/faq", get(Self::faq))
         .route("/favicon.ico", get(Self::favicon))
         .route("/feed.xml", get(Self::feed))
         .route("/input/:block/:transaction/:input", get(Self::input))
         .route("/inscription/:inscription_query", get(Self::inscription))
+        .route(
+          "/inscription/:inscription_query/history",
+          get(Self::inscription_history),
+        )
+        .route(
+          "/inscription/:inscription_query/history/:page",
+          get(Self::inscription_history_paginated),
+        )
         .route("/inscriptions", get(Self::inscriptions))
         .route("/inscriptions/:from", get(Self::inscriptions_from))
         .route("/inscriptions/:from/:n", get(Self::inscriptions_from_n))
@@ -218,9 +256,16 @@ impl Server {
           get(Self::inscriptions_in_block_from_page),
         )
         .route("/install.sh", get(Self::install_script))
+        .route("/metaprotocol/:metaprotocol", get(Self::metaprotocol))
+        .route(
+          "/metaprotocol/:metaprotocol/:page",
+          get(Self::metaprotocol_paginated),
+        )
+        .route("/metaprotocols", get(Self::metaprotocols))
         .route("/ordinal/:sat", get(Self::ordinal))
         .route("/output/:output", get(Self::output))
         .route("/preview/:inscription_id", get(Self::preview))
+        .route("/provenance/:inscription_id", get(Self::provenance))
         .route("/r/blockhash", get(Self::block_hash_json))
         .route(
           "/r/blockhash/:height",
@@ -228,10 +273,29 @@ impl Server {
         )
         .route("/r/blockheight", get(Self::block_height))
         .route("/r/blocktime", get(Self::block_time))
+        .route("/r/children/:inscription_id", get(Self::children_recursive))
+        .route(
+          "/r/children/:inscription_id/:page",
+          get(Self::children_recursive_paginated),
+        )
+        .route(
+          "/r/inscription/:inscription_id",
+          get(Self::inscription_recursive),
+        )
         .route("/r/metadata/:inscription_id", get(Self::metadata))
+        .route("/r/sat/:sat_number", get(Self::sat_inscriptions))
+        .route(
+          "/r/sat/:sat_number/:page",
+          get(Self::sat_inscriptions_paginated),
+        )
         .route("/range/:start/:end", get(Self::range))
         .route("/rare.txt", get(Self::rare_txt))
         .route("/rune/:rune", get(Self::rune))
+        .route("/rune/:rune/holders", get(Self::rune_holders))
+        .route(
+          "/rune/:rune/holders/:page",
+          get(Self::rune_holders_paginated),
+        )
         .route("/runes", get(Self::runes))
         .route("/sat/:sat", get(Self::sat))
         .route("/search", get(Self::search_by_query))
@@ -251,9 +315,18 @@ impl Server {
           HeaderValue::from_static("max-age=31536000; includeSubDomains; preload"),
         ))
         .layer(
+          // the CORS layer replaces any `Vary` header set by handlers, and
+          // compressed and content-encoded responses depend on
+          // `Accept-Encoding`
           CorsLayer::new()
             .allow_methods([http::Method::GET])
-            .allow_origin(Any),
+            .allow_origin(Any)
+            .vary([
+              header::ORIGIN,
+              header::ACCESS_CONTROL_REQUEST_METHOD,
+              header::ACCESS_CONTROL_REQUEST_HEADERS,
+              header::ACCEPT_ENCODING,
+            ]),
         )
         .layer(CompressionLayer::new())
         .with_state(server_config);
@@ -554,6 +627,70 @@ impl Server {
     })
   }
 
+  async fn address(
+    Extension(page_config): Extension<Arc<PageConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path(DeserializeFromStr(address)): Path<DeserializeFromStr<Address<NetworkUnchecked>>>,
+    accept_json: AcceptJson,
+  ) -> ServerResult<Response> {
+    if !index.has_address_index() {
+      return Err(ServerError::NotFound(
+        "this server has no address index, run ord with `--index-addresses` to enable".into(),
+      ));
+    }
+
+    let address = address
+      .require_network(page_config.chain.network())
+      .map_err(|err| ServerError::BadRequest(err.to_string()))?;
+
+    let mut outputs = Vec::new();
+    let mut cardinal = 0;
+    let mut inscriptions = Vec::new();
+    let mut runes: BTreeMap<Rune, Pile> = BTreeMap::new();
+
+    for (outpoint, value) in index.get_address_outputs(&address)? {
+      outputs.push(outpoint);
+
+      let output_inscriptions = index.get_inscriptions_on_output(outpoint)?;
+
+      if output_inscriptions.is_empty() {
+        cardinal += value;
+      }
+
+      inscriptions.extend(output_inscriptions);
+
+      for (rune, pile) in index.get_rune_balances_for_outpoint(outpoint)? {
+        runes
+          .entry(rune)
+          .and_modify(|balance| balance.amount += pile.amount)
+          .or_insert(pile);
+      }
+    }
+
+    Ok(if accept_json.0 {
+      Json(AddressJson {
+        cardinal,
+        inscriptions,
+        outputs,
+        runes: runes
+          .into_iter()
+          .map(|(rune, pile)| (rune, pile.amount))
+          .collect(),
+      })
+      .into_response()
+    } else {
+      AddressHtml {
+        address,
+        cardinal,
+        inscriptions,
+        outputs,
+        runes: runes.into_iter().collect(),
+      }
+      .page(page_config)
+      .into_response()
+    })
+  }
+
   async fn range(
     Extension(page_config): Extension<Arc<PageConfig>>,
     Path((DeserializeFromStr(start), DeserializeFromStr(end))): Path<(
@@ -595,6 +732,55 @@ impl Server {
     Ok(RuneHtml { id, entry, parent }.page(page_config))
   }
 
+  async fn rune_holders(
+    Extension(page_config): Extension<Arc<PageConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path(DeserializeFromStr(rune)): Path<DeserializeFromStr<Rune>>,
+    accept_json: AcceptJson,
+  ) -> ServerResult<Response> {
+    Self::rune_holders_paginated(
+      Extension(page_config),
+      Extension(index),
+      Path((DeserializeFromStr(rune), 0)),
+      accept_json,
+    )
+    .await
+  }
+
+  async fn rune_holders_paginated(
+    Extension(page_config): Extension<Arc<PageConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path((DeserializeFromStr(rune), page)): Path<(DeserializeFromStr<Rune>, usize)>,
+    accept_json: AcceptJson,
+  ) -> ServerResult<Response> {
+    let (id, entry) = index.rune(rune)?.ok_or_else(|| {
+      ServerError::NotFound(if index.has_rune_index() {
+        format!("rune {rune} not found")
+      } else {
+        "tracking runes requires index created with `--index-runes-pre-alpha-i-agree-to-get-rekt` flag".into()
+      })
+    })?;
+
+    let (holders, more_holders) = index.get_rune_holders_paginated(id, 100, page)?;
+
+    let prev_page = page.checked_sub(1);
+
+    let next_page = more_holders.then_some(page + 1);
+
+    Ok(if accept_json.0 {
+      Json(RuneHoldersJson::new(holders, prev_page, next_page)).into_response()
+    } else {
+      RuneHoldersHtml {
+        entry,
+        holders,
+        prev_page,
+        next_page,
+      }
+      .page(page_config)
+      .into_response()
+    })
+  }
+
   async fn runes(
     Extension(page_config): Extension<Arc<PageConfig>>,
     Extension(index): Extension<Arc<Index>>,
@@ -607,6 +793,78 @@ impl Server {
     )
   }
 
+  async fn metaprotocols(
+    Extension(page_config): Extension<Arc<PageConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    accept_json: AcceptJson,
+  ) -> ServerResult<Response> {
+    let metaprotocols = index.get_metaprotocols()?;
+
+    Ok(if accept_json.0 {
+      Json(MetaprotocolsJson::new(metaprotocols)).into_response()
+    } else {
+      MetaprotocolsHtml { metaprotocols }
+        .page(page_config)
+        .into_response()
+    })
+  }
+
+  async fn metaprotocol(
+    Extension(page_config): Extension<Arc<PageConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path(metaprotocol): Path<String>,
+    accept_json: AcceptJson,
+  ) -> ServerResult<Response> {
+    Self::metaprotocol_paginated(
+      Extension(page_config),
+      Extension(index),
+      Path((metaprotocol, 0)),
+      accept_json,
+    )
+    .await
+  }
+
+  async fn metaprotocol_paginated(
+    Extension(page_config): Extension<Arc<PageConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path((metaprotocol, page)): Path<(String, usize)>,
+    accept_json: AcceptJson,
+  ) -> ServerResult<Response> {
+    let count = index
+      .get_metaprotocol_inscription_count(&metaprotocol)?
+      .ok_or_not_found(|| format!("metaprotocol {metaprotocol}"))?;
+
+    let (inscriptions, more) = index.get_inscriptions_filtered(
+      &InscriptionFilter {
+        metaprotocol: Some(metaprotocol.clone()),
+        ..Default::default()
+      },
+      100,
+      page,
+    )?;
+
+    Ok(if accept_json.0 {
+      Json(MetaprotocolJson {
+        count,
+        ids: inscriptions,
+        metaprotocol,
+        more,
+        page,
+      })
+      .into_response()
+    } else {
+      MetaprotocolHtml {
+        metaprotocol,
+        count,
+        inscriptions,
+        prev_page: page.checked_sub(1),
+        next_page: more.then_some(page + 1),
+      }
+      .page(page_config)
+      .into_response()
+    })
+  }
+
   async fn home(
     Extension(page_config): Extension<Arc<PageConfig>>,
     Extension(index): Extension<Arc<Index>>,
@@ -726,6 +984,54 @@ impl Server {
     Ok(Json(hex::encode(metadata)))
   }
 
+  async fn inscription_recursive(
+    Extension(index): Extension<Arc<Index>>,
+    Path(inscription_id): Path<InscriptionId>,
+  ) -> ServerResult<Json<InscriptionRecursiveJson>> {
+    let entry = index
+      .get_inscription_entry(inscription_id)?
+      .ok_or_not_found(|| format!("inscription {inscription_id}"))?;
+
+    let satpoint = index
+      .get_inscription_satpoint_by_id(inscription_id)?
+      .ok_or_not_found(|| format!("inscription {inscription_id}"))?;
+
+    Ok(Json(InscriptionRecursiveJson {
+      content_length: entry
+        .content_length
+        .map(|content_length| content_length.try_into().unwrap()),
+      content_type: entry.content_type().map(|s| s.to_string()),
+      height: entry.height,
+      id: inscription_id,
+      number: entry.inscription_number,
+      sat: entry.sat,
+      satpoint,
+      timestamp: entry.timestamp.into(),
+    }))
+  }
+
+  async fn sat_inscriptions(
+    Extension(index): Extension<Arc<Index>>,
+    Path(sat): Path<u64>,
+  ) -> ServerResult<Json<SatInscriptionsJson>> {
+    Self::sat_inscriptions_paginated(Extension(index), Path((sat, 0))).await
+  }
+
+  async fn sat_inscriptions_paginated(
+    Extension(index): Extension<Arc<Index>>,
+    Path((sat, page)): Path<(u64, usize)>,
+  ) -> ServerResult<Json<SatInscriptionsJson>> {
+    if !index.has_sat_index() {
+      return Err(ServerError::NotFound(
+        "this server has no sat index".to_string(),
+      ));
+    }
+
+    let (ids, more) = index.get_inscription_ids_by_sat_paginated(Sat(sat), 100, page)?;
+
+    Ok(Json(SatInscriptionsJson { ids, more, page }))
+  }
+
   async fn status(Extension(index): Extension<Arc<Index>>) -> (StatusCode, &'static str) {
     if index.is_unrecoverably_reorged() {
       (
@@ -741,46 +1047,71 @@ impl Server {
   }
 
   async fn search_by_query(
+    Extension(page_config): Extension<Arc<PageConfig>>,
     Extension(index): Extension<Arc<Index>>,
     Query(search): Query<Search>,
-  ) -> ServerResult<Redirect> {
-    Self::search(&index, &search.query).await
+    Query(Page { page }): Query<Page>,
+    accept_json: AcceptJson,
+  ) -> ServerResult<Response> {
+    Self::search(
+      page_config,
+      &index,
+      &search.query,
+      page.unwrap_or_default(),
+      accept_json,
+    )
+    .await
   }
 
   async fn search_by_path(
+    Extension(page_config): Extension<Arc<PageConfig>>,
     Extension(index): Extension<Arc<Index>>,
     Path(search): Path<Search>,
-  ) -> ServerResult<Redirect> {
-    Self::search(&index, &search.query).await
+    accept_json: AcceptJson,
+  ) -> ServerResult<Response> {
+    Self::search(page_config, &index, &search.query, 0, accept_json).await
   }
 
-  async fn search(index: &Index, query: &str) -> ServerResult<Redirect> {
-    Self::search_inner(index, query)
+  async fn search(
+    page_config: Arc<PageConfig>,
+    index: &Index,
+    query: &str,
+    page: usize,
+    accept_json: AcceptJson,
+  ) -> ServerResult<Response> {
+    Self::search_inner(page_config, index, query, page, accept_json)
   }
 
-  fn search_inner(index: &Index, query: &str) -> ServerResult<Redirect> {
+  fn search_inner(
+    page_config: Arc<PageConfig>,
+    index: &Index,
+    query: &str,
+    page: usize,
+    accept_json: AcceptJson,
+  ) -> ServerResult<Response> {
     lazy_static! {
       static ref HASH: Regex = Regex::new(r"^[[:xdigit:]]{64}$").unwrap();
       static ref INSCRIPTION_ID: Regex = Regex::new(r"^[[:xdigit:]]{64}i\d+$").unwrap();
       static ref OUTPOINT: Regex = Regex::new(r"^[[:xdigit:]]{64}:\d+$").unwrap();
       static ref RUNE: Regex = Regex::new(r"^[A-Z]+$").unwrap();
       static ref RUNE_ID: Regex = Regex::new(r"^[0-9]+/[0-9]+$").unwrap();
+      static ref SAT_NAME: Regex = Regex::new(r"^[a-z]+$").unwrap();
     }
 
     let query = query.trim();
 
     if HASH.is_match(query) {
       if index.block_header(query.parse().unwrap())?.is_some() {
-        Ok(Redirect::to(&format!("/block/{query}")))
+        Ok(Redirect::to(&format!("/block/{query}")).into_response())
       } else {
-        Ok(Redirect::to(&format!("/tx/{query}")))
+        Ok(Redirect::to(&format!("/tx/{query}")).into_response())
       }
     } else if OUTPOINT.is_match(query) {
-      Ok(Redirect::to(&format!("/output/{query}")))
+      Ok(Redirect::to(&format!("/output/{query}")).into_response())
     } else if INSCRIPTION_ID.is_match(query) {
-      Ok(Redirect::to(&format!("/inscription/{query}")))
+      Ok(Redirect::to(&format!("/inscription/{query}")).into_response())
     } else if RUNE.is_match(query) {
-      Ok(Redirect::to(&format!("/rune/{query}")))
+      Ok(Redirect::to(&format!("/rune/{query}")).into_response())
     } else if RUNE_ID.is_match(query) {
       let id = query
         .parse::<RuneId>()
@@ -788,9 +1119,43 @@ impl Server {
 
       let rune = index.get_rune_by_id(id)?.ok_or_not_found(|| "rune ID")?;
 
-      Ok(Redirect::to(&format!("/rune/{rune}")))
+      Ok(Redirect::to(&format!("/rune/{rune}")).into_response())
     } else {
-      Ok(Redirect::to(&format!("/sat/{query}")))
+      let sat = query.parse::<Sat>().ok();
+
+      if !index.has_text_index() || (sat.is_some() && !SAT_NAME.is_match(query)) {
+        return Ok(Redirect::to(&format!("/sat/{query}")).into_response());
+      }
+
+      if text::words(query).len() > text::MAX_QUERY_WORDS {
+        return Err(ServerError::BadRequest(format!(
+          "search query may contain at most {} words",
+          text::MAX_QUERY_WORDS
+        )));
+      }
+
+      let (inscriptions, more) = index.search_text(query, 100, page)?;
+
+      Ok(if accept_json.0 {
+        Json(SearchJson {
+          ids: inscriptions,
+          more,
+          page,
+          query: query.into(),
+        })
+        .into_response()
+      } else {
+        SearchHtml {
+          query: query.into(),
+          sat,
+          words: text::words(query).into_iter().collect(),
+          inscriptions,
+          prev_page: page.checked_sub(1),
+          next_page: more.then_some(page + 1),
+        }
+        .page(page_config)
+        .into_response()
+      })
     }
   }
 
@@ -822,6 +1187,18 @@ impl Server {
     }
   }
 
+  async fn events(
+    Extension(index): Extension<Arc<Index>>,
+  ) -> Sse<impl Stream<Item = Result<sse::Event, serde_json::Error>>> {
+    Sse::new(
+      BroadcastStream::new(index.subscribe())
+        // lagging subscribers skip missed events rather than disconnect
+        .filter_map(|event| event.ok())
+        .map(|event| sse::Event::default().event(event.name()).json_data(event)),
+    )
+    .keep_alive(KeepAlive::default())
+  }
+
   async fn feed(
     Extension(page_config): Extension<Arc<PageConfig>>,
     Extension(index): Extension<Arc<Index>>,
@@ -983,23 +1360,39 @@ impl Server {
     Extension(index): Extension<Arc<Index>>,
     Extension(config): Extension<Arc<Config>>,
     Path(inscription_id): Path<InscriptionId>,
+    request_headers: HeaderMap,
   ) -> ServerResult<Response> {
     if config.is_hidden(inscription_id) {
       return Ok(PreviewUnknownHtml.into_response());
     }
 
-    let inscription = index
+    let mut inscription = index
       .get_inscription_by_id(inscription_id)?
       .ok_or_not_found(|| format!("inscription {inscription_id}"))?;
 
+    if let Some(delegate) = inscription.delegate() {
+      if config.is_hidden(delegate) {
+        return Ok(PreviewUnknownHtml.into_response());
+      }
+
+      inscription = index
+        .get_inscription_by_id(delegate)?
+        .ok_or_not_found(|| format!("delegate {delegate}"))?
+    }
+
     Ok(
-      Self::content_response(inscription)
+      Self::content_response(inscription, &request_headers)?
         .ok_or_not_found(|| format!("inscription {inscription_id} content"))?
         .into_response(),
     )
   }
 
-  fn content_response(inscription: Inscription) -> Option<(HeaderMap, Vec<u8>)> {
+  /// Content is served with its content encoding if the client accepts it,
+  /// and is otherwise decoded before being served.
+  fn content_response(
+    inscription: Inscription,
+    request_headers: &HeaderMap,
+  ) -> ServerResult<Option<(HeaderMap, Vec<u8>)>> {
     let mut headers = HeaderMap::new();
 
     headers.insert(
@@ -1022,22 +1415,83 @@ impl Server {
       HeaderValue::from_static("max-age=31536000, immutable"),
     );
 
-    Some((headers, inscription.into_body()?))
+    let Some(content_encoding) = inscription.content_encoding() else {
+      return Ok(inscription.into_body().map(|body| (headers, body)));
+    };
+
+    if !matches!(content_encoding, "br" | "gzip") {
+      return Err(ServerError::NotAcceptable(format!(
+        "inscription content encoding `{content_encoding}` is not supported"
+      )));
+    }
+
+    // the body depends on `Accept-Encoding`, so caches must not share it
+    // between clients that accept different encodings
+    headers.insert(header::VARY, HeaderValue::from_static("Accept-Encoding"));
+
+    if Self::accepts_encoding(request_headers, content_encoding) {
+      headers.insert(
+        header::CONTENT_ENCODING,
+        HeaderValue::from_str(content_encoding).unwrap(),
+      );
+      return Ok(inscription.into_body().map(|body| (headers, body)));
+    }
+
+    let body = inscription.decoded_body().map_err(|err| {
+      ServerError::NotAcceptable(format!(
+        "inscription content encoding `{content_encoding}` is not accepted and content could not be decoded: {err}"
+      ))
+    })?;
+
+    Ok(body.map(|body| (headers, body)))
+  }
+
+  fn accepts_encoding(request_headers: &HeaderMap, encoding: &str) -> bool {
+    request_headers
+      .get_all(header::ACCEPT_ENCODING)
+      .iter()
+      .filter_map(|value| value.to_str().ok())
+      .flat_map(|value| value.split(','))
+      .any(|coding| {
+        let mut params = coding.split(';').map(str::trim);
+
+        let name = params.next().unwrap_or_default();
+
+        (name.eq_ignore_ascii_case(encoding) || name == "*")
+          && params.all(|param| {
+            param
+              .strip_prefix("q=")
+              .and_then(|q| q.parse::<f32>().ok())
+              .map(|q| q > 0.0)
+              .unwrap_or(true)
+          })
+      })
   }
 
   async fn preview(
     Extension(index): Extension<Arc<Index>>,
     Extension(config): Extension<Arc<Config>>,
     Path(inscription_id): Path<InscriptionId>,
+    request_headers: HeaderMap,
   ) -> ServerResult<Response> {
     if config.is_hidden(inscription_id) {
       return Ok(PreviewUnknownHtml.into_response());
     }
 
-    let inscription = index
+    let mut inscription = index
       .get_inscription_by_id(inscription_id)?
       .ok_or_not_found(|| format!("inscription {inscription_id}"))?;
 
+    if let Some(delegate) = inscription.delegate() {
+      if config.is_hidden(delegate) {
+        return Ok(PreviewUnknownHtml.into_response());
+      }
+
+      inscription = index
+        .get_inscription_by_id(delegate)?
+        .ok_or_not_found(|| format!("delegate {delegate}"))?
+    }
+
     match inscription.media() {
       Media::Audio => Ok(PreviewAudioHtml { inscription_id }.into_response()),
       Media::Code => Ok(
@@ -1051,7 +1505,7 @@ impl Server {
           .into_response(),
       ),
       Media::Iframe => Ok(
-        Self::content_response(inscription)
+        Self::content_response(inscription, &request_headers)?
           .ok_or_not_found(|| format!("inscription {inscription_id} content"))?
           .into_response(),
       ),
@@ -1096,12 +1550,13 @@ impl Server {
           .into_response(),
       ),
       Media::Text => {
-        let content = inscription
-          .body()
-          .ok_or_not_found(|| format!("inscription {inscription_id} content"))?;
+        let Ok(content) = inscription.decoded_body() else {
+          return Ok(PreviewUnknownHtml.into_response());
+        };
+        let content = content.ok_or_not_found(|| format!("inscription {inscription_id} content"))?;
         Ok(
           PreviewTextHtml {
-            text: str::from_utf8(content)
+            text: str::from_utf8(&content)
               .map_err(|err| anyhow!("Failed to decode {inscription_id} text: {err}"))?,
           }
           .into_response(),
@@ -1164,9 +1619,32 @@ impl Server {
 
     let rune = index.get_rune_by_inscription_id(inscription_id)?;
 
+    let provenance = if entry.parent.is_some() || !children.is_empty() {
+      index.get_provenance(inscription_id, 3)?
+    } else {
+      None
+    };
+
+    let (duplicate_count, duplicates) = match inscription.content_hash() {
+      Some(content_hash) => (
+        index
+          .get_content_hash_inscription_count(content_hash)?
+          .saturating_sub(1),
+        index
+          .get_inscriptions_by_content_hash_paginated(content_hash, 5, 0)?
+          .0
+          .into_iter()
+          .filter(|id| *id != inscription_id)
+          .take(4)
+          .collect(),
+      ),
+      None => (0, Vec::new()),
+    };
+
     Ok(if accept_json.0 {
       Json(InscriptionJson::new(
         page_config.chain,
+        entry.charms,
         children,
         entry.fee,
         entry.height,
@@ -1186,7 +1664,10 @@ impl Server {
     } else {
       InscriptionHtml {
         chain: page_config.chain,
+        charms: entry.charms,
         children,
+        duplicate_count,
+        duplicates,
         genesis_fee: entry.fee,
         genesis_height: entry.height,
         inscription,
@@ -1196,6 +1677,7 @@ impl Server {
         output,
         parent: entry.parent,
         previous,
+        provenance,
         rune,
         sat: entry.sat,
         satpoint,
@@ -1219,6 +1701,26 @@ impl Server {
     .await
   }
 
+  async fn children_recursive(
+    Extension(index): Extension<Arc<Index>>,
+    Path(inscription_id): Path<InscriptionId>,
+  ) -> ServerResult<Json<ChildrenJson>> {
+    Self::children_recursive_paginated(Extension(index), Path((inscription_id, 0))).await
+  }
+
+  async fn children_recursive_paginated(
+    Extension(index): Extension<Arc<Index>>,
+    Path((parent, page)): Path<(InscriptionId, usize)>,
+  ) -> ServerResult<Json<ChildrenJson>> {
+    index
+      .get_inscription_entry(parent)?
+      .ok_or_not_found(|| format!("inscription {parent}"))?;
+
+    let (ids, more) = index.get_children_by_inscription_id_paginated(parent, 100, page)?;
+
+    Ok(Json(ChildrenJson { ids, more, page }))
+  }
+
   async fn children_paginated(
     Extension(page_config): Extension<Arc<PageConfig>>,
     Extension(index): Extension<Arc<Index>>,
@@ -1249,36 +1751,255 @@ impl Server {
     )
   }
 
-  async fn inscriptions(
-    Extension(page_config): Extension<Arc<PageConfig>>,
-    Extension(index): Extension<Arc<Index>>,
-    accept_json: AcceptJson,
-  ) -> ServerResult<Response> {
-    Self::inscriptions_inner(page_config, index, None, 100, accept_json).await
-  }
-
-  async fn inscriptions_in_block(
+  async fn collection(
     Extension(page_config): Extension<Arc<PageConfig>>,
     Extension(index): Extension<Arc<Index>>,
-    Path(block_height): Path<u64>,
+    Path(inscription_id): Path<InscriptionId>,
     accept_json: AcceptJson,
   ) -> ServerResult<Response> {
-    Self::inscriptions_in_block_from_page(
+    Self::collection_paginated(
       Extension(page_config),
       Extension(index),
-      Path((block_height, 0)),
+      Path((inscription_id, 0)),
       accept_json,
     )
     .await
   }
 
-  async fn inscriptions_in_block_from_page(
+  async fn collection_paginated(
     Extension(page_config): Extension<Arc<PageConfig>>,
     Extension(index): Extension<Arc<Index>>,
-    Path((block_height, page)): Path<(u64, usize)>,
+    Path((parent, page)): Path<(InscriptionId, usize)>,
     accept_json: AcceptJson,
   ) -> ServerResult<Response> {
-    let inscriptions = index.get_inscriptions_in_block(block_height)?;
+    let parent_number = index
+      .get_inscription_entry(parent)?
+      .ok_or_not_found(|| format!("inscription {parent}"))?
+      .inscription_number;
+
+    let collection = index.get_collection(parent)?;
+
+    let (children, more) = index.get_children_by_inscription_id_paginated(parent, 100, page)?;
+
+    Ok(if accept_json.0 {
+      Json(CollectionJson {
+        child_count: collection.child_count,
+        first_height: collection.first_height,
+        floor_rarity: collection.floor_rarity,
+        ids: children,
+        last_height: collection.last_height,
+        more,
+        page,
+        parent,
+        parent_number,
+        traits: collection.traits,
+      })
+      .into_response()
+    } else {
+      CollectionHtml {
+        parent,
+        parent_number,
+        collection,
+        children,
+        prev_page: page.checked_sub(1),
+        next_page: more.then_some(page + 1),
+      }
+      .page(page_config)
+      .into_response()
+    })
+  }
+
+  async fn provenance(
+    Extension(index): Extension<Arc<Index>>,
+    Path(inscription_id): Path<InscriptionId>,
+    Query(depth): Query<Depth>,
+  ) -> ServerResult<Json<provenance::Output>> {
+    let depth = depth.depth.unwrap_or(16);
+
+    if depth > 64 {
+      return Err(ServerError::BadRequest(
+        "depth must not be greater than 64".into(),
+      ));
+    }
+
+    Ok(Json(
+      index
+        .get_provenance(inscription_id, depth)?
+        .ok_or_not_found(|| format!("inscription {inscription_id}"))?,
+    ))
+  }
+
+  async fn content_hash(
+    Extension(page_config): Extension<Arc<PageConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path(DeserializeFromStr(content_hash)): Path<DeserializeFromStr<sha256::Hash>>,
+    accept_json: AcceptJson,
+  ) -> ServerResult<Response> {
+    Self::content_hash_paginated(
+      Extension(page_config),
+      Extension(index),
+      Path((DeserializeFromStr(content_hash), 0)),
+      accept_json,
+    )
+    .await
+  }
+
+  async fn content_hash_paginated(
+    Extension(page_config): Extension<Arc<PageConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path((DeserializeFromStr(content_hash), page)): Path<(DeserializeFromStr<sha256::Hash>, usize)>,
+    accept_json: AcceptJson,
+  ) -> ServerResult<Response> {
+    let count = index.get_content_hash_inscription_count(content_hash)?;
+
+    if count == 0 {
+      return Err(ServerError::NotFound(format!(
+        "content hash {content_hash} not found"
+      )));
+    }
+
+    let (inscriptions, more) =
+      index.get_inscriptions_by_content_hash_paginated(content_hash, 100, page)?;
+
+    Ok(if accept_json.0 {
+      Json(ContentHashJson {
+        content_hash,
+        count,
+        ids: inscriptions,
+        more,
+        page,
+      })
+      .into_response()
+    } else {
+      ContentHashHtml {
+        content_hash,
+        count,
+        inscriptions,
+        prev_page: page.checked_sub(1),
+        next_page: more.then_some(page + 1),
+      }
+      .page(page_config)
+      .into_response()
+    })
+  }
+
+  async fn inscription_history(
+    Extension(page_config): Extension<Arc<PageConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path(inscription_id): Path<InscriptionId>,
+    accept_json: AcceptJson,
+  ) -> ServerResult<Response> {
+    Self::inscription_history_paginated(
+      Extension(page_config),
+      Extension(index),
+      Path((inscription_id, 0)),
+      accept_json,
+    )
+    .await
+  }
+
+  async fn inscription_history_paginated(
+    Extension(page_config): Extension<Arc<PageConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path((inscription_id, page)): Path<(InscriptionId, usize)>,
+    accept_json: AcceptJson,
+  ) -> ServerResult<Response> {
+    let inscription_number = index
+      .get_inscription_entry(inscription_id)?
+      .ok_or_not_found(|| format!("inscription {inscription_id}"))?
+      .inscription_number;
+
+    let (transfers, more_transfers) = index
+      .get_transfers_by_inscription_id_paginated(inscription_id, 100, page)?
+      .ok_or_not_found(|| format!("inscription {inscription_id}"))?;
+
+    let prev_page = page.checked_sub(1);
+
+    let next_page = more_transfers.then_some(page + 1);
+
+    Ok(if accept_json.0 {
+      Json(InscriptionHistoryJson::new(transfers, prev_page, next_page)).into_response()
+    } else {
+      InscriptionHistoryHtml {
+        inscription_id,
+        inscription_number,
+        transfers,
+        prev_page,
+        next_page,
+      }
+      .page(page_config)
+      .into_response()
+    })
+  }
+
+  async fn inscriptions(
+    Extension(page_config): Extension<Arc<PageConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Query(filter): Query<InscriptionFilter>,
+    Query(Page { page }): Query<Page>,
+    RawQuery(query): RawQuery,
+    accept_json: AcceptJson,
+  ) -> ServerResult<Response> {
+    if filter == InscriptionFilter::default() && page.is_none() {
+      return Self::inscriptions_inner(page_config, index, None, 100, accept_json).await;
+    }
+
+    if filter.sat_rarity.is_some() && !index.has_sat_index() {
+      return Err(ServerError::BadRequest(
+        "sat_rarity filter requires a sat index".into(),
+      ));
+    }
+
+    let page = page.unwrap_or_default();
+
+    let (inscriptions, more) = index.get_inscriptions_filtered(&filter, 100, page)?;
+
+    Ok(if accept_json.0 {
+      Json(InscriptionsFilteredJson {
+        ids: inscriptions,
+        more,
+        page,
+      })
+      .into_response()
+    } else {
+      InscriptionsFilteredHtml {
+        inscriptions,
+        prev_page: page.checked_sub(1),
+        next_page: more.then_some(page + 1),
+        query: query
+          .unwrap_or_default()
+          .split('&')
+          .filter(|pair| !pair.is_empty() && *pair != "page" && !pair.starts_with("page="))
+          .map(|pair| format!("{pair}&"))
+          .collect(),
+      }
+      .page(page_config)
+      .into_response()
+    })
+  }
+
+  async fn inscriptions_in_block(
+    Extension(page_config): Extension<Arc<PageConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path(block_height): Path<u64>,
+    accept_json: AcceptJson,
+  ) -> ServerResult<Response> {
+    Self::inscriptions_in_block_from_page(
+      Extension(page_config),
+      Extension(index),
+      Path((block_height, 0)),
+      accept_json,
+    )
+    .await
+  }
+
+  async fn inscriptions_in_block_from_page(
+    Extension(page_config): Extension<Arc<PageConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path((block_height, page)): Path<(u64, usize)>,
+    accept_json: AcceptJson,
+  ) -> ServerResult<Response> {
+    let inscriptions = index.get_inscriptions_in_block(block_height)?;
 
     Ok(if accept_json.0 {
       Json(InscriptionsJson::new(inscriptions, None, None, None, None)).into_response()
@@ -1357,10 +2078,16 @@ impl Server {
 mod tests {
   use {
     super::*,
-    crate::runes::{Edict, Etching, Rune, Runestone},
+    crate::{
+      runes::{Edict, Etching, Rune, Runestone},
+      templates::inscription_history::InscriptionTransferJson,
+    },
     reqwest::Url,
     serde::de::DeserializeOwned,
-    std::net::TcpListener,
+    std::{
+      io::{BufRead, BufReader},
+      net::TcpListener,
+    },
   };
 
   const RUNE: u128 = 99246114928149462;
@@ -1435,6 +2162,17 @@ mod tests {
       )
     }
 
+    fn new_with_regtest_with_index_text() -> Self {
+      Self::new_server(
+        test_bitcoincore_rpc::builder()
+          .network(bitcoin::Network::Regtest)
+          .build(),
+        None,
+        &["--chain", "regtest", "--index-text"],
+        &["--enable-json-api"],
+      )
+    }
+
     fn new_with_bitcoin_rpc_server_and_config(
       bitcoin_rpc_server: test_bitcoincore_rpc::Handle,
       config: String,
@@ -2014,6 +2752,68 @@ mod tests {
     assert_eq!(response.text().unwrap(), "1231006505");
   }
 
+  #[test]
+  fn address_page_requires_address_index() {
+    TestServer::new().assert_response(
+      "/address/bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+      StatusCode::NOT_FOUND,
+      "this server has no address index, run ord with `--index-addresses` to enable",
+    );
+  }
+
+  #[test]
+  fn address_page_rejects_address_for_wrong_network() {
+    TestServer::new_with_args(&["--index-addresses"], &[]).assert_response(
+      "/address/tb1q6en7qjxgw4ev8xwx94pzdry6a6ky7wlfeqzunz",
+      StatusCode::BAD_REQUEST,
+      "address tb1q6en7qjxgw4ev8xwx94pzdry6a6ky7wlfeqzunz belongs to network testnet which is different from required bitcoin",
+    );
+  }
+
+  #[test]
+  fn address_page_lists_outputs_and_balance() {
+    let server = TestServer::new_with_args(&["--index-addresses"], &["--enable-json-api"]);
+
+    server.mine_blocks(1);
+
+    let address = address();
+
+    let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Default::default())],
+      outputs: 2,
+      recipient: Some(address.clone()),
+      ..Default::default()
+    });
+
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      format!("/address/{address}"),
+      StatusCode::OK,
+      format!(
+        ".*<title>Address {address}</title>.*
+<dl>
+  <dt>cardinal balance</dt><dd>5000000000</dd>
+</dl>
+<h2>2 Outputs</h2>
+<ul class=monospace>
+  <li><a href=/output/{txid}:0>{txid}:0</a></li>
+  <li><a href=/output/{txid}:1>{txid}:1</a></li>
+</ul>.*"
+      ),
+    );
+
+    assert_eq!(
+      server.get_json::<AddressJson>(format!("/address/{address}")),
+      AddressJson {
+        cardinal: 50 * COIN_VALUE,
+        inscriptions: Vec::new(),
+        outputs: vec![OutPoint::new(txid, 0), OutPoint::new(txid, 1)],
+        runes: BTreeMap::new(),
+      }
+    );
+  }
+
   #[test]
   fn range_end_before_range_start_returns_400() {
     TestServer::new().assert_response(
@@ -2237,6 +3037,10 @@ mod tests {
         ".*<dl>
   <dt>id</dt>
   <dd class=monospace>{inscription_id}</dd>
+  <dt>charms</dt>
+  <dd>
+    <span title=unbound>🔓</span>
+  </dd>
   <dt>preview</dt>.*<dt>output</dt>
   <dd><a class=monospace href=/output/0000000000000000000000000000000000000000000000000000000000000000:0>0000000000000000000000000000000000000000000000000000000000000000:0</a></dd>.*"
       ),
@@ -2694,24 +3498,25 @@ mod tests {
 
   #[test]
   fn content_response_no_content() {
-    assert_eq!(
-      Server::content_response(Inscription::new(
-        Some("text/plain".as_bytes().to_vec()),
-        None
-      )),
-      None
-    );
+    assert!(Server::content_response(
+      Inscription::new(Some("text/plain".as_bytes().to_vec()), None),
+      &HeaderMap::new(),
+    )
+    .unwrap()
+    .is_none());
   }
 
   #[test]
   fn content_response_with_content() {
-    let (headers, body) = Server::content_response(Inscription::new(
-      Some("text/plain".as_bytes().to_vec()),
-      Some(vec![1, 2, 3]),
-    ))
+    let (headers, body) = Server::content_response(
+      Inscription::new(Some("text/plain".as_bytes().to_vec()), Some(vec![1, 2, 3])),
+      &HeaderMap::new(),
+    )
+    .unwrap()
     .unwrap();
 
     assert_eq!(headers["content-type"], "text/plain");
+    assert!(!headers.contains_key(header::VARY));
     assert_eq!(body, vec![1, 2, 3]);
   }
 
@@ -2740,58 +3545,310 @@ mod tests {
     );
   }
 
-  #[test]
-  fn content_response_no_content_type() {
-    let (headers, body) =
-      Server::content_response(Inscription::new(None, Some(Vec::new()))).unwrap();
-
-    assert_eq!(headers["content-type"], "application/octet-stream");
-    assert!(body.is_empty());
+  fn accept_encoding(value: &'static str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(header::ACCEPT_ENCODING, HeaderValue::from_static(value));
+    headers
   }
 
   #[test]
-  fn content_response_bad_content_type() {
-    let (headers, body) = Server::content_response(Inscription::new(
-      Some("\n".as_bytes().to_vec()),
-      Some(Vec::new()),
-    ))
+  fn content_response_with_content_encoding() {
+    let (headers, body) = Server::content_response(
+      Inscription {
+        content_type: Some("text/plain".as_bytes().to_vec()),
+        content_encoding: Some("br".as_bytes().to_vec()),
+        body: Some(vec![1, 2, 3]),
+        ..Default::default()
+      },
+      &accept_encoding("gzip, br"),
+    )
+    .unwrap()
     .unwrap();
 
-    assert_eq!(headers["content-type"], "application/octet-stream");
-    assert!(body.is_empty());
+    assert_eq!(headers["content-encoding"], "br");
+    assert_eq!(headers["vary"], "Accept-Encoding");
+    assert_eq!(body, vec![1, 2, 3]);
   }
 
   #[test]
-  fn text_preview() {
-    let server = TestServer::new_with_regtest();
-    server.mine_blocks(1);
+  fn content_response_decodes_content_encoding_not_accepted_by_client() {
+    let mut body = Vec::new();
+
+    brotli::BrotliCompress(&mut "hello".as_bytes(), &mut body, &Default::default()).unwrap();
+
+    for request_headers in [
+      HeaderMap::new(),
+      accept_encoding("gzip"),
+      accept_encoding("br;q=0"),
+    ] {
+      let (headers, decoded) = Server::content_response(
+        Inscription {
+          content_type: Some("text/plain".as_bytes().to_vec()),
+          content_encoding: Some("br".as_bytes().to_vec()),
+          body: Some(body.clone()),
+          ..Default::default()
+        },
+        &request_headers,
+      )
+      .unwrap()
+      .unwrap();
 
-    let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
-      inputs: &[(
-        1,
-        0,
-        0,
-        inscription("text/plain;charset=utf-8", "hello").to_witness(),
-      )],
+      assert!(!headers.contains_key(header::CONTENT_ENCODING));
+      assert_eq!(headers["vary"], "Accept-Encoding");
+      assert_eq!(decoded, b"hello");
+    }
+  }
+
+  #[test]
+  fn content_response_with_gzip_content_encoding() {
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    std::io::Write::write_all(&mut encoder, b"hello").unwrap();
+    let body = encoder.finish().unwrap();
+
+    let inscription = Inscription {
+      content_type: Some("text/plain".as_bytes().to_vec()),
+      content_encoding: Some("gzip".as_bytes().to_vec()),
+      body: Some(body.clone()),
       ..Default::default()
-    });
+    };
 
-    server.mine_blocks(1);
+    let (headers, encoded) = Server::content_response(inscription.clone(), &accept_encoding("*"))
+      .unwrap()
+      .unwrap();
 
-    server.assert_response_csp(
-      format!("/preview/{}", InscriptionId { txid, index: 0 }),
-      StatusCode::OK,
-      "default-src 'self'",
-      ".*<pre>hello</pre>.*",
-    );
+    assert_eq!(headers["content-encoding"], "gzip");
+    assert_eq!(encoded, body);
+
+    let (headers, decoded) = Server::content_response(inscription, &HeaderMap::new())
+      .unwrap()
+      .unwrap();
+
+    assert!(!headers.contains_key(header::CONTENT_ENCODING));
+    assert_eq!(decoded, b"hello");
   }
 
   #[test]
-  fn text_preview_returns_error_when_content_is_not_utf8() {
-    let server = TestServer::new_with_regtest();
-    server.mine_blocks(1);
-
-    let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+  fn content_response_with_unsupported_content_encoding() {
+    assert_eq!(
+      Server::content_response(
+        Inscription {
+          content_type: Some("text/plain".as_bytes().to_vec()),
+          content_encoding: Some("\"><script>".as_bytes().to_vec()),
+          body: Some(vec![1, 2, 3]),
+          ..Default::default()
+        },
+        &accept_encoding("*"),
+      )
+      .unwrap_err()
+      .into_response()
+      .status(),
+      StatusCode::NOT_ACCEPTABLE,
+    );
+  }
+
+  #[test]
+  fn content_response_rejects_oversized_decoded_content() {
+    let mut body = Vec::new();
+
+    brotli::BrotliCompress(
+      &mut vec![0; Inscription::MAX_DECODED_BODY_LEN + 1].as_slice(),
+      &mut body,
+      &Default::default(),
+    )
+    .unwrap();
+
+    assert_eq!(
+      Server::content_response(
+        Inscription {
+          content_type: Some("text/plain".as_bytes().to_vec()),
+          content_encoding: Some("br".as_bytes().to_vec()),
+          body: Some(body),
+          ..Default::default()
+        },
+        &HeaderMap::new(),
+      )
+      .unwrap_err()
+      .into_response()
+      .status(),
+      StatusCode::NOT_ACCEPTABLE,
+    );
+  }
+
+  #[test]
+  fn compressed_content_is_served_with_content_encoding_and_previewed_decoded() {
+    let server = TestServer::new_with_regtest();
+    server.mine_blocks(1);
+
+    let mut body = Vec::new();
+
+    brotli::BrotliCompress(
+      &mut "hello".repeat(100).as_bytes(),
+      &mut body,
+      &Default::default(),
+    )
+    .unwrap();
+
+    let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        1,
+        0,
+        0,
+        Inscription {
+          content_type: Some("text/plain;charset=utf-8".as_bytes().to_vec()),
+          content_encoding: Some("br".as_bytes().to_vec()),
+          body: Some(body.clone()),
+          ..Default::default()
+        }
+        .to_witness(),
+      )],
+      ..Default::default()
+    });
+
+    server.mine_blocks(1);
+
+    let inscription_id = InscriptionId { txid, index: 0 };
+
+    let response = reqwest::blocking::Client::builder()
+      .default_headers(accept_encoding("br"))
+      .build()
+      .unwrap()
+      .get(server.join_url(&format!("/content/{inscription_id}")))
+      .send()
+      .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_ENCODING], "br");
+    assert!(response
+      .headers()
+      .get_all(header::VARY)
+      .iter()
+      .any(|value| value.as_bytes().eq_ignore_ascii_case(b"accept-encoding")));
+    assert_eq!(response.bytes().unwrap(), body);
+
+    let response = server.get(format!("/content/{inscription_id}"));
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(!response.headers().contains_key(header::CONTENT_ENCODING));
+    assert_eq!(response.text().unwrap(), "hello".repeat(100));
+
+    server.assert_response_regex(
+      format!("/preview/{inscription_id}"),
+      StatusCode::OK,
+      format!(".*<pre>{}</pre>.*", "hello".repeat(100)),
+    );
+  }
+
+  #[test]
+  fn content_and_preview_are_served_from_delegate() {
+    let server = TestServer::new_with_regtest();
+    server.mine_blocks(1);
+
+    let delegate_txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        1,
+        0,
+        0,
+        inscription("text/plain;charset=utf-8", "foo").to_witness(),
+      )],
+      ..Default::default()
+    });
+
+    server.mine_blocks(1);
+
+    let delegate = InscriptionId {
+      txid: delegate_txid,
+      index: 0,
+    };
+
+    let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        2,
+        0,
+        0,
+        Inscription {
+          delegate: Some(delegate.value()),
+          ..Default::default()
+        }
+        .to_witness(),
+      )],
+      ..Default::default()
+    });
+
+    server.mine_blocks(1);
+
+    let inscription_id = InscriptionId { txid, index: 0 };
+
+    let response = server.get(format!("/content/{inscription_id}"));
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response.headers()[header::CONTENT_TYPE],
+      "text/plain;charset=utf-8"
+    );
+    assert_eq!(response.text().unwrap(), "foo");
+
+    server.assert_response_regex(
+      format!("/preview/{inscription_id}"),
+      StatusCode::OK,
+      ".*<pre>foo</pre>.*",
+    );
+  }
+
+  #[test]
+  fn content_response_no_content_type() {
+    let (headers, body) =
+      Server::content_response(Inscription::new(None, Some(Vec::new())), &HeaderMap::new())
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(headers["content-type"], "application/octet-stream");
+    assert!(body.is_empty());
+  }
+
+  #[test]
+  fn content_response_bad_content_type() {
+    let (headers, body) = Server::content_response(
+      Inscription::new(Some("\n".as_bytes().to_vec()), Some(Vec::new())),
+      &HeaderMap::new(),
+    )
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(headers["content-type"], "application/octet-stream");
+    assert!(body.is_empty());
+  }
+
+  #[test]
+  fn text_preview() {
+    let server = TestServer::new_with_regtest();
+    server.mine_blocks(1);
+
+    let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        1,
+        0,
+        0,
+        inscription("text/plain;charset=utf-8", "hello").to_witness(),
+      )],
+      ..Default::default()
+    });
+
+    server.mine_blocks(1);
+
+    server.assert_response_csp(
+      format!("/preview/{}", InscriptionId { txid, index: 0 }),
+      StatusCode::OK,
+      "default-src 'self'",
+      ".*<pre>hello</pre>.*",
+    );
+  }
+
+  #[test]
+  fn text_preview_returns_error_when_content_is_not_utf8() {
+    let server = TestServer::new_with_regtest();
+    server.mine_blocks(1);
+
+    let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
       inputs: &[(
         1,
         0,
@@ -3047,6 +4104,93 @@ mod tests {
     );
   }
 
+  #[test]
+  fn inscription_history() {
+    let server = TestServer::new_with_regtest_with_json_api();
+    server.mine_blocks(1);
+
+    let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/foo", "hello").to_witness())],
+      ..Default::default()
+    });
+
+    server.mine_blocks(1);
+
+    let inscription_id = InscriptionId { txid, index: 0 };
+
+    server.assert_response_regex(
+      format!("/inscription/{inscription_id}"),
+      StatusCode::OK,
+      format!(
+        ".*<dt>history</dt>\\s*<dd><a href=/inscription/{inscription_id}/history>link</a></dd>.*"
+      ),
+    );
+
+    let send_txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 1, 0, Default::default())],
+      ..Default::default()
+    });
+
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      format!("/inscription/{inscription_id}/history"),
+      StatusCode::OK,
+      format!(
+        ".*<title>Inscription 0 History</title>.*
+  <tr>
+    <td><a href=/block/2>2</a></td>
+    <td><a class=monospace href=/tx/{txid}>{txid}</a></td>
+    <td>genesis</td>
+    <td class=monospace>{txid}:0:0</td>
+  </tr>
+  <tr>
+    <td><a href=/block/3>3</a></td>
+    <td><a class=monospace href=/tx/{send_txid}>{send_txid}</a></td>
+    <td class=monospace>{txid}:0:0</td>
+    <td class=monospace>{send_txid}:0:0</td>
+  </tr>.*"
+      ),
+    );
+
+    pretty_assert_eq!(
+      server.get_json::<InscriptionHistoryJson>(format!("/inscription/{inscription_id}/history/0")),
+      InscriptionHistoryJson {
+        transfers: vec![
+          InscriptionTransferJson {
+            height: 2,
+            new_satpoint: format!("{txid}:0:0").parse().unwrap(),
+            old_satpoint: None,
+            txid,
+          },
+          InscriptionTransferJson {
+            height: 3,
+            new_satpoint: format!("{send_txid}:0:0").parse().unwrap(),
+            old_satpoint: Some(format!("{txid}:0:0").parse().unwrap()),
+            txid: send_txid,
+          },
+        ],
+        prev_page: None,
+        next_page: None,
+      }
+    );
+
+    server.assert_response_regex(
+      format!("/inscription/{inscription_id}/history/1"),
+      StatusCode::OK,
+      ".*<h3>No history</h3>.*",
+    );
+  }
+
+  #[test]
+  fn inscription_history_not_found() {
+    TestServer::new_with_regtest().assert_response(
+      format!("/inscription/{}/history", inscription_id(1)),
+      StatusCode::NOT_FOUND,
+      &format!("inscription {} not found", inscription_id(1)),
+    );
+  }
+
   #[test]
   fn strict_transport_security_header_is_set() {
     assert_eq!(
@@ -3059,6 +4203,31 @@ mod tests {
     );
   }
 
+  #[test]
+  fn events() {
+    let server = TestServer::new();
+
+    let response = server.get("/events");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response.headers().get(header::CONTENT_TYPE).unwrap(),
+      "text/event-stream"
+    );
+
+    let hash = server.bitcoin_rpc_server.mine_blocks(1)[0].block_hash();
+
+    server.index.update().unwrap();
+
+    let mut lines = BufReader::new(response).lines();
+
+    assert_eq!(lines.next().unwrap().unwrap(), "event:block_committed");
+    assert_eq!(
+      lines.next().unwrap().unwrap(),
+      format!("data:{{\"type\":\"block_committed\",\"hash\":\"{hash}\",\"height\":1}}")
+    );
+  }
+
   #[test]
   fn feed() {
     let server = TestServer::new_with_regtest_with_index_sats();
@@ -3216,42 +4385,447 @@ mod tests {
   }
 
   #[test]
-  fn responses_are_gzipped() {
-    let server = TestServer::new();
+  fn inscriptions_can_be_filtered_by_content_type_and_ordered() {
+    let server = TestServer::new_with_regtest_with_json_api();
 
-    let mut headers = HeaderMap::new();
+    let mut ids = Vec::new();
 
-    headers.insert(header::ACCEPT_ENCODING, "gzip".parse().unwrap());
+    for (i, content_type) in ["image/png", "text/plain", "image/jpeg"].iter().enumerate() {
+      server.mine_blocks(1);
+      let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(i + 1, 0, 0, inscription(content_type, "hello").to_witness())],
+        ..Default::default()
+      });
+      ids.push(InscriptionId { txid, index: 0 });
+    }
 
-    let response = reqwest::blocking::Client::builder()
-      .default_headers(headers)
-      .build()
-      .unwrap()
-      .get(server.join_url("/"))
-      .send()
-      .unwrap();
+    server.mine_blocks(1);
 
     assert_eq!(
-      response.headers().get(header::CONTENT_ENCODING).unwrap(),
-      "gzip"
+      server.get_json::<InscriptionsFilteredJson>("/inscriptions?content_type=image/"),
+      InscriptionsFilteredJson {
+        ids: vec![ids[2], ids[0]],
+        more: false,
+        page: 0,
+      }
+    );
+
+    assert_eq!(
+      server.get_json::<InscriptionsFilteredJson>("/inscriptions?content_type=image/&order=oldest"),
+      InscriptionsFilteredJson {
+        ids: vec![ids[0], ids[2]],
+        more: false,
+        page: 0,
+      }
     );
   }
 
   #[test]
-  fn responses_are_brotlied() {
-    let server = TestServer::new();
-
-    let mut headers = HeaderMap::new();
+  fn inscriptions_can_be_filtered_by_height_and_metaprotocol() {
+    let server = TestServer::new_with_regtest_with_json_api();
 
-    headers.insert(header::ACCEPT_ENCODING, "br".parse().unwrap());
+    let mut ids = Vec::new();
 
-    let response = reqwest::blocking::Client::builder()
-      .default_headers(headers)
-      .build()
-      .unwrap()
-      .get(server.join_url("/"))
-      .send()
-      .unwrap();
+    for i in 0..3 {
+      server.mine_blocks(1);
+      let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(
+          i + 1,
+          0,
+          0,
+          Inscription {
+            content_type: Some("text/plain".into()),
+            body: Some("hello".into()),
+            metaprotocol: (i != 1).then(|| "foo".into()),
+            ..Default::default()
+          }
+          .to_witness(),
+        )],
+        ..Default::default()
+      });
+      ids.push(InscriptionId { txid, index: 0 });
+    }
+
+    server.mine_blocks(1);
+
+    assert_eq!(
+      server.get_json::<InscriptionsFilteredJson>("/inscriptions?height=3"),
+      InscriptionsFilteredJson {
+        ids: vec![ids[1]],
+        more: false,
+        page: 0,
+      }
+    );
+
+    assert_eq!(
+      server.get_json::<InscriptionsFilteredJson>("/inscriptions?metaprotocol=foo"),
+      InscriptionsFilteredJson {
+        ids: vec![ids[2], ids[0]],
+        more: false,
+        page: 0,
+      }
+    );
+
+    assert_eq!(
+      server.get_json::<InscriptionsFilteredJson>("/inscriptions?metaprotocol=foo&height=2"),
+      InscriptionsFilteredJson {
+        ids: vec![ids[0]],
+        more: false,
+        page: 0,
+      }
+    );
+  }
+
+  #[test]
+  fn filtered_inscriptions_are_paginated() {
+    let server = TestServer::new_with_regtest();
+
+    for i in 0..101 {
+      server.mine_blocks(1);
+      server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(i + 1, 0, 0, inscription("text/foo", "hello").to_witness())],
+        ..Default::default()
+      });
+    }
+
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      "/inscriptions?content_type=text/",
+      StatusCode::OK,
+      ".*prev\n<a class=next href=/inscriptions\\?content_type=text/&amp;page=1>next</a>.*",
+    );
+
+    server.assert_response_regex(
+      "/inscriptions?page=1&content_type=text/",
+      StatusCode::OK,
+      ".*<a class=prev href=/inscriptions\\?content_type=text/&amp;page=0>prev</a>\nnext.*",
+    );
+  }
+
+  #[test]
+  fn sat_rarity_filter_requires_sat_index() {
+    TestServer::new_with_regtest().assert_response(
+      "/inscriptions?sat_rarity=uncommon",
+      StatusCode::BAD_REQUEST,
+      "sat_rarity filter requires a sat index",
+    );
+  }
+
+  #[test]
+  fn metaprotocols_are_listed_with_counts() {
+    let server = TestServer::new_with_regtest_with_json_api();
+
+    server.assert_response_regex("/metaprotocols", StatusCode::OK, ".*No metaprotocols.*");
+
+    let mut ids = Vec::new();
+
+    for (i, metaprotocol) in ["foo", "bar", "foo"].iter().enumerate() {
+      server.mine_blocks(1);
+      let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(
+          i + 1,
+          0,
+          0,
+          Inscription {
+            content_type: Some("text/plain".into()),
+            body: Some("hello".into()),
+            metaprotocol: Some(metaprotocol.as_bytes().to_vec()),
+            ..Default::default()
+          }
+          .to_witness(),
+        )],
+        ..Default::default()
+      });
+      ids.push(InscriptionId { txid, index: 0 });
+    }
+
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      "/metaprotocols",
+      StatusCode::OK,
+      ".*<li><a href=\"/metaprotocol/foo\">foo</a> \\(2\\)</li>\n  <li><a href=\"/metaprotocol/bar\">bar</a> \\(1\\)</li>.*",
+    );
+
+    assert_eq!(
+      server.get_json::<MetaprotocolsJson>("/metaprotocols"),
+      MetaprotocolsJson::new(vec![("foo".into(), 2), ("bar".into(), 1)]),
+    );
+
+    server.assert_response_regex(
+      "/metaprotocol/foo",
+      StatusCode::OK,
+      format!(
+        ".*<h1>Metaprotocol foo</h1>.*<dd>2</dd>.*<a href=/inscription/{}>.*<a href=/inscription/{}>.*",
+        ids[2], ids[0]
+      ),
+    );
+
+    assert_eq!(
+      server.get_json::<MetaprotocolJson>("/metaprotocol/foo/0"),
+      MetaprotocolJson {
+        count: 2,
+        ids: vec![ids[2], ids[0]],
+        metaprotocol: "foo".into(),
+        more: false,
+        page: 0,
+      }
+    );
+
+    server.assert_response_regex(
+      format!("/inscription/{}", ids[1]),
+      StatusCode::OK,
+      ".*<dt>metaprotocol</dt>\n  <dd><a href=\"/metaprotocol/bar\">bar</a></dd>.*",
+    );
+  }
+
+  #[test]
+  fn hostile_metaprotocol_is_escaped_and_percent_encoded() {
+    let server = TestServer::new_with_regtest();
+    server.mine_blocks(1);
+
+    let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        1,
+        0,
+        0,
+        Inscription {
+          content_type: Some("text/plain".into()),
+          body: Some("hello".into()),
+          metaprotocol: Some("x onclick=alert(1) \"><b>/..".into()),
+          ..Default::default()
+        }
+        .to_witness(),
+      )],
+      ..Default::default()
+    });
+
+    server.mine_blocks(1);
+
+    let path = "/metaprotocol/x%20onclick%3Dalert%281%29%20%22%3E%3Cb%3E%2F..";
+
+    server.assert_response_regex(
+      "/metaprotocols",
+      StatusCode::OK,
+      format!(
+        ".*<li><a href=\"{path}\">x onclick=alert\\(1\\) &quot;&gt;&lt;b&gt;/..</a> \\(1\\)</li>.*"
+      ),
+    );
+
+    server.assert_response_regex(
+      format!("/inscription/{}", InscriptionId { txid, index: 0 }),
+      StatusCode::OK,
+      format!(".*<dd><a href=\"{path}\">x onclick=alert\\(1\\) &quot;&gt;&lt;b&gt;/..</a></dd>.*"),
+    );
+
+    server.assert_response_regex(
+      path,
+      StatusCode::OK,
+      ".*<h1>Metaprotocol x onclick=alert\\(1\\) &quot;&gt;&lt;b&gt;/..</h1>.*",
+    );
+  }
+
+  #[test]
+  fn unknown_metaprotocol_returns_404() {
+    TestServer::new_with_regtest().assert_response(
+      "/metaprotocol/foo",
+      StatusCode::NOT_FOUND,
+      "metaprotocol foo not found",
+    );
+  }
+
+  #[test]
+  fn inscriptions_with_identical_content_are_linked() {
+    let server = TestServer::new_with_regtest_with_json_api();
+
+    let mut ids = Vec::new();
+
+    for i in 0..3 {
+      server.mine_blocks(1);
+      let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(i + 1, 0, 0, inscription("text/plain", "hello").to_witness())],
+        ..Default::default()
+      });
+      ids.push(InscriptionId { txid, index: 0 });
+    }
+
+    server.mine_blocks(1);
+
+    let content_hash = sha256::Hash::hash(b"hello");
+
+    server.assert_response_regex(
+      format!("/inscription/{}", ids[0]),
+      StatusCode::OK,
+      format!(
+        ".*<dt>content hash</dt>
+  <dd><a class=monospace href=/content-hash/{content_hash}>{content_hash}</a></dd>
+  <dt>duplicates</dt>
+  <dd>
+    <div class=thumbnails>
+      <a href=/inscription/{}>.*</a>
+      <a href=/inscription/{}>.*</a>
+    </div>
+    <div class=center>
+      <a href=/content-hash/{content_hash}>2 other inscriptions share this content</a>
+    </div>
+  </dd>.*",
+        ids[1], ids[2],
+      ),
+    );
+
+    assert_eq!(
+      server.get_json::<ContentHashJson>(format!("/content-hash/{content_hash}")),
+      ContentHashJson {
+        content_hash,
+        count: 3,
+        ids: ids.clone(),
+        more: false,
+        page: 0,
+      }
+    );
+
+    server.assert_response_regex(
+      format!("/content-hash/{content_hash}"),
+      StatusCode::OK,
+      format!(
+        ".*<dd>3</dd>.*<a href=/inscription/{}>.*<a href=/inscription/{}>.*<a href=/inscription/{}>.*",
+        ids[0], ids[1], ids[2]
+      ),
+    );
+  }
+
+  #[test]
+  fn inscription_with_unique_content_has_no_duplicates() {
+    let server = TestServer::new_with_regtest();
+    server.mine_blocks(1);
+
+    let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      ..Default::default()
+    });
+
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      format!("/inscription/{}", InscriptionId { txid, index: 0 }),
+      StatusCode::OK,
+      ".*<dt>content hash</dt>\n  <dd><a class=monospace href=/content-hash/[[:xdigit:]]{64}>[[:xdigit:]]{64}</a></dd>\n  <dt>timestamp</dt>.*",
+    );
+  }
+
+  #[test]
+  fn unknown_content_hash_returns_404() {
+    let content_hash = sha256::Hash::hash(b"hello");
+
+    TestServer::new_with_regtest().assert_response(
+      format!("/content-hash/{content_hash}"),
+      StatusCode::NOT_FOUND,
+      &format!("content hash {content_hash} not found"),
+    );
+  }
+
+  #[test]
+  fn search_falls_back_to_full_text_search() {
+    let server = TestServer::new_with_regtest_with_index_text();
+
+    let mut ids = Vec::new();
+
+    for (i, body) in ["hello world", "hello there"].iter().enumerate() {
+      server.mine_blocks(1);
+      let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+        inputs: &[(i + 1, 0, 0, inscription("text/plain", body).to_witness())],
+        ..Default::default()
+      });
+      ids.push(InscriptionId { txid, index: 0 });
+    }
+
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      "/search?query=hello",
+      StatusCode::OK,
+      format!(
+        ".*<h1>Search results for hello</h1>
+<p>Looking for <a href=/sat/\\d+>sat hello</a>\\?</p>
+<div class=thumbnails>
+  <a href=/inscription/{}>.*</a>
+  <a href=/inscription/{}>.*</a>
+</div>.*",
+        ids[1], ids[0]
+      ),
+    );
+
+    assert_eq!(
+      server.get_json::<SearchJson>("/search?query=hello%20world"),
+      SearchJson {
+        ids: vec![ids[0]],
+        more: false,
+        page: 0,
+        query: "hello world".into(),
+      }
+    );
+
+    server.assert_response_regex(
+      "/search/goodbye world",
+      StatusCode::OK,
+      ".*<h1>Search results for goodbye world</h1>\n<h3>No inscriptions</h3>.*",
+    );
+
+    server.assert_redirect("/search?query=0", "/sat/0");
+  }
+
+  #[test]
+  fn search_with_too_many_words_is_rejected() {
+    TestServer::new_with_regtest_with_index_text().assert_response(
+      "/search?query=a%20b%20c%20d%20e%20f%20g%20h%20i",
+      StatusCode::BAD_REQUEST,
+      "search query may contain at most 8 words",
+    );
+  }
+
+  #[test]
+  fn search_redirects_to_sat_without_text_index() {
+    TestServer::new_with_regtest().assert_redirect("/search?query=hello", "/sat/hello");
+  }
+
+  #[test]
+  fn responses_are_gzipped() {
+    let server = TestServer::new();
+
+    let mut headers = HeaderMap::new();
+
+    headers.insert(header::ACCEPT_ENCODING, "gzip".parse().unwrap());
+
+    let response = reqwest::blocking::Client::builder()
+      .default_headers(headers)
+      .build()
+      .unwrap()
+      .get(server.join_url("/"))
+      .send()
+      .unwrap();
+
+    assert_eq!(
+      response.headers().get(header::CONTENT_ENCODING).unwrap(),
+      "gzip"
+    );
+  }
+
+  #[test]
+  fn responses_are_brotlied() {
+    let server = TestServer::new();
+
+    let mut headers = HeaderMap::new();
+
+    headers.insert(header::ACCEPT_ENCODING, "br".parse().unwrap());
+
+    let response = reqwest::blocking::Client::builder()
+      .default_headers(headers)
+      .build()
+      .unwrap()
+      .get(server.join_url("/"))
+      .send()
+      .unwrap();
 
     assert_eq!(
       response.headers().get(header::CONTENT_ENCODING).unwrap(),
@@ -3272,29 +4846,203 @@ mod tests {
       )],
       ..Default::default()
     });
-    let inscription = InscriptionId { txid, index: 0 };
-    bitcoin_rpc_server.mine_blocks(1);
+    let inscription = InscriptionId { txid, index: 0 };
+    bitcoin_rpc_server.mine_blocks(1);
+
+    let server = TestServer::new_with_bitcoin_rpc_server_and_config(
+      bitcoin_rpc_server,
+      format!("\"hidden\":\n - {inscription}"),
+    );
+
+    server.assert_response(
+      format!("/preview/{inscription}"),
+      StatusCode::OK,
+      &fs::read_to_string("templates/preview-unknown.html").unwrap(),
+    );
+
+    server.assert_response(
+      format!("/content/{inscription}"),
+      StatusCode::OK,
+      &fs::read_to_string("templates/preview-unknown.html").unwrap(),
+    );
+  }
+
+  #[test]
+  fn inscriptions_delegating_to_hidden_inscriptions_are_hidden() {
+    let bitcoin_rpc_server = test_bitcoincore_rpc::builder()
+      .network(bitcoin::Network::Regtest)
+      .build();
+    bitcoin_rpc_server.mine_blocks(1);
+    let txid = bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        1,
+        0,
+        0,
+        inscription("text/plain;charset=utf-8", "hello").to_witness(),
+      )],
+      ..Default::default()
+    });
+    let delegate = InscriptionId { txid, index: 0 };
+    bitcoin_rpc_server.mine_blocks(1);
+
+    let txid = bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(
+        2,
+        0,
+        0,
+        Inscription {
+          delegate: Some(delegate.value()),
+          ..Default::default()
+        }
+        .to_witness(),
+      )],
+      ..Default::default()
+    });
+    let inscription = InscriptionId { txid, index: 0 };
+    bitcoin_rpc_server.mine_blocks(1);
+
+    let server = TestServer::new_server(
+      bitcoin_rpc_server,
+      Some(format!("\"hidden\":\n - {delegate}")),
+      &["--chain", "regtest"],
+      &[],
+    );
+
+    server.assert_response(
+      format!("/preview/{inscription}"),
+      StatusCode::OK,
+      &fs::read_to_string("templates/preview-unknown.html").unwrap(),
+    );
+
+    server.assert_response(
+      format!("/content/{inscription}"),
+      StatusCode::OK,
+      &fs::read_to_string("templates/preview-unknown.html").unwrap(),
+    );
+  }
+
+  #[test]
+  fn inscription_links_to_parent() {
+    let server = TestServer::new_with_regtest_with_json_api();
+    server.mine_blocks(1);
+
+    let parent_txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      ..Default::default()
+    });
+
+    server.mine_blocks(1);
+
+    let parent_inscription_id = InscriptionId {
+      txid: parent_txid,
+      index: 0,
+    };
+
+    let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[
+        (
+          2,
+          0,
+          0,
+          Inscription {
+            content_type: Some("text/plain".into()),
+            body: Some("hello".into()),
+            parent: Some(parent_inscription_id.parent_value()),
+            ..Default::default()
+          }
+          .to_witness(),
+        ),
+        (2, 1, 0, Default::default()),
+      ],
+      ..Default::default()
+    });
+
+    server.mine_blocks(1);
+
+    let inscription_id = InscriptionId { txid, index: 0 };
+
+    server.assert_response_regex(
+      format!("/inscription/{inscription_id}"),
+      StatusCode::OK,
+      format!(".*<title>Inscription 1</title>.*<dt>parent</dt>.*<dd><a class=monospace href=/inscription/{parent_inscription_id}>{parent_inscription_id}</a></dd>.*"),
+    );
+
+    server.assert_response_regex(
+      format!("/inscription/{parent_inscription_id}"),
+      StatusCode::OK,
+      format!(".*<title>Inscription 0</title>.*<dt>children</dt>.*<a href=/inscription/{inscription_id}>.*</a>.*"),
+    );
+
+    assert_eq!(
+      server
+        .get_json::<InscriptionJson>(format!("/inscription/{inscription_id}"))
+        .parent,
+      Some(parent_inscription_id),
+    );
+
+    assert_eq!(
+      server
+        .get_json::<InscriptionJson>(format!("/inscription/{parent_inscription_id}"))
+        .children,
+      [inscription_id],
+    );
+  }
+
+  #[test]
+  fn inscription_with_and_without_children_page() {
+    let server = TestServer::new_with_regtest();
+    server.mine_blocks(1);
+
+    let parent_txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      ..Default::default()
+    });
+
+    server.mine_blocks(1);
+
+    let parent_inscription_id = InscriptionId {
+      txid: parent_txid,
+      index: 0,
+    };
+
+    server.assert_response_regex(
+      format!("/children/{parent_inscription_id}"),
+      StatusCode::OK,
+      ".*<h3>No children</h3>.*",
+    );
+
+    let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[
+        (
+          2,
+          0,
+          0,
+          Inscription {
+            content_type: Some("text/plain".into()),
+            body: Some("hello".into()),
+            parent: Some(parent_inscription_id.parent_value()),
+            ..Default::default()
+          }
+          .to_witness(),
+        ),
+        (2, 1, 0, Default::default()),
+      ],
+      ..Default::default()
+    });
 
-    let server = TestServer::new_with_bitcoin_rpc_server_and_config(
-      bitcoin_rpc_server,
-      format!("\"hidden\":\n - {inscription}"),
-    );
+    server.mine_blocks(1);
 
-    server.assert_response(
-      format!("/preview/{inscription}"),
-      StatusCode::OK,
-      &fs::read_to_string("templates/preview-unknown.html").unwrap(),
-    );
+    let inscription_id = InscriptionId { txid, index: 0 };
 
-    server.assert_response(
-      format!("/content/{inscription}"),
+    server.assert_response_regex(
+      format!("/children/{parent_inscription_id}"),
       StatusCode::OK,
-      &fs::read_to_string("templates/preview-unknown.html").unwrap(),
+      format!(".*<title>Inscription 0 Children</title>.*<h1><a href=/inscription/{parent_inscription_id}>Inscription 0</a> Children</h1>.*<div class=thumbnails>.*<a href=/inscription/{inscription_id}><iframe .* src=/preview/{inscription_id}></iframe></a>.*"),
     );
   }
 
   #[test]
-  fn inscription_links_to_parent() {
+  fn collection_page() {
     let server = TestServer::new_with_regtest_with_json_api();
     server.mine_blocks(1);
 
@@ -3310,6 +5058,28 @@ mod tests {
       index: 0,
     };
 
+    server.assert_response_regex(
+      format!("/collection/{parent_inscription_id}"),
+      StatusCode::OK,
+      ".*<title>Inscription 0 Collection</title>.*<h3>No children</h3>.*",
+    );
+
+    let mut metadata = Vec::new();
+    ciborium::into_writer(
+      &Value::Map(vec![(
+        Value::Text("attributes".into()),
+        Value::Array(vec![Value::Map(vec![
+          (
+            Value::Text("trait_type".into()),
+            Value::Text("color".into()),
+          ),
+          (Value::Text("value".into()), Value::Text("red".into())),
+        ])]),
+      )]),
+      &mut metadata,
+    )
+    .unwrap();
+
     let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
       inputs: &[
         (
@@ -3320,6 +5090,7 @@ mod tests {
             content_type: Some("text/plain".into()),
             body: Some("hello".into()),
             parent: Some(parent_inscription_id.parent_value()),
+            metadata: Some(metadata),
             ..Default::default()
           }
           .to_witness(),
@@ -3334,34 +5105,57 @@ mod tests {
     let inscription_id = InscriptionId { txid, index: 0 };
 
     server.assert_response_regex(
-      format!("/inscription/{inscription_id}"),
-      StatusCode::OK,
-      format!(".*<title>Inscription 1</title>.*<dt>parent</dt>.*<dd><a class=monospace href=/inscription/{parent_inscription_id}>{parent_inscription_id}</a></dd>.*"),
-    );
-
-    server.assert_response_regex(
-      format!("/inscription/{parent_inscription_id}"),
+      format!("/collection/{parent_inscription_id}"),
       StatusCode::OK,
-      format!(".*<title>Inscription 0</title>.*<dt>children</dt>.*<a href=/inscription/{inscription_id}>.*</a>.*"),
+      format!(
+        ".*<h1><a href=/inscription/{parent_inscription_id}>Inscription 0</a> Collection</h1>
+<dl>
+  <dt>children</dt>
+  <dd><a href=/children/{parent_inscription_id}>1</a></dd>
+  <dt>minted</dt>
+  <dd><a href=/block/3>3</a>–<a href=/block/3>3</a></dd>
+</dl>
+<h2>Traits</h2>
+<dl>
+  <dt>color</dt>
+  <dd>
+    <ul>
+      <li>red \\(1\\)</li>
+    </ul>
+  </dd>
+</dl>
+<h2>Children</h2>
+<div class=thumbnails>
+  <a href=/inscription/{inscription_id}><iframe .* src=/preview/{inscription_id}></iframe></a>
+</div>.*"
+      ),
     );
 
     assert_eq!(
-      server
-        .get_json::<InscriptionJson>(format!("/inscription/{inscription_id}"))
-        .parent,
-      Some(parent_inscription_id),
+      server.get_json::<CollectionJson>(format!("/collection/{parent_inscription_id}")),
+      CollectionJson {
+        child_count: 1,
+        first_height: Some(3),
+        floor_rarity: None,
+        ids: vec![inscription_id],
+        last_height: Some(3),
+        more: false,
+        page: 0,
+        parent: parent_inscription_id,
+        parent_number: 0,
+        traits: [("color".to_string(), [("red".to_string(), 1)].into())].into(),
+      }
     );
 
-    assert_eq!(
-      server
-        .get_json::<InscriptionJson>(format!("/inscription/{parent_inscription_id}"))
-        .children,
-      [inscription_id],
+    server.assert_response(
+      format!("/collection/{txid}i1"),
+      StatusCode::NOT_FOUND,
+      &format!("inscription {txid}i1 not found"),
     );
   }
 
   #[test]
-  fn inscription_with_and_without_children_page() {
+  fn provenance() {
     let server = TestServer::new_with_regtest();
     server.mine_blocks(1);
 
@@ -3377,11 +5171,11 @@ mod tests {
       index: 0,
     };
 
-    server.assert_response_regex(
-      format!("/children/{parent_inscription_id}"),
-      StatusCode::OK,
-      ".*<h3>No children</h3>.*",
-    );
+    assert!(!server
+      .get(format!("/inscription/{parent_inscription_id}"))
+      .text()
+      .unwrap()
+      .contains("<dt>provenance</dt>"));
 
     let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
       inputs: &[
@@ -3406,10 +5200,47 @@ mod tests {
 
     let inscription_id = InscriptionId { txid, index: 0 };
 
+    assert_eq!(
+      server.get_json::<provenance::Output>(format!("/provenance/{inscription_id}")),
+      provenance::Output {
+        ancestors: vec![parent_inscription_id],
+        ancestors_truncated: false,
+        descendants: Vec::new(),
+        descendants_truncated: false,
+        inscription: inscription_id,
+      }
+    );
+
+    assert_eq!(
+      server.get_json::<provenance::Output>(format!("/provenance/{parent_inscription_id}?depth=0")),
+      provenance::Output {
+        ancestors: Vec::new(),
+        ancestors_truncated: false,
+        descendants: Vec::new(),
+        descendants_truncated: true,
+        inscription: parent_inscription_id,
+      }
+    );
+
+    server.assert_response(
+      format!("/provenance/{parent_inscription_id}?depth=65"),
+      StatusCode::BAD_REQUEST,
+      "depth must not be greater than 64",
+    );
+
+    server.assert_response(
+      format!("/provenance/{txid}i1"),
+      StatusCode::NOT_FOUND,
+      &format!("inscription {txid}i1 not found"),
+    );
+
     server.assert_response_regex(
-      format!("/children/{parent_inscription_id}"),
+      format!("/inscription/{inscription_id}"),
       StatusCode::OK,
-      format!(".*<title>Inscription 0 Children</title>.*<h1><a href=/inscription/{parent_inscription_id}>Inscription 0</a> Children</h1>.*<div class=thumbnails>.*<a href=/inscription/{inscription_id}><iframe .* src=/preview/{inscription_id}></iframe></a>.*"),
+      format!(
+        ".*<dt>provenance</dt>
+  <dd><ul class=provenance><li><a class=monospace href=/inscription/{parent_inscription_id}>{parent_inscription_id}</a><ul><li><span class=monospace>{inscription_id}</span></li></ul></li></ul></dd>.*"
+      ),
     );
   }
 
@@ -3510,6 +5341,7 @@ mod tests {
 .*<a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>.*
     <div class=center>
       <a href=/children/{parent_inscription_id}>all</a>
+      <a href=/collection/{parent_inscription_id}>collection</a>
     </div>.*"
       ),
     );
@@ -3584,6 +5416,138 @@ mod tests {
     );
   }
 
+  #[test]
+  fn rune_holders() {
+    let server = TestServer::new_server(
+      test_bitcoincore_rpc::builder()
+        .network(bitcoin::Network::Regtest)
+        .build(),
+      None,
+      &[
+        "--chain",
+        "regtest",
+        "--index-addresses",
+        "--index-runes-pre-alpha-i-agree-to-get-rekt",
+      ],
+      &["--enable-json-api"],
+    );
+
+    server.mine_blocks(1);
+
+    let rune = Rune(RUNE);
+
+    server.assert_response(
+      format!("/rune/{rune}/holders"),
+      StatusCode::NOT_FOUND,
+      &format!("rune {rune} not found"),
+    );
+
+    let address = Address::from_script(&address().script_pubkey(), Network::Regtest).unwrap();
+
+    let etching = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Witness::new())],
+      outputs: 2,
+      recipient: Some(address.clone()),
+      op_return: Some(
+        Runestone {
+          edicts: vec![
+            Edict {
+              id: 0,
+              amount: 100,
+              output: 0,
+            },
+            Edict {
+              id: 0,
+              amount: 200,
+              output: 1,
+            },
+          ],
+          etching: Some(Etching {
+            rune,
+            divisibility: 1,
+            ..Default::default()
+          }),
+          ..Default::default()
+        }
+        .encipher(),
+      ),
+      ..Default::default()
+    });
+
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      format!("/rune/{rune}/holders"),
+      StatusCode::OK,
+      format!(
+        ".*<title>Rune {rune} Holders</title>.*
+<h1><a href=/rune/{rune}>Rune {rune}</a> Holders</h1>
+<table>
+  <tr>
+    <th>address</th>
+    <th>amount</th>
+    <th>outputs</th>
+  </tr>
+  <tr>
+    <td><a class=monospace href=/address/{address}>{address}</a></td>
+    <td>30</td>
+    <td>
+      <a class=monospace href=/output/{etching}:0>{etching}:0</a>
+      <a class=monospace href=/output/{etching}:1>{etching}:1</a>
+    </td>
+  </tr>
+</table>
+.*"
+      ),
+    );
+
+    let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 1, 0, Witness::new())],
+      ..Default::default()
+    });
+
+    server.mine_blocks(1);
+
+    let holders = server
+      .get_json::<RuneHoldersJson>(format!("/rune/{rune}/holders"))
+      .holders
+      .into_iter()
+      .map(|holder| (holder.address.is_some(), holder.amount, holder.outpoints))
+      .collect::<Vec<(bool, u128, Vec<OutPoint>)>>();
+
+    assert_eq!(
+      holders,
+      [
+        (
+          true,
+          200,
+          vec![OutPoint {
+            txid: etching,
+            vout: 1,
+          }]
+        ),
+        (false, 100, vec![OutPoint { txid, vout: 0 }]),
+      ]
+    );
+
+    let id = RuneId {
+      height: 2,
+      index: 1,
+    };
+
+    let (first, more) = server.index.get_rune_holders_paginated(id, 1, 0).unwrap();
+    assert_eq!(first.len(), 1);
+    assert!(more);
+
+    let (second, more) = server.index.get_rune_holders_paginated(id, 1, 1).unwrap();
+    assert_eq!(second.len(), 1);
+    assert!(!more);
+
+    let mut amounts = [first[0].amount, second[0].amount];
+    amounts.sort();
+    assert_eq!(amounts, [100, 200]);
+  }
+
   #[test]
   fn runes_are_displayed_on_rune_page() {
     let server = TestServer::new_with_regtest_with_index_runes();
@@ -3664,6 +5628,8 @@ mod tests {
   <dd>\$340282366920938463463374607431768211455</dd>
   <dt>burned</dt>
   <dd>\$0</dd>
+  <dt>holders</dt>
+  <dd><a href=/rune/AAAAAAAAAAAAA/holders>link</a></dd>
   <dt>divisibility</dt>
   <dd>0</dd>
   <dt>symbol</dt>
@@ -3907,4 +5873,157 @@ mod tests {
       }
     );
   }
+
+  #[test]
+  fn recursive_inscription_endpoint() {
+    let server = TestServer::new_with_regtest_with_index_sats();
+    server.mine_blocks(1);
+
+    let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      ..Default::default()
+    });
+
+    server.mine_blocks(1);
+
+    let inscription_id = InscriptionId { txid, index: 0 };
+
+    let json =
+      server.get_json::<InscriptionRecursiveJson>(format!("/r/inscription/{inscription_id}"));
+
+    assert_eq!(
+      json,
+      InscriptionRecursiveJson {
+        content_length: Some(5),
+        content_type: Some("text/plain".into()),
+        height: 2,
+        id: inscription_id,
+        number: 0,
+        sat: Some(Sat(50 * COIN_VALUE)),
+        satpoint: SatPoint {
+          outpoint: OutPoint { txid, vout: 0 },
+          offset: 0,
+        },
+        timestamp: json.timestamp,
+      }
+    );
+
+    server.assert_response_regex(
+      format!("/r/inscription/{}", test::inscription_id(1)),
+      StatusCode::NOT_FOUND,
+      ".*",
+    );
+  }
+
+  #[test]
+  fn recursive_children_endpoint() {
+    let server = TestServer::new_with_regtest();
+    server.mine_blocks(1);
+
+    let parent_txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      ..Default::default()
+    });
+
+    server.mine_blocks(1);
+
+    let parent_inscription_id = InscriptionId {
+      txid: parent_txid,
+      index: 0,
+    };
+
+    assert_eq!(
+      server.get_json::<ChildrenJson>(format!("/r/children/{parent_inscription_id}")),
+      ChildrenJson {
+        ids: Vec::new(),
+        more: false,
+        page: 0,
+      }
+    );
+
+    let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[
+        (
+          2,
+          0,
+          0,
+          Inscription {
+            content_type: Some("text/plain".into()),
+            body: Some("hello".into()),
+            parent: Some(parent_inscription_id.parent_value()),
+            ..Default::default()
+          }
+          .to_witness(),
+        ),
+        (2, 1, 0, Default::default()),
+      ],
+      ..Default::default()
+    });
+
+    server.mine_blocks(1);
+
+    assert_eq!(
+      server.get_json::<ChildrenJson>(format!("/r/children/{parent_inscription_id}")),
+      ChildrenJson {
+        ids: vec![InscriptionId { txid, index: 0 }],
+        more: false,
+        page: 0,
+      }
+    );
+
+    assert_eq!(
+      server.get_json::<ChildrenJson>(format!("/r/children/{parent_inscription_id}/1")),
+      ChildrenJson {
+        ids: Vec::new(),
+        more: false,
+        page: 1,
+      }
+    );
+
+    server.assert_response_regex(
+      format!("/r/children/{}", inscription_id(1)),
+      StatusCode::NOT_FOUND,
+      ".*",
+    );
+  }
+
+  #[test]
+  fn recursive_sat_endpoint() {
+    let server = TestServer::new_with_regtest_with_index_sats();
+    server.mine_blocks(1);
+
+    let txid = server.bitcoin_rpc_server.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      ..Default::default()
+    });
+
+    server.mine_blocks(1);
+
+    assert_eq!(
+      server.get_json::<SatInscriptionsJson>(format!("/r/sat/{}", 50 * COIN_VALUE)),
+      SatInscriptionsJson {
+        ids: vec![InscriptionId { txid, index: 0 }],
+        more: false,
+        page: 0,
+      }
+    );
+
+    assert_eq!(
+      server.get_json::<SatInscriptionsJson>("/r/sat/0"),
+      SatInscriptionsJson {
+        ids: Vec::new(),
+        more: false,
+        page: 0,
+      }
+    );
+  }
+
+  #[test]
+  fn recursive_sat_endpoint_requires_sat_index() {
+    TestServer::new().assert_response(
+      "/r/sat/0",
+      StatusCode::NOT_FOUND,
+      "this server has no sat index",
+    );
+  }
 }
@@ -1,8 +1,10 @@
 use super::*;
 
+#[derive(Debug)]
 pub(super) enum ServerError {
   Internal(Error),
   BadRequest(String),
+  NotAcceptable(String),
   NotFound(String),
 }
 
@@ -22,6 +24,7 @@ impl IntoResponse for ServerError {
         )
           .into_response()
       }
+      Self::NotAcceptable(message) => (StatusCode::NOT_ACCEPTABLE, message).into_response(),
       Self::NotFound(message) => (
         StatusCode::NOT_FOUND,
         [(header::CACHE_CONTROL, HeaderValue::from_static("no-store"))],
@@ -8,45 +8,85 @@ use {
     bip32::{ChildNumber, DerivationPath, ExtendedPrivKey, Fingerprint},
     Network,
   },
+  bitcoin::{blockdata::witness::Witness, psbt::Psbt},
   bitcoincore_rpc::bitcoincore_rpc_json::{ImportDescriptors, Timestamp},
+  coin_control::CoinControl,
   fee_rate::FeeRate,
+  journal::Journal,
   miniscript::descriptor::{Descriptor, DescriptorSecretKey, DescriptorXKey, Wildcard},
+  store::Store,
   transaction_builder::TransactionBuilder,
 };
 
+pub mod abandon;
 pub mod balance;
+pub mod bump;
 pub mod cardinals;
+mod coin_control;
 pub mod create;
+pub mod etch;
+pub mod finalize_psbt;
+pub mod freeze;
 pub mod inscribe;
 pub mod inscriptions;
+mod journal;
+pub mod label;
+pub mod mint;
+pub mod offer;
 pub mod outputs;
 pub mod receive;
 mod restore;
+pub mod resume;
+pub mod runes;
 pub mod sats;
 pub mod send;
+mod store;
 pub mod transaction_builder;
 pub mod transactions;
+pub mod unfreeze;
 
 #[derive(Debug, Parser)]
 pub(crate) enum Wallet {
+  #[command(about = "Abandon pending inscription and recover its commit output")]
+  Abandon(abandon::Abandon),
   #[command(about = "Get wallet balance")]
   Balance,
+  #[command(about = "Bump fee of unconfirmed transaction")]
+  Bump(bump::Bump),
   #[command(about = "Create new wallet")]
   Create(create::Create),
+  #[command(about = "Etch rune")]
+  Etch(etch::Etch),
+  #[command(about = "Finalize and broadcast a signed PSBT")]
+  FinalizePsbt(finalize_psbt::FinalizePsbt),
+  #[command(about = "Freeze wallet outputs so they are never used as cardinal inputs")]
+  Freeze(freeze::Freeze),
   #[command(about = "Create inscription")]
   Inscribe(inscribe::Inscribe),
   #[command(about = "List wallet inscriptions")]
   Inscriptions,
+  #[command(about = "Label wallet output")]
+  Label(label::Label),
+  #[command(about = "Mint rune")]
+  Mint(mint::Mint),
+  #[command(subcommand, about = "Create or accept inscription offers")]
+  Offer(offer::Offer),
   #[command(about = "Generate receive address")]
   Receive,
   #[command(about = "Restore wallet")]
   Restore(restore::Restore),
+  #[command(about = "Resume pending inscriptions")]
+  Resume(resume::Resume),
+  #[command(about = "List wallet rune balances")]
+  Runes,
   #[command(about = "List wallet satoshis")]
   Sats(sats::Sats),
   #[command(about = "Send sat or inscription")]
   Send(send::Send),
   #[command(about = "See wallet transactions")]
   Transactions(transactions::Transactions),
+  #[command(about = "Unfreeze wallet outputs")]
+  Unfreeze(unfreeze::Unfreeze),
   #[command(about = "List all unspent outputs in wallet")]
   Outputs,
   #[command(about = "List unspent cardinal outputs in wallet")]
@@ -56,15 +96,26 @@ pub(crate) enum Wallet {
 impl Wallet {
   pub(crate) fn run(self, options: Options) -> SubcommandResult {
     match self {
+      Self::Abandon(abandon) => abandon.run(options),
       Self::Balance => balance::run(options),
+      Self::Bump(bump) => bump.run(options),
       Self::Create(create) => create.run(options),
+      Self::Etch(etch) => etch.run(options),
+      Self::FinalizePsbt(finalize_psbt) => finalize_psbt.run(options),
+      Self::Freeze(freeze) => freeze.run(options),
       Self::Inscribe(inscribe) => inscribe.run(options),
       Self::Inscriptions => inscriptions::run(options),
+      Self::Label(label) => label.run(options),
+      Self::Mint(mint) => mint.run(options),
+      Self::Offer(offer) => offer.run(options),
       Self::Receive => receive::run(options),
       Self::Restore(restore) => restore.run(options),
+      Self::Resume(resume) => resume.run(options),
+      Self::Runes => runes::run(options),
       Self::Sats(sats) => sats.run(options),
       Self::Send(send) => send.run(options),
       Self::Transactions(transactions) => transactions.run(options),
+      Self::Unfreeze(unfreeze) => unfreeze.run(options),
       Self::Outputs => outputs::run(options),
       Self::Cardinals => cardinals::run(options),
     }
@@ -80,6 +131,120 @@ fn get_change_address(client: &Client, chain: Chain) -> Result<Address> {
   )
 }
 
+fn add_witness_utxos(index: &Index, psbt: &mut Psbt) -> Result {
+  for (input, psbt_input) in psbt.unsigned_tx.input.iter().zip(psbt.inputs.iter_mut()) {
+    let outpoint = input.previous_output;
+
+    let transaction = index
+      .get_transaction(outpoint.txid)?
+      .ok_or_else(|| anyhow!("transaction {} not found", outpoint.txid))?;
+
+    psbt_input.witness_utxo = Some(
+      transaction
+        .output
+        .into_iter()
+        .nth(outpoint.vout.try_into().unwrap())
+        .ok_or_else(|| anyhow!("output {outpoint} not found"))?,
+    );
+  }
+
+  Ok(())
+}
+
+fn get_rune_free_cardinals(index: &Index, options: &Options) -> Result<Vec<(OutPoint, Amount)>> {
+  let unspent_outputs = index.get_unspent_outputs(crate::wallet::Wallet::load(options)?)?;
+
+  let locked_outputs = index.get_locked_outputs(crate::wallet::Wallet::load(options)?)?;
+
+  let coin_control = CoinControl::load(options)?;
+
+  let inscribed_outputs = index
+    .get_inscriptions(&unspent_outputs)?
+    .keys()
+    .map(|satpoint| satpoint.outpoint)
+    .collect::<HashSet<OutPoint>>();
+
+  let mut cardinals = Vec::new();
+
+  for (outpoint, value) in unspent_outputs {
+    if inscribed_outputs.contains(&outpoint)
+      || locked_outputs.contains(&outpoint)
+      || coin_control.is_frozen(outpoint)
+      || !index.get_rune_balances_for_outpoint(outpoint)?.is_empty()
+    {
+      continue;
+    }
+
+    cardinals.push((outpoint, value));
+  }
+
+  Ok(cardinals)
+}
+
+/// Adds cardinals to `transaction`, largest first, until it pays for itself
+/// and its fee, with whatever remains going to the output at index `change`.
+/// Since that output usually receives runes, it is never given less than the
+/// target postage.
+fn fund_rune_transaction(
+  transaction: &mut Transaction,
+  mut cardinals: Vec<(OutPoint, Amount)>,
+  mut input_value: u64,
+  change: usize,
+  fee_rate: FeeRate,
+) -> Result {
+  cardinals.sort_by_key(|(_outpoint, value)| *value);
+
+  let output_value = transaction
+    .output
+    .iter()
+    .enumerate()
+    .filter(|(vout, _output)| *vout != change)
+    .map(|(_vout, output)| output.value)
+    .sum::<u64>();
+
+  loop {
+    let fee = estimate_fee(transaction, fee_rate).to_sat();
+
+    if input_value >= output_value + fee + TransactionBuilder::TARGET_POSTAGE.to_sat() {
+      transaction.output[change].value = input_value - output_value - fee;
+      return Ok(());
+    }
+
+    let (outpoint, value) = cardinals.pop().ok_or_else(|| {
+      anyhow!(
+        "wallet does not contain enough cardinal UTXOs, please add additional funds to wallet."
+      )
+    })?;
+
+    transaction.input.push(unsigned_input(outpoint));
+
+    input_value += value.to_sat();
+  }
+}
+
+fn unsigned_input(previous_output: OutPoint) -> TxIn {
+  TxIn {
+    previous_output,
+    script_sig: ScriptBuf::new(),
+    sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+    witness: Witness::new(),
+  }
+}
+
+/// Estimates the fee of `transaction` at `fee_rate`, assuming that its
+/// unsigned inputs are taproot key path spends.
+fn estimate_fee(transaction: &Transaction, fee_rate: FeeRate) -> Amount {
+  let mut transaction = transaction.clone();
+
+  for input in &mut transaction.input {
+    if input.witness.is_empty() {
+      input.witness = Witness::from_slice(&[[0; 64]]);
+    }
+  }
+
+  fee_rate.fee(transaction.vsize())
+}
+
 pub(crate) fn initialize_wallet(options: &Options, seed: [u8; 64]) -> Result {
   let client = options.bitcoin_rpc_client_for_wallet_command(true)?;
   let network = options.chain().network();
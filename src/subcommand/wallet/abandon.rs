@@ -0,0 +1,152 @@
+use {
+  super::{
+    journal::{Pending, Status},
+    *,
+  },
+  bitcoin::{
+    key::KeyPair,
+    locktime::absolute::LockTime,
+    secp256k1::{constants::SCHNORR_SIGNATURE_SIZE, Message},
+    sighash::{Prevouts, SighashCache, TapSighashType},
+    taproot::Signature,
+  },
+};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Abandon {
+  #[arg(
+    long,
+    help = "Use fee rate of <FEE_RATE> sats/vB for sweep transaction."
+  )]
+  fee_rate: FeeRate,
+  #[arg(help = "Abandon inscription with commit transaction <COMMIT>.")]
+  commit: Txid,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Output {
+  pub commit: Txid,
+  pub sweep: Option<Txid>,
+}
+
+impl Abandon {
+  pub(crate) fn run(self, options: Options) -> SubcommandResult {
+    let client = options.bitcoin_rpc_client_for_wallet_command(false)?;
+
+    let mut journal = Journal::load(&options)?;
+
+    let pending = journal.get(self.commit).cloned().ok_or_else(|| {
+      anyhow!(
+        "no pending inscription with commit transaction {}",
+        self.commit
+      )
+    })?;
+
+    let sweep = match pending.status(&client)? {
+      Status::Unsent => {
+        // since the commit transaction spends them, its inputs being unspent
+        // means it is neither in the mempool nor in a block
+        for input in &pending.commit.input {
+          let outpoint = input.previous_output;
+          if client
+            .get_tx_out(&outpoint.txid, outpoint.vout, Some(true))?
+            .is_none()
+          {
+            bail!(
+              "commit transaction {} is not in wallet, but its input {outpoint} has been spent",
+              self.commit
+            );
+          }
+        }
+
+        journal.remove(self.commit)?;
+
+        None
+      }
+      Status::Committed => Some(self.sweep(&client, options.chain(), &mut journal, pending)?),
+      Status::Spending if pending.sweep.is_some() => {
+        Some(self.sweep(&client, options.chain(), &mut journal, pending)?)
+      }
+      Status::Spending => bail!(
+        "reveal transaction {} has already been broadcast",
+        pending.output.reveal
+      ),
+      Status::Settled => bail!(
+        "commit output {} has already been spent by a confirmed transaction",
+        pending.commit_outpoint()
+      ),
+    };
+
+    Ok(Box::new(Output {
+      commit: self.commit,
+      sweep,
+    }))
+  }
+
+  /// Sweep the commit output back to the wallet, keeping the inscription in
+  /// the journal until the sweep confirms.
+  fn sweep(
+    &self,
+    client: &Client,
+    chain: Chain,
+    journal: &mut Journal,
+    mut pending: Pending,
+  ) -> Result<Txid> {
+    let outpoint = pending.commit_outpoint();
+    let commit_output = &pending.reveal_prevouts[pending.commit_input];
+
+    let destination = get_change_address(client, chain)?;
+
+    let mut sweep = Transaction {
+      version: 2,
+      lock_time: LockTime::ZERO,
+      input: vec![TxIn {
+        previous_output: outpoint,
+        script_sig: ScriptBuf::new(),
+        sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+        witness: Witness::from_slice(&[&[0; SCHNORR_SIGNATURE_SIZE]]),
+      }],
+      output: vec![TxOut {
+        script_pubkey: destination.script_pubkey(),
+        value: commit_output.value,
+      }],
+    };
+
+    let fee = self.fee_rate.fee(sweep.vsize()).to_sat();
+
+    sweep.output[0].value = commit_output
+      .value
+      .checked_sub(fee)
+      .filter(|value| *value >= destination.script_pubkey().dust_value().to_sat())
+      .ok_or_else(|| anyhow!("commit output {outpoint} cannot pay sweep fee of {fee} sats"))?;
+
+    let sighash = SighashCache::new(&sweep)
+      .taproot_key_spend_signature_hash(
+        0,
+        &Prevouts::All(&[commit_output]),
+        TapSighashType::Default,
+      )
+      .expect("signature hash should compute");
+
+    let secp256k1 = Secp256k1::new();
+
+    let sig = secp256k1.sign_schnorr(
+      &Message::from_slice(sighash.as_ref()).expect("should be cryptographically secure hash"),
+      &KeyPair::from_secret_key(&secp256k1, &pending.recovery_key.inner),
+    );
+
+    sweep.input[0].witness = Witness::from_slice(&[Signature {
+      sig,
+      hash_ty: TapSighashType::Default,
+    }
+    .to_vec()]);
+
+    let txid = client.send_raw_transaction(&sweep)?;
+
+    pending.sweep = Some(sweep);
+
+    journal.insert(pending)?;
+
+    Ok(txid)
+  }
+}
@@ -0,0 +1,348 @@
+use {
+  super::{inscribe::InscriptionInfo, *},
+  bitcoin::{locktime::absolute::LockTime, secp256k1::constants::SCHNORR_SIGNATURE_SIZE},
+  bitcoincore_rpc::bitcoincore_rpc_json::SignRawTransactionInput,
+  journal::Pending,
+};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Bump {
+  #[arg(
+    long,
+    help = "Spend an output of <TXID> with a child transaction paying the fee instead of replacing <TXID>."
+  )]
+  cpfp: bool,
+  #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vB.")]
+  fee_rate: FeeRate,
+  #[arg(help = "Bump fee of unconfirmed transaction <TXID>.")]
+  txid: Txid,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Output {
+  pub cpfp: bool,
+  pub fee: u64,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub inscriptions: Option<Vec<InscriptionInfo>>,
+  pub transaction: Txid,
+}
+
+impl Bump {
+  pub(crate) fn run(self, options: Options) -> SubcommandResult {
+    let index = Index::open(&options)?;
+    index.update()?;
+
+    let client = options.bitcoin_rpc_client_for_wallet_command(false)?;
+
+    let chain = options.chain();
+
+    let tx = client
+      .get_raw_transaction(&self.txid, None)
+      .with_context(|| format!("transaction {} not found", self.txid))?;
+
+    if client
+      .get_raw_transaction_info(&self.txid, None)?
+      .confirmations
+      .unwrap_or_default()
+      > 0
+    {
+      bail!("transaction {} is already confirmed", self.txid);
+    }
+
+    let input_value = tx
+      .input
+      .iter()
+      .map(|input| Ok(Self::get_tx_out(&client, input.previous_output)?.value))
+      .sum::<Result<u64>>()?;
+
+    let fee = input_value - tx.output.iter().map(|output| output.value).sum::<u64>();
+
+    let utxos = get_rune_free_cardinals(&index, &options)?
+      .into_iter()
+      .filter(|(outpoint, _amount)| {
+        !tx
+          .input
+          .iter()
+          .any(|input| input.previous_output == *outpoint)
+      })
+      .collect::<BTreeMap<OutPoint, Amount>>();
+
+    let mut journal = Journal::load(&options)?;
+
+    journal.prune(&client)?;
+
+    // the journaled reveal spends the commit by txid, so a replacement commit
+    // would strand the commit output
+    let commit_outpoint = journal.get(self.txid).map(Pending::commit_outpoint);
+
+    if self.cpfp || !tx.input.iter().any(|input| input.sequence.is_rbf()) {
+      self.child_pays_for_parent(chain, &client, commit_outpoint, fee, &tx, &utxos)
+    } else if commit_outpoint.is_some() {
+      bail!(
+        "transaction {} is the commit transaction of a pending inscription and cannot be replaced, bump it with `--cpfp` instead",
+        self.txid
+      );
+    } else {
+      self.replace_by_fee(chain, &client, fee, &mut journal, &tx, &utxos)
+    }
+  }
+
+  fn replace_by_fee(
+    &self,
+    chain: Chain,
+    client: &Client,
+    fee: u64,
+    journal: &mut Journal,
+    tx: &Transaction,
+    utxos: &BTreeMap<OutPoint, Amount>,
+  ) -> SubcommandResult {
+    let pending = journal.get_by_reveal(self.txid).cloned();
+
+    let change = get_change_address(client, chain)?;
+
+    let mut replacement = tx.clone();
+
+    replacement.input.push(TxIn {
+      previous_output: OutPoint::null(),
+      script_sig: ScriptBuf::new(),
+      sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+      witness: Witness::from_slice(&[&[0; SCHNORR_SIGNATURE_SIZE]]),
+    });
+
+    replacement.output.push(TxOut {
+      script_pubkey: change.script_pubkey(),
+      value: 0,
+    });
+
+    let target = self.fee_rate.fee(replacement.vsize()).to_sat();
+
+    if target <= fee {
+      bail!(
+        "transaction {} already pays {fee} sats, which is at least {target} sats at new fee rate",
+        self.txid
+      );
+    }
+
+    let (cardinal, value) = Self::select_cardinal_utxo(
+      utxos,
+      target - fee + change.script_pubkey().dust_value().to_sat(),
+    )?;
+
+    replacement.input.last_mut().unwrap().previous_output = cardinal;
+    replacement.output.last_mut().unwrap().value = value + fee - target;
+
+    for (i, input) in replacement.input.iter_mut().enumerate() {
+      if pending
+        .as_ref()
+        .map(|pending| pending.commit_input != i)
+        .unwrap_or(true)
+      {
+        input.witness.clear();
+      }
+    }
+
+    let (replacement, pending) = match pending {
+      Some(mut pending) => {
+        let mut prevouts = pending.reveal_prevouts.clone();
+        prevouts.push(Self::get_tx_out(client, cardinal)?);
+
+        pending.sign_commit_input(&mut replacement, &prevouts);
+
+        let replacement = pending.sign_with_wallet(client, &replacement)?;
+
+        pending.reveal_prevouts = prevouts;
+        pending.replace_reveal(replacement.clone(), target - fee);
+
+        (replacement, Some(pending))
+      }
+      None => {
+        let signed = client.sign_raw_transaction_with_wallet(&replacement, None, None)?;
+
+        if !signed.complete {
+          bail!(
+            "failed to sign replacement for transaction {}, try bumping with `--cpfp`",
+            self.txid
+          );
+        }
+
+        (consensus::encode::deserialize(&signed.hex)?, None)
+      }
+    };
+
+    let transaction = client.send_raw_transaction(&replacement)?;
+
+    let inscriptions = match pending {
+      Some(pending) => {
+        let inscriptions = pending.output.inscriptions.clone();
+        journal.insert(pending)?;
+        Some(inscriptions)
+      }
+      None => None,
+    };
+
+    Ok(Box::new(Output {
+      cpfp: false,
+      fee: target,
+      inscriptions,
+      transaction,
+    }))
+  }
+
+  fn child_pays_for_parent(
+    &self,
+    chain: Chain,
+    client: &Client,
+    commit_outpoint: Option<OutPoint>,
+    fee: u64,
+    tx: &Transaction,
+    utxos: &BTreeMap<OutPoint, Amount>,
+  ) -> SubcommandResult {
+    #[derive(Deserialize)]
+    struct AddressInfo {
+      ismine: bool,
+    }
+
+    let mut parent = None;
+
+    for (vout, output) in tx.output.iter().enumerate() {
+      let outpoint = OutPoint {
+        txid: self.txid,
+        vout: vout.try_into().unwrap(),
+      };
+
+      if Some(outpoint) == commit_outpoint {
+        continue;
+      }
+
+      let Ok(address) = chain.address_from_script(&output.script_pubkey) else {
+        continue;
+      };
+
+      if client
+        .call::<AddressInfo>("getaddressinfo", &[address.to_string().into()])?
+        .ismine
+      {
+        parent = Some((outpoint, output));
+        break;
+      }
+    }
+
+    let (outpoint, parent_output) = parent.ok_or_else(|| {
+      anyhow!(
+        "transaction {} has no outputs owned by the wallet",
+        self.txid
+      )
+    })?;
+
+    let change = get_change_address(client, chain)?;
+
+    let mut child = Transaction {
+      version: 2,
+      lock_time: LockTime::ZERO,
+      input: vec![
+        TxIn {
+          previous_output: outpoint,
+          script_sig: ScriptBuf::new(),
+          sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+          witness: Witness::from_slice(&[&[0; SCHNORR_SIGNATURE_SIZE]]),
+        },
+        TxIn {
+          previous_output: OutPoint::null(),
+          script_sig: ScriptBuf::new(),
+          sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+          witness: Witness::from_slice(&[&[0; SCHNORR_SIGNATURE_SIZE]]),
+        },
+      ],
+      output: vec![
+        TxOut {
+          script_pubkey: get_change_address(client, chain)?.script_pubkey(),
+          value: parent_output.value,
+        },
+        TxOut {
+          script_pubkey: change.script_pubkey(),
+          value: 0,
+        },
+      ],
+    };
+
+    let target = self.fee_rate.fee(tx.vsize() + child.vsize()).to_sat();
+
+    if target <= fee {
+      bail!(
+        "transaction {} already pays {fee} sats, which is at least {target} sats at new fee rate",
+        self.txid
+      );
+    }
+
+    let child_fee = target - fee;
+
+    let (cardinal, value) = Self::select_cardinal_utxo(
+      utxos,
+      child_fee + change.script_pubkey().dust_value().to_sat(),
+    )?;
+
+    child.input[1].previous_output = cardinal;
+    child.output[1].value = value - child_fee;
+
+    for input in &mut child.input {
+      input.witness.clear();
+    }
+
+    let signed = client.sign_raw_transaction_with_wallet(
+      &child,
+      Some(&[SignRawTransactionInput {
+        txid: outpoint.txid,
+        vout: outpoint.vout,
+        script_pub_key: parent_output.script_pubkey.clone(),
+        redeem_script: None,
+        amount: Some(Amount::from_sat(parent_output.value)),
+      }]),
+      None,
+    )?;
+
+    if !signed.complete {
+      bail!("failed to sign child transaction spending {outpoint}");
+    }
+
+    let child: Transaction = consensus::encode::deserialize(&signed.hex)?;
+
+    Ok(Box::new(Output {
+      cpfp: true,
+      fee: child_fee,
+      inscriptions: None,
+      transaction: client.send_raw_transaction(&child)?,
+    }))
+  }
+
+  /// Without `-txindex`, `getrawtransaction` only sees unconfirmed
+  /// transactions, so outputs of confirmed transactions are fetched with
+  /// `gettxout`, which ignores spends by unconfirmed transactions when
+  /// `include_mempool` is false.
+  fn get_tx_out(client: &Client, outpoint: OutPoint) -> Result<TxOut> {
+    if let Some(tx_out) = client.get_tx_out(&outpoint.txid, outpoint.vout, Some(false))? {
+      return Ok(TxOut {
+        value: tx_out.value.to_sat(),
+        script_pubkey: ScriptBuf::from(tx_out.script_pub_key.hex),
+      });
+    }
+
+    client
+      .get_raw_transaction(&outpoint.txid, None)?
+      .output
+      .into_iter()
+      .nth(outpoint.vout.try_into().unwrap())
+      .ok_or_else(|| anyhow!("output {outpoint} not found"))
+  }
+
+  fn select_cardinal_utxo(
+    utxos: &BTreeMap<OutPoint, Amount>,
+    minimum: u64,
+  ) -> Result<(OutPoint, u64)> {
+    utxos
+      .iter()
+      .map(|(outpoint, amount)| (*outpoint, amount.to_sat()))
+      .filter(|(_outpoint, value)| *value >= minimum)
+      .min_by_key(|(_outpoint, value)| *value)
+      .ok_or_else(|| anyhow!("wallet contains no cardinal utxo worth at least {minimum} sats"))
+  }
+}
@@ -0,0 +1,103 @@
+use {super::*, std::collections::BTreeSet};
+
+#[derive(Default, Deserialize, Serialize)]
+struct State {
+  frozen: BTreeSet<OutPoint>,
+  labels: BTreeMap<OutPoint, String>,
+}
+
+/// Frozen outputs and output labels, which unlike outputs locked with Bitcoin
+/// Core's `lockunspent` survive node restarts.
+pub(crate) struct CoinControl {
+  state: State,
+  store: Store,
+}
+
+impl CoinControl {
+  pub(crate) fn load(options: &Options) -> Result<Self> {
+    let store = Store::new(options, "coin_control.json")?;
+
+    let state = store.load()?;
+
+    Ok(Self { state, store })
+  }
+
+  pub(crate) fn frozen(&self) -> &BTreeSet<OutPoint> {
+    &self.state.frozen
+  }
+
+  pub(crate) fn is_frozen(&self, outpoint: OutPoint) -> bool {
+    self.state.frozen.contains(&outpoint)
+  }
+
+  pub(crate) fn label(&self, outpoint: OutPoint) -> Option<&String> {
+    self.state.labels.get(&outpoint)
+  }
+
+  pub(crate) fn freeze(&mut self, outpoints: &[OutPoint]) -> Result {
+    self.state.frozen.extend(outpoints);
+    self.save()
+  }
+
+  pub(crate) fn unfreeze(&mut self, outpoints: &[OutPoint]) -> Result {
+    for outpoint in outpoints {
+      self.state.frozen.remove(outpoint);
+    }
+    self.save()
+  }
+
+  pub(crate) fn set_label(&mut self, outpoint: OutPoint, label: Option<String>) -> Result {
+    match label {
+      Some(label) => self.state.labels.insert(outpoint, label),
+      None => self.state.labels.remove(&outpoint),
+    };
+    self.save()
+  }
+
+  /// Outputs in `utxos` which may not be used as cardinal inputs: frozen
+  /// outputs, and, if `inputs` is not empty, any output not in `inputs`.
+  /// Inputs holding inscriptions or runes are rejected, since the transaction
+  /// builder would otherwise silently skip them.
+  pub(crate) fn excluded_utxos(
+    &self,
+    index: &Index,
+    utxos: &BTreeMap<OutPoint, Amount>,
+    inputs: &[OutPoint],
+  ) -> Result<BTreeSet<OutPoint>> {
+    for input in inputs {
+      let Some(value) = utxos.get(input) else {
+        bail!("input {input} not in wallet");
+      };
+
+      if self.is_frozen(*input) {
+        bail!("input {input} is frozen");
+      }
+
+      if let Some(inscription) = index
+        .get_inscriptions(&[(*input, *value)].into())?
+        .values()
+        .next()
+      {
+        bail!("input {input} contains inscription {inscription}");
+      }
+
+      if !index.get_rune_balances_for_outpoint(*input)?.is_empty() {
+        bail!("input {input} contains runes");
+      }
+    }
+
+    Ok(
+      utxos
+        .keys()
+        .filter(|outpoint| {
+          self.is_frozen(**outpoint) || !(inputs.is_empty() || inputs.contains(outpoint))
+        })
+        .copied()
+        .collect(),
+    )
+  }
+
+  fn save(&self) -> Result {
+    self.store.save(&self.state)
+  }
+}
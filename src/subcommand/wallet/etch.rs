@@ -0,0 +1,162 @@
+use {
+  super::*,
+  crate::runes::{Edict, Etching, Runestone, MAX_DIVISIBILITY, MAX_LIMIT},
+  bitcoin::blockdata::locktime::absolute::LockTime,
+};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Etch {
+  #[arg(long, default_value = "0", help = "Etch with <DIVISIBILITY>.")]
+  divisibility: u8,
+  #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vB.")]
+  fee_rate: FeeRate,
+  #[arg(
+    long,
+    help = "Allow anyone to mint up to <LIMIT> runes per transaction."
+  )]
+  limit: Option<Decimal>,
+  #[arg(long, help = "Etch <RUNE>.")]
+  rune: Rune,
+  #[arg(
+    long,
+    help = "Send <SUPPLY> runes to the wallet in the etching transaction."
+  )]
+  supply: Decimal,
+  #[arg(long, help = "Etch with currency <SYMBOL>.")]
+  symbol: Option<char>,
+  #[arg(long, help = "Allow minting for <TERM> blocks after etching.")]
+  term: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Output {
+  pub rune: Rune,
+  pub supply: String,
+  pub transaction: Txid,
+}
+
+impl Etch {
+  pub(crate) fn run(self, options: Options) -> SubcommandResult {
+    let index = Index::open(&options)?;
+
+    if !index.has_rune_index() {
+      bail!("`ord wallet etch` requires index created with `--index-runes-pre-alpha-i-agree-to-get-rekt` flag");
+    }
+
+    index.update()?;
+
+    if self.divisibility > MAX_DIVISIBILITY {
+      bail!("divisibility must be less than or equal to {MAX_DIVISIBILITY}");
+    }
+
+    if self.term == Some(0) {
+      bail!("term must be greater than zero");
+    }
+
+    let supply = self.supply.to_amount(self.divisibility)?;
+
+    let limit = self
+      .limit
+      .map(|limit| limit.to_amount(self.divisibility))
+      .transpose()?;
+
+    // an etching with a term but no limit is open with the maximum limit
+    let effective_limit = match (limit, self.term) {
+      (None, Some(_)) => Some(MAX_LIMIT),
+      (limit, _) => limit,
+    };
+
+    match effective_limit {
+      Some(limit) => {
+        if limit > MAX_LIMIT {
+          bail!("limit must be less than or equal to {MAX_LIMIT}");
+        }
+
+        if supply > limit {
+          bail!("supply must be less than or equal to limit");
+        }
+      }
+      None => {
+        if supply == 0 {
+          bail!("supply must be greater than zero");
+        }
+      }
+    }
+
+    let minimum = Rune::minimum_at_height(Height(index.block_count()?));
+
+    if self.rune < minimum {
+      bail!(
+        "rune `{}` is less than minimum for next block: `{minimum}`",
+        self.rune
+      );
+    }
+
+    if index.rune(self.rune)?.is_some() {
+      bail!("rune `{}` has already been etched", self.rune);
+    }
+
+    let runestone = Runestone {
+      etching: Some(Etching {
+        divisibility: self.divisibility,
+        limit,
+        rune: self.rune,
+        symbol: self.symbol,
+        term: self.term,
+      }),
+      edicts: if supply > 0 {
+        vec![Edict {
+          amount: supply,
+          id: 0,
+          output: 1,
+        }]
+      } else {
+        Vec::new()
+      },
+      burn: false,
+    };
+
+    let client = options.bitcoin_rpc_client_for_wallet_command(false)?;
+
+    let mut transaction = Transaction {
+      version: 2,
+      lock_time: LockTime::ZERO,
+      input: Vec::new(),
+      output: vec![
+        TxOut {
+          script_pubkey: runestone.encipher(),
+          value: 0,
+        },
+        TxOut {
+          script_pubkey: get_change_address(&client, options.chain())?.script_pubkey(),
+          value: 0,
+        },
+      ],
+    };
+
+    fund_rune_transaction(
+      &mut transaction,
+      get_rune_free_cardinals(&index, &options)?,
+      0,
+      1,
+      self.fee_rate,
+    )?;
+
+    let signed_transaction = client
+      .sign_raw_transaction_with_wallet(&transaction, None, None)?
+      .hex;
+
+    let txid = client.send_raw_transaction(&signed_transaction)?;
+
+    Ok(Box::new(Output {
+      rune: self.rune,
+      supply: Pile {
+        amount: supply,
+        divisibility: self.divisibility,
+        symbol: self.symbol,
+      }
+      .to_string(),
+      transaction: txid,
+    }))
+  }
+}
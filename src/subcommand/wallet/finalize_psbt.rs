@@ -0,0 +1,48 @@
+use {
+  super::*,
+  bitcoin::{psbt::Psbt, secp256k1::Secp256k1},
+  miniscript::psbt::PsbtExt,
+};
+
+#[derive(Debug, Parser)]
+pub(crate) struct FinalizePsbt {
+  #[arg(help = "Base64-encoded signed <PSBT>.")]
+  psbt: Psbt,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Output {
+  pub transaction: Txid,
+}
+
+impl FinalizePsbt {
+  pub(crate) fn run(self, options: Options) -> SubcommandResult {
+    let mut psbt = self.psbt;
+
+    let finalized = psbt
+      .inputs
+      .iter()
+      .all(|input| input.final_script_witness.is_some() || input.final_script_sig.is_some());
+
+    if !finalized {
+      psbt.finalize_mut(&Secp256k1::new()).map_err(|errors| {
+        anyhow!(
+          "failed to finalize PSBT: {}",
+          errors
+            .iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+        )
+      })?;
+    }
+
+    let transaction = psbt.extract_tx();
+
+    let client = options.bitcoin_rpc_client_for_wallet_command(false)?;
+
+    let txid = client.send_raw_transaction(&transaction)?;
+
+    Ok(Box::new(Output { transaction: txid }))
+  }
+}
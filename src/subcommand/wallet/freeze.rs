@@ -0,0 +1,35 @@
+use {super::*, crate::wallet::Wallet};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Freeze {
+  #[arg(required = true, help = "Never use <OUTPOINT> as a cardinal input.")]
+  outpoints: Vec<OutPoint>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Output {
+  pub frozen: Vec<OutPoint>,
+}
+
+impl Freeze {
+  pub(crate) fn run(self, options: Options) -> SubcommandResult {
+    let index = Index::open(&options)?;
+    index.update()?;
+
+    let utxos = index.get_unspent_outputs(Wallet::load(&options)?)?;
+
+    for outpoint in &self.outpoints {
+      if !utxos.contains_key(outpoint) {
+        bail!("output {outpoint} not in wallet");
+      }
+    }
+
+    let mut coin_control = CoinControl::load(&options)?;
+
+    coin_control.freeze(&self.outpoints)?;
+
+    Ok(Box::new(Output {
+      frozen: coin_control.frozen().iter().copied().collect(),
+    }))
+  }
+}
@@ -1,6 +1,6 @@
 use {
   self::batch::{Batch, Batchfile, Mode},
-  super::*,
+  super::{journal::Pending, *},
   crate::{subcommand::wallet::transaction_builder::Target, wallet::Wallet},
   bitcoin::{
     blockdata::{opcodes, script},
@@ -21,17 +21,19 @@ use {
 
 mod batch;
 
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct InscriptionInfo {
   pub id: InscriptionId,
   pub location: SatPoint,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct Output {
   pub commit: Txid,
   pub inscriptions: Vec<InscriptionInfo>,
   pub parent: Option<InscriptionId>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub psbt: Option<String>,
   pub reveal: Txid,
   pub total_fees: u64,
 }
@@ -48,14 +50,15 @@ pub(crate) struct ParentInfo {
 #[clap(
   group = ArgGroup::new("source")
       .required(true)
-      .args(&["file", "batch"]),
+      .multiple(true)
+      .args(&["file", "batch", "delegate"]),
 )]
 pub(crate) struct Inscribe {
   #[arg(
     long,
     help = "Inscribe a multiple inscriptions defines in a yaml <BATCH_FILE>.",
     conflicts_with_all = &[
-      "file", "destination", "cbor_metadata", "json_metadata", "satpoint", "reinscribe", "metaprotocol", "parent"
+      "file", "delegate", "destination", "cbor_metadata", "json_metadata", "sat", "satpoint", "reinscribe", "metaprotocol", "parent"
     ]
   )]
   pub(crate) batch: Option<PathBuf>,
@@ -65,19 +68,41 @@ pub(crate) struct Inscribe {
     conflicts_with = "json_metadata"
   )]
   pub(crate) cbor_metadata: Option<PathBuf>,
+  #[arg(
+    long,
+    help = "Refuse to inscribe content that has already been inscribed."
+  )]
+  pub(crate) check_duplicate: bool,
   #[arg(
     long,
     help = "Use <COMMIT_FEE_RATE> sats/vbyte for commit transaction.\nDefaults to <FEE_RATE> if unset."
   )]
   pub(crate) commit_fee_rate: Option<FeeRate>,
+  #[arg(
+    long,
+    help = "Compress inscription content with brotli and set its content encoding to `br`."
+  )]
+  pub(crate) compress: bool,
+  #[arg(long, help = "Delegate inscription content to <DELEGATE>.")]
+  pub(crate) delegate: Option<InscriptionId>,
   #[arg(long, help = "Send inscription to <DESTINATION>.")]
   pub(crate) destination: Option<Address<NetworkUnchecked>>,
-  #[arg(long, help = "Don't sign or broadcast transactions.")]
+  #[arg(
+    long,
+    help = "Don't sign or broadcast transactions.",
+    conflicts_with = "psbt"
+  )]
   pub(crate) dry_run: bool,
   #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vB.")]
   pub(crate) fee_rate: FeeRate,
   #[arg(long, help = "Inscribe sat with contents of <FILE>.")]
   pub(crate) file: Option<PathBuf>,
+  #[arg(
+    long,
+    value_delimiter = ',',
+    help = "Only use comma-separated outpoints <INPUTS> as cardinal inputs."
+  )]
+  pub(crate) inputs: Vec<OutPoint>,
   #[arg(
     long,
     help = "Include JSON in file at <METADATA> convered to CBOR as inscription metadata",
@@ -100,8 +125,19 @@ pub(crate) struct Inscribe {
     help = "Amount of postage to include in the inscription. Default `10000sat`."
   )]
   pub(crate) postage: Option<Amount>,
+  #[arg(
+    long,
+    help = "Output the commit transaction as an unsigned PSBT for signing by an external signer instead of signing and broadcasting it. Run `ord wallet resume` to broadcast the reveal transaction once the commit transaction has been broadcast."
+  )]
+  pub(crate) psbt: bool,
   #[clap(long, help = "Allow reinscription.")]
   pub(crate) reinscribe: bool,
+  #[arg(
+    long,
+    help = "Inscribe <SAT>, given in any sat notation.",
+    conflicts_with = "satpoint"
+  )]
+  pub(crate) sat: Option<Sat>,
   #[arg(long, help = "Inscribe <SATPOINT>.")]
   pub(crate) satpoint: Option<SatPoint>,
 }
@@ -117,6 +153,8 @@ impl Inscribe {
 
     let locked_utxos = index.get_locked_outputs(Wallet::load(&options)?)?;
 
+    let frozen_utxos = CoinControl::load(&options)?.excluded_utxos(&index, &utxos, &self.inputs)?;
+
     let client = options.bitcoin_rpc_client_for_wallet_command(false)?;
 
     let chain = options.chain();
@@ -127,65 +165,162 @@ impl Inscribe {
     let inscriptions;
     let mode;
     let parent_info;
+    let postages;
+    let reveal_satpoints;
+    let satpoint;
 
     match (self.file, self.batch) {
-      (Some(file), None) => {
+      (file, None) => {
+        if let Some(delegate) = self.delegate {
+          if !index.inscription_exists(delegate)? {
+            bail!("delegate {delegate} does not exist");
+          }
+        }
+
         parent_info = Inscribe::get_parent_info(self.parent, &index, &utxos, &client, chain)?;
         inscriptions = vec![Inscription::from_file(
           chain,
-          file,
+          file.as_deref(),
+          self.delegate,
           self.parent,
           None,
           self.metaprotocol,
           metadata,
+          self.compress,
         )?];
         mode = Mode::SeparateOutputs;
         destinations = vec![match self.destination.clone() {
           Some(destination) => destination.require_network(chain.network())?,
           None => get_change_address(&client, chain)?,
         }];
+        postages = vec![postage];
+        reveal_satpoints = Vec::new();
+        satpoint = match self.sat {
+          Some(sat) => Inscribe::resolve_satpoint(
+            Some(sat),
+            None,
+            &Inscribe::get_wallet_sat_ranges(&index, &options)?,
+          )?,
+          None => self.satpoint,
+        };
       }
       (None, Some(batch)) => {
         let batchfile = Batchfile::load(&batch)?;
 
+        for delegate in batchfile
+          .inscriptions
+          .iter()
+          .filter_map(|entry| entry.delegate)
+        {
+          if !index.inscription_exists(delegate)? {
+            bail!("delegate {delegate} does not exist");
+          }
+        }
+
         parent_info = Inscribe::get_parent_info(batchfile.parent, &index, &utxos, &client, chain)?;
 
+        let sat_ranges = if batchfile.sat.is_some()
+          || batchfile
+            .inscriptions
+            .iter()
+            .any(|entry| entry.sat.is_some())
+        {
+          Inscribe::get_wallet_sat_ranges(&index, &options)?
+        } else {
+          Vec::new()
+        };
+
+        reveal_satpoints = if batchfile.mode == Mode::SatPoints {
+          Inscribe::get_reveal_satpoints(&batchfile, &index, &sat_ranges, &utxos)?
+        } else {
+          Vec::new()
+        };
+
+        postages = batchfile
+          .inscriptions
+          .iter()
+          .enumerate()
+          .map(|(i, entry)| match batchfile.mode {
+            Mode::SatPoints => Amount::from_sat(reveal_satpoints[i].1.value),
+            Mode::SameSat | Mode::SeparateOutputs | Mode::SharedOutput => {
+              entry.postage.map(Amount::from_sat).unwrap_or(postage)
+            }
+          })
+          .collect::<Vec<Amount>>();
+
         inscriptions = batchfile.inscriptions(
           chain,
           parent_info.as_ref().map(|info| info.tx_out.value),
           metadata,
-          postage,
+          &postages,
+          &reveal_satpoints,
+          self.compress,
         )?;
 
         mode = batchfile.mode;
 
-        let destination_count = match batchfile.mode {
-          Mode::SharedOutput => 1,
-          Mode::SeparateOutputs => inscriptions.len(),
+        destinations = match batchfile.mode {
+          Mode::SameSat | Mode::SharedOutput => vec![get_change_address(&client, chain)?],
+          Mode::SatPoints | Mode::SeparateOutputs => batchfile
+            .inscriptions
+            .iter()
+            .map(|entry| match entry.destination.clone() {
+              Some(destination) => Ok(destination.require_network(chain.network())?),
+              None => get_change_address(&client, chain),
+            })
+            .collect::<Result<Vec<Address>>>()?,
         };
 
-        destinations = (0..destination_count)
-          .map(|_| get_change_address(&client, chain))
-          .collect::<Result<Vec<Address>>>()?;
+        satpoint = Inscribe::resolve_satpoint(batchfile.sat, batchfile.satpoint, &sat_ranges)?;
       }
       _ => unreachable!(),
     }
 
+    if self.psbt && (parent_info.is_some() || mode == Mode::SatPoints) {
+      bail!("--psbt is not supported when inscribing with a parent or in satpoints mode");
+    }
+
+    if self.check_duplicate {
+      for inscription in &inscriptions {
+        let Some(content_hash) = inscription.content_hash() else {
+          continue;
+        };
+
+        if let Some(id) = index
+          .get_inscriptions_by_content_hash_paginated(content_hash, 1, 0)?
+          .0
+          .first()
+        {
+          bail!("content with hash {content_hash} has already been inscribed as {id}");
+        }
+      }
+    }
+
     Batch {
       commit_fee_rate: self.commit_fee_rate.unwrap_or(self.fee_rate),
       destinations,
       dry_run: self.dry_run,
+      frozen_utxos,
       inscriptions,
       mode,
       no_backup: self.no_backup,
       no_limit: self.no_limit,
       parent_info,
-      postage,
+      postages,
+      psbt: self.psbt,
       reinscribe: self.reinscribe,
       reveal_fee_rate: self.fee_rate,
-      satpoint: self.satpoint,
+      reveal_satpoints,
+      satpoint,
     }
-    .inscribe(chain, &index, &client, &locked_utxos, &utxos)
+    .inscribe(
+      chain,
+      &index,
+      &client,
+      &mut Journal::load(&options)?,
+      &locked_utxos,
+      &utxos,
+    )
   }
 
   fn parse_metadata(cbor: Option<PathBuf>, json: Option<PathBuf>) -> Result<Option<Vec<u8>>> {
@@ -207,6 +342,80 @@ impl Inscribe {
     }
   }
 
+  fn get_wallet_sat_ranges(
+    index: &Index,
+    options: &Options,
+  ) -> Result<Vec<(OutPoint, Vec<(u64, u64)>)>> {
+    if !index.has_sat_index() {
+      bail!("inscribing on a specific sat requires index created with `--index-sats` flag");
+    }
+
+    index.get_unspent_output_ranges(Wallet::load(options)?)
+  }
+
+  fn resolve_satpoint(
+    sat: Option<Sat>,
+    satpoint: Option<SatPoint>,
+    sat_ranges: &[(OutPoint, Vec<(u64, u64)>)],
+  ) -> Result<Option<SatPoint>> {
+    let Some(sat) = sat else {
+      return Ok(satpoint);
+    };
+
+    for (outpoint, ranges) in sat_ranges {
+      let mut offset = 0;
+      for (start, end) in ranges {
+        if (*start..*end).contains(&sat.n()) {
+          return Ok(Some(SatPoint {
+            outpoint: *outpoint,
+            offset: offset + sat.n() - start,
+          }));
+        }
+        offset += end - start;
+      }
+    }
+
+    bail!("could not find sat {sat} in wallet outputs");
+  }
+
+  fn get_reveal_satpoints(
+    batchfile: &Batchfile,
+    index: &Index,
+    sat_ranges: &[(OutPoint, Vec<(u64, u64)>)],
+    utxos: &BTreeMap<OutPoint, Amount>,
+  ) -> Result<Vec<(SatPoint, TxOut)>> {
+    let mut reveal_satpoints = Vec::new();
+    let mut outpoints = BTreeSet::new();
+
+    for entry in &batchfile.inscriptions {
+      let satpoint = Inscribe::resolve_satpoint(entry.sat, entry.satpoint, sat_ranges)?
+        .expect("batchfile entries must have a satpoint in satpoints mode");
+
+      if !utxos.contains_key(&satpoint.outpoint) {
+        bail!("satpoint {satpoint} not in wallet");
+      }
+
+      if !outpoints.insert(satpoint.outpoint) {
+        bail!(
+          "utxo {} referenced by more than one inscription",
+          satpoint.outpoint
+        );
+      }
+
+      let tx_out = index
+        .get_transaction(satpoint.outpoint.txid)?
+        .ok_or_else(|| anyhow!("transaction {} not found", satpoint.outpoint.txid))?
+        .output
+        .into_iter()
+        .nth(satpoint.outpoint.vout.try_into().unwrap())
+        .ok_or_else(|| anyhow!("output {} not found", satpoint.outpoint))?;
+
+      reveal_satpoints.push((satpoint, tx_out));
+    }
+
+    Ok(reveal_satpoints)
+  }
+
   fn get_parent_info(
     parent: Option<InscriptionId>,
     index: &Index,
@@ -257,7 +466,7 @@ mod tests {
     let reveal_address = recipient();
     let change = [commit_address, change(1)];
 
-    let (commit_tx, reveal_tx, _private_key, _) = Batch {
+    let (commit_tx, reveal_tx, _key_pair, _recovery_key_pair, _) = Batch {
       satpoint: Some(satpoint(1, 0)),
       parent_info: None,
       inscriptions: vec![inscription],
@@ -266,7 +475,7 @@ mod tests {
       reveal_fee_rate: FeeRate::try_from(1.0).unwrap(),
       no_limit: false,
       reinscribe: false,
-      postage: TransactionBuilder::TARGET_POSTAGE,
+      postages: vec![TransactionBuilder::TARGET_POSTAGE],
       mode: Mode::SharedOutput,
       ..Default::default()
     }
@@ -297,7 +506,7 @@ mod tests {
     let reveal_address = recipient();
     let change = [commit_address, change(1)];
 
-    let (commit_tx, reveal_tx, _, _) = Batch {
+    let (commit_tx, reveal_tx, _, _, _) = Batch {
       satpoint: Some(satpoint(1, 0)),
       parent_info: None,
       inscriptions: vec![inscription],
@@ -306,7 +515,7 @@ mod tests {
       reveal_fee_rate: FeeRate::try_from(1.0).unwrap(),
       no_limit: false,
       reinscribe: false,
-      postage: TransactionBuilder::TARGET_POSTAGE,
+      postages: vec![TransactionBuilder::TARGET_POSTAGE],
       mode: Mode::SharedOutput,
       ..Default::default()
     }
@@ -349,7 +558,7 @@ mod tests {
       reveal_fee_rate: FeeRate::try_from(1.0).unwrap(),
       no_limit: false,
       reinscribe: false,
-      postage: TransactionBuilder::TARGET_POSTAGE,
+      postages: vec![TransactionBuilder::TARGET_POSTAGE],
       mode: Mode::SharedOutput,
       ..Default::default()
     }
@@ -399,7 +608,7 @@ mod tests {
       reveal_fee_rate: FeeRate::try_from(1.0).unwrap(),
       no_limit: false,
       reinscribe: false,
-      postage: TransactionBuilder::TARGET_POSTAGE,
+      postages: vec![TransactionBuilder::TARGET_POSTAGE],
       mode: Mode::SharedOutput,
       ..Default::default()
     }
@@ -434,7 +643,7 @@ mod tests {
     let reveal_address = recipient();
     let fee_rate = 3.3;
 
-    let (commit_tx, reveal_tx, _private_key, _) = Batch {
+    let (commit_tx, reveal_tx, _key_pair, _recovery_key_pair, _) = Batch {
       satpoint,
       parent_info: None,
       inscriptions: vec![inscription],
@@ -443,7 +652,7 @@ mod tests {
       reveal_fee_rate: FeeRate::try_from(fee_rate).unwrap(),
       no_limit: false,
       reinscribe: false,
-      postage: TransactionBuilder::TARGET_POSTAGE,
+      postages: vec![TransactionBuilder::TARGET_POSTAGE],
       mode: Mode::SharedOutput,
       ..Default::default()
     }
@@ -515,7 +724,7 @@ mod tests {
     let reveal_address = recipient();
     let fee_rate = 4.0;
 
-    let (commit_tx, reveal_tx, _private_key, _) = Batch {
+    let (commit_tx, reveal_tx, _key_pair, _recovery_key_pair, _) = Batch {
       satpoint: None,
       parent_info: Some(parent_info.clone()),
       inscriptions: vec![child_inscription],
@@ -524,7 +733,7 @@ mod tests {
       reveal_fee_rate: FeeRate::try_from(fee_rate).unwrap(),
       no_limit: false,
       reinscribe: false,
-      postage: TransactionBuilder::TARGET_POSTAGE,
+      postages: vec![TransactionBuilder::TARGET_POSTAGE],
       mode: Mode::SharedOutput,
       ..Default::default()
     }
@@ -596,7 +805,7 @@ mod tests {
     let commit_fee_rate = 3.3;
     let fee_rate = 1.0;
 
-    let (commit_tx, reveal_tx, _private_key, _) = Batch {
+    let (commit_tx, reveal_tx, _key_pair, _recovery_key_pair, _) = Batch {
       satpoint,
       parent_info: None,
       inscriptions: vec![inscription],
@@ -605,7 +814,7 @@ mod tests {
       reveal_fee_rate: FeeRate::try_from(fee_rate).unwrap(),
       no_limit: false,
       reinscribe: false,
-      postage: TransactionBuilder::TARGET_POSTAGE,
+      postages: vec![TransactionBuilder::TARGET_POSTAGE],
       mode: Mode::SharedOutput,
       ..Default::default()
     }
@@ -662,7 +871,7 @@ mod tests {
       reveal_fee_rate: FeeRate::try_from(1.0).unwrap(),
       no_limit: false,
       reinscribe: false,
-      postage: TransactionBuilder::TARGET_POSTAGE,
+      postages: vec![TransactionBuilder::TARGET_POSTAGE],
       mode: Mode::SharedOutput,
       ..Default::default()
     }
@@ -692,7 +901,7 @@ mod tests {
     let commit_address = change(0);
     let reveal_address = recipient();
 
-    let (_commit_tx, reveal_tx, _private_key, _) = Batch {
+    let (_commit_tx, reveal_tx, _key_pair, _recovery_key_pair, _) = Batch {
       satpoint,
       parent_info: None,
       inscriptions: vec![inscription],
@@ -701,7 +910,7 @@ mod tests {
       reveal_fee_rate: FeeRate::try_from(1.0).unwrap(),
       no_limit: true,
       reinscribe: false,
-      postage: TransactionBuilder::TARGET_POSTAGE,
+      postages: vec![TransactionBuilder::TARGET_POSTAGE],
       mode: Mode::SharedOutput,
       ..Default::default()
     }
@@ -737,6 +946,61 @@ mod tests {
     );
   }
 
+  #[test]
+  fn sat_and_satpoint_flags_conflict() {
+    assert_regex_match!(
+      Arguments::try_parse_from([
+        "ord",
+        "wallet",
+        "inscribe",
+        "--sat",
+        "nvtdijuwxlp",
+        "--satpoint",
+        "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0:0",
+        "--file",
+        "baz",
+      ])
+      .unwrap_err()
+      .to_string(),
+      ".*--sat.*cannot be used with.*--satpoint.*"
+    );
+  }
+
+  #[test]
+  fn resolve_satpoint_finds_sat_in_wallet_ranges() {
+    let sat_ranges = vec![
+      (outpoint(1), vec![(0, 10), (20, 30)]),
+      (outpoint(2), vec![(100, 200)]),
+    ];
+
+    assert_eq!(
+      Inscribe::resolve_satpoint(None, Some(satpoint(3, 7)), &sat_ranges).unwrap(),
+      Some(satpoint(3, 7)),
+    );
+
+    assert_eq!(
+      Inscribe::resolve_satpoint(Some(Sat(5)), None, &sat_ranges).unwrap(),
+      Some(satpoint(1, 5)),
+    );
+
+    assert_eq!(
+      Inscribe::resolve_satpoint(Some(Sat(25)), None, &sat_ranges).unwrap(),
+      Some(satpoint(1, 15)),
+    );
+
+    assert_eq!(
+      Inscribe::resolve_satpoint(Some(Sat(150)), None, &sat_ranges).unwrap(),
+      Some(satpoint(2, 50)),
+    );
+
+    assert_eq!(
+      Inscribe::resolve_satpoint(Some(Sat(15)), None, &sat_ranges)
+        .unwrap_err()
+        .to_string(),
+      "could not find sat 15 in wallet outputs",
+    );
+  }
+
   #[test]
   fn batch_is_loaded_from_yaml_file() {
     let parent = "8d363b28528b0cb86b5fd48615493fb175bdf132d2a3d20b4251bba3f130a5abi0"
@@ -782,22 +1046,67 @@ inscriptions:
       Batchfile {
         inscriptions: vec![
           BatchEntry {
-            file: inscription_path,
+            file: Some(inscription_path),
             metadata: Some(Value::Mapping(metadata)),
             ..Default::default()
           },
           BatchEntry {
-            file: brc20_path,
+            file: Some(brc20_path),
             metaprotocol: Some("brc-20".to_string()),
             ..Default::default()
           }
         ],
         parent: Some(parent),
         mode: Mode::SeparateOutputs,
+        sat: None,
+        satpoint: None,
       }
     );
   }
 
+  #[test]
+  fn batch_with_fields_invalid_for_mode_throws_error() {
+    let tempdir = TempDir::new().unwrap();
+    let batch_path = tempdir.path().join("batch.yaml");
+
+    #[track_caller]
+    fn case(batch_path: &Path, batch: &str, error: &str) {
+      fs::write(batch_path, batch).unwrap();
+
+      assert_eq!(Batchfile::load(batch_path).unwrap_err().to_string(), error);
+    }
+
+    case(
+      &batch_path,
+      "mode: same-sat\ninscriptions:\n- file: meow.wav\n  postage: 1000\n",
+      "`destination`, `postage`, `sat` and `satpoint` cannot be set for individual inscriptions in same-sat mode",
+    );
+
+    case(
+      &batch_path,
+      "mode: shared-output\nsat: 1000\ninscriptions:\n- file: meow.wav\n",
+      "`sat` and `satpoint` can only be set at the top level in same-sat mode",
+    );
+
+    case(
+      &batch_path,
+      "mode: separate-outputs\ninscriptions:\n- file: meow.wav\n  sat: 1000\n",
+      "`sat` and `satpoint` can only be set for individual inscriptions in satpoints mode",
+    );
+
+    case(
+      &batch_path,
+      "mode: satpoints\ninscriptions:\n- file: meow.wav\n  sat: 1000\n  postage: 1000\n",
+      "`postage` cannot be set in satpoints mode",
+    );
+
+    case(
+      &batch_path,
+      "mode: satpoints\ninscriptions:\n- file: meow.wav\n  sat: 1000\n  satpoint: 1111111111111111111111111111111111111111111111111111111111111111:1:0\n",
+      "inscriptions cannot set both `sat` and `satpoint`",
+    );
+  }
+
   #[test]
   fn batch_with_unknown_field_throws_error() {
     let tempdir = TempDir::new().unwrap();
@@ -861,7 +1170,7 @@ inscriptions:
 
     let fee_rate = 4.0.try_into().unwrap();
 
-    let (commit_tx, reveal_tx, _private_key, _) = Batch {
+    let (commit_tx, reveal_tx, _key_pair, _recovery_key_pair, _) = Batch {
       satpoint: None,
       parent_info: Some(parent_info.clone()),
       inscriptions,
@@ -870,7 +1179,7 @@ inscriptions:
       reveal_fee_rate: fee_rate,
       no_limit: false,
       reinscribe: false,
-      postage: Amount::from_sat(10_000),
+      postages: vec![Amount::from_sat(10_000); 3],
       mode,
       ..Default::default()
     }
@@ -966,7 +1275,7 @@ inscriptions:
       reveal_fee_rate: 4.0.try_into().unwrap(),
       no_limit: false,
       reinscribe: false,
-      postage: Amount::from_sat(10_000),
+      postages: vec![Amount::from_sat(10_000); 3],
       mode: Mode::SharedOutput,
       ..Default::default()
     }
@@ -1040,7 +1349,7 @@ inscriptions:
       reveal_fee_rate: 4.0.try_into().unwrap(),
       no_limit: false,
       reinscribe: false,
-      postage: Amount::from_sat(10_000),
+      postages: vec![Amount::from_sat(10_000); 3],
       mode: Mode::SharedOutput,
       ..Default::default()
     }
@@ -1077,7 +1386,7 @@ inscriptions:
       reveal_fee_rate: 1.0.try_into().unwrap(),
       no_limit: false,
       reinscribe: false,
-      postage: Amount::from_sat(30_000),
+      postages: vec![Amount::from_sat(30_000); 3],
       mode: Mode::SharedOutput,
       ..Default::default()
     }
@@ -1120,7 +1429,7 @@ inscriptions:
 
     let fee_rate = 4.0.try_into().unwrap();
 
-    let (_commit_tx, reveal_tx, _private_key, _) = Batch {
+    let (_commit_tx, reveal_tx, _key_pair, _recovery_key_pair, _) = Batch {
       satpoint: None,
       parent_info: None,
       inscriptions,
@@ -1129,7 +1438,7 @@ inscriptions:
       reveal_fee_rate: fee_rate,
       no_limit: false,
       reinscribe: false,
-      postage: Amount::from_sat(10_000),
+      postages: vec![Amount::from_sat(10_000); 3],
       mode,
       ..Default::default()
     }
@@ -1196,7 +1505,7 @@ inscriptions:
 
     let fee_rate = 4.0.try_into().unwrap();
 
-    let (commit_tx, reveal_tx, _private_key, _) = Batch {
+    let (commit_tx, reveal_tx, _key_pair, _recovery_key_pair, _) = Batch {
       satpoint: None,
       parent_info: Some(parent_info.clone()),
       inscriptions,
@@ -1205,7 +1514,7 @@ inscriptions:
       reveal_fee_rate: fee_rate,
       no_limit: false,
       reinscribe: false,
-      postage: Amount::from_sat(10_000),
+      postages: vec![Amount::from_sat(10_000); 3],
       mode,
       ..Default::default()
     }
@@ -1275,6 +1584,7 @@ inscriptions:
       ),
       ("--cbor-metadata", Some("foo")),
       ("--json-metadata", Some("foo")),
+      ("--sat", Some("5000000000")),
       (
         "--satpoint",
         Some("4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0:0"),
@@ -1309,12 +1619,106 @@ inscriptions:
   }
 
   #[test]
-  fn batch_or_file_is_required() {
+  fn batch_inscribe_into_satpoints() {
+    let utxos = vec![
+      (outpoint(1), Amount::from_sat(10_000)),
+      (outpoint(2), Amount::from_sat(20_000)),
+      (outpoint(3), Amount::from_sat(80_000)),
+    ];
+
+    let reveal_satpoints = vec![
+      (
+        satpoint(1, 0),
+        TxOut {
+          script_pubkey: change(0).script_pubkey(),
+          value: 10_000,
+        },
+      ),
+      (
+        satpoint(2, 5),
+        TxOut {
+          script_pubkey: change(0).script_pubkey(),
+          value: 20_000,
+        },
+      ),
+    ];
+
+    let (commit_tx, reveal_tx, _key_pair, _recovery_key_pair, _) = Batch {
+      destinations: vec![recipient(), recipient()],
+      inscriptions: vec![
+        inscription("text/plain", "foo"),
+        inscription("text/plain", "bar"),
+      ],
+      mode: Mode::SatPoints,
+      postages: vec![Amount::from_sat(10_000), Amount::from_sat(20_000)],
+      reveal_satpoints,
+      ..Default::default()
+    }
+    .create_batch_inscription_transactions(
+      BTreeMap::new(),
+      Chain::Signet,
+      BTreeSet::new(),
+      utxos.into_iter().collect(),
+      [change(1), change(2)],
+    )
+    .unwrap();
+
+    assert_eq!(commit_tx.input.len(), 1);
+    assert_eq!(commit_tx.input[0].previous_output, outpoint(3));
+
+    assert_eq!(reveal_tx.input.len(), 3);
+    assert_eq!(reveal_tx.input[0].previous_output, outpoint(1));
+    assert_eq!(reveal_tx.input[1].previous_output, outpoint(2));
+    assert_eq!(reveal_tx.input[2].previous_output.txid, commit_tx.txid());
+
+    let dust = commit_tx.output[reveal_tx.input[2].previous_output.vout as usize]
+      .script_pubkey
+      .dust_value()
+      .to_sat();
+
+    assert_eq!(reveal_tx.output.len(), 2);
+    assert_eq!(reveal_tx.output[0].value, 10_000);
+    assert_eq!(reveal_tx.output[1].value, 20_000 + dust);
+  }
+
+  #[test]
+  fn batch_inscribe_on_same_sat() {
+    let utxos = vec![(outpoint(1), Amount::from_sat(80_000))];
+
+    let (_commit_tx, reveal_tx, _key_pair, _recovery_key_pair, _) = Batch {
+      destinations: vec![recipient()],
+      inscriptions: vec![
+        inscription("text/plain", "foo"),
+        inscription("text/plain", "bar"),
+        inscription("text/plain", "baz"),
+      ],
+      mode: Mode::SameSat,
+      postages: vec![TransactionBuilder::TARGET_POSTAGE; 3],
+      ..Default::default()
+    }
+    .create_batch_inscription_transactions(
+      BTreeMap::new(),
+      Chain::Signet,
+      BTreeSet::new(),
+      utxos.into_iter().collect(),
+      [change(1), change(2)],
+    )
+    .unwrap();
+
+    assert_eq!(reveal_tx.output.len(), 1);
+    assert_eq!(
+      reveal_tx.output[0].value,
+      TransactionBuilder::TARGET_POSTAGE.to_sat()
+    );
+  }
+
+  #[test]
+  fn batch_file_or_delegate_is_required() {
     assert!(
       Arguments::try_parse_from(["ord", "wallet", "inscribe", "--fee-rate", "1",])
         .unwrap_err()
         .to_string()
-        .contains("error: the following required arguments were not provided:\n  <--file <FILE>|--batch <BATCH>>")
+        .contains("error: the following required arguments were not provided:\n  <--file <FILE>|--batch <BATCH>|--delegate <DELEGATE>>")
     );
   }
 }
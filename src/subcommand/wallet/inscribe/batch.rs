@@ -4,14 +4,17 @@ pub(super) struct Batch {
   pub(super) commit_fee_rate: FeeRate,
   pub(super) destinations: Vec<Address>,
   pub(super) dry_run: bool,
+  pub(super) frozen_utxos: BTreeSet<OutPoint>,
   pub(super) inscriptions: Vec<Inscription>,
   pub(super) mode: Mode,
   pub(super) no_backup: bool,
   pub(super) no_limit: bool,
   pub(super) parent_info: Option<ParentInfo>,
-  pub(super) postage: Amount,
+  pub(super) postages: Vec<Amount>,
+  pub(super) psbt: bool,
   pub(super) reinscribe: bool,
   pub(super) reveal_fee_rate: FeeRate,
+  pub(super) reveal_satpoints: Vec<(SatPoint, TxOut)>,
   pub(super) satpoint: Option<SatPoint>,
 }
 
@@ -21,14 +24,17 @@ impl Default for Batch {
       commit_fee_rate: 1.0.try_into().unwrap(),
       destinations: Vec::new(),
       dry_run: false,
+      frozen_utxos: BTreeSet::new(),
       inscriptions: Vec::new(),
       mode: Mode::SharedOutput,
       no_backup: false,
       no_limit: false,
       parent_info: None,
-      postage: Amount::from_sat(10_000),
+      postages: vec![Amount::from_sat(10_000)],
+      psbt: false,
       reinscribe: false,
       reveal_fee_rate: 1.0.try_into().unwrap(),
+      reveal_satpoints: Vec::new(),
       satpoint: None,
     }
   }
@@ -40,6 +46,7 @@ impl Batch {
     chain: Chain,
     index: &Index,
     client: &Client,
+    journal: &mut Journal,
     locked_utxos: &BTreeSet<OutPoint>,
     utxos: &BTreeMap<OutPoint, Amount>,
   ) -> SubcommandResult {
@@ -50,7 +57,7 @@ impl Batch {
       get_change_address(client, chain)?,
     ];
 
-    let (commit_tx, reveal_tx, recovery_key_pair, total_fees) = self
+    let (commit_tx, reveal_tx, key_pair, recovery_key_pair, total_fees) = self
       .create_batch_inscription_transactions(
         wallet_inscriptions,
         chain,
@@ -68,11 +75,25 @@ impl Batch {
       )));
     }
 
-    let signed_commit_tx = client
-      .sign_raw_transaction_with_wallet(&commit_tx, None, None)?
-      .hex;
+    let psbt = if self.psbt {
+      let mut psbt = Psbt::from_unsigned_tx(commit_tx.clone())?;
+      add_witness_utxos(index, &mut psbt)?;
+      Some(psbt)
+    } else {
+      None
+    };
+
+    // with `--psbt`, the commit transaction is signed by an external signer,
+    // which does not change its txid, since its inputs are segwit
+    let signed_commit_tx = if psbt.is_some() {
+      consensus::encode::serialize(&commit_tx)
+    } else {
+      client
+        .sign_raw_transaction_with_wallet(&commit_tx, None, None)?
+        .hex
+    };
 
-    let signed_reveal_tx = if self.parent_info.is_some() {
+    let signed_reveal_tx = if self.parent_info.is_some() || !self.reveal_satpoints.is_empty() {
       client
         .sign_raw_transaction_with_wallet(
           &reveal_tx,
@@ -101,14 +122,68 @@ impl Batch {
       Self::backup_recovery_key(client, recovery_key_pair, chain.network())?;
     }
 
-    let commit = client.send_raw_transaction(&signed_commit_tx)?;
+    let signed_commit_tx: Transaction = consensus::encode::deserialize(&signed_commit_tx)?;
+    let signed_reveal_tx: Transaction = consensus::encode::deserialize(&signed_reveal_tx)?;
+
+    let mut reveal_prevouts = Vec::new();
+
+    if let Some(parent_info) = &self.parent_info {
+      reveal_prevouts.push(parent_info.tx_out.clone());
+    }
+
+    for (_satpoint, tx_out) in &self.reveal_satpoints {
+      reveal_prevouts.push(tx_out.clone());
+    }
+
+    let commit_input = reveal_prevouts.len();
+
+    reveal_prevouts
+      .push(commit_tx.output[reveal_tx.input[commit_input].previous_output.vout as usize].clone());
+
+    journal.prune(client)?;
+
+    journal.insert(Pending {
+      commit: signed_commit_tx.clone(),
+      commit_input,
+      output: self.output(
+        commit_tx.txid(),
+        reveal_tx.txid(),
+        total_fees,
+        self.inscriptions.clone(),
+      ),
+      recovery_key: PrivateKey::new(recovery_key_pair.to_inner().secret_key(), chain.network()),
+      reveal: signed_reveal_tx.clone(),
+      reveal_key: PrivateKey::new(key_pair.secret_key(), chain.network()),
+      reveal_prevouts,
+      sweep: None,
+    })?;
+
+    if let Some(psbt) = psbt {
+      return Ok(Box::new(super::Output {
+        psbt: Some(psbt.to_string()),
+        ..self.output(
+          commit_tx.txid(),
+          reveal_tx.txid(),
+          total_fees,
+          self.inscriptions.clone(),
+        )
+      }));
+    }
+
+    let commit = match client.send_raw_transaction(&signed_commit_tx) {
+      Ok(txid) => txid,
+      Err(err) => {
+        journal.remove(commit_tx.txid())?;
+        return Err(err.into());
+      }
+    };
 
     let reveal = match client.send_raw_transaction(&signed_reveal_tx) {
       Ok(txid) => txid,
       Err(err) => {
         return Err(anyhow!(
-        "Failed to send reveal transaction: {err}\nCommit tx {commit} will be recovered once mined"
-      ))
+          "Failed to send reveal transaction: {err}\nCommit tx {commit} was saved, run `ord wallet resume` to retry or `ord wallet abandon {commit}` to recover its funds"
+        ))
       }
     };
 
@@ -128,31 +203,25 @@ impl Batch {
     inscriptions: Vec<Inscription>,
   ) -> super::Output {
     let mut inscriptions_output = Vec::new();
+    let mut shared_offset = 0;
     for index in 0..inscriptions.len() {
-      let index = u32::try_from(index).unwrap();
+      let first_vout = if self.parent_info.is_some() { 1 } else { 0 };
 
       let vout = match self.mode {
-        Mode::SharedOutput => {
-          if self.parent_info.is_some() {
-            1
-          } else {
-            0
-          }
-        }
-        Mode::SeparateOutputs => {
-          if self.parent_info.is_some() {
-            index + 1
-          } else {
-            index
-          }
-        }
+        Mode::SameSat | Mode::SharedOutput => first_vout,
+        Mode::SatPoints | Mode::SeparateOutputs => first_vout + u32::try_from(index).unwrap(),
       };
 
       let offset = match self.mode {
-        Mode::SharedOutput => u64::from(index) * self.postage.to_sat(),
-        Mode::SeparateOutputs => 0,
+        Mode::SameSat | Mode::SeparateOutputs => 0,
+        Mode::SatPoints => self.reveal_satpoints[index].0.offset,
+        Mode::SharedOutput => shared_offset,
       };
 
+      shared_offset += self.postages[index].to_sat();
+
+      let index = u32::try_from(index).unwrap();
+
       inscriptions_output.push(InscriptionInfo {
         id: InscriptionId {
           txid: reveal,
@@ -170,6 +239,7 @@ impl Batch {
       reveal,
       total_fees,
       parent: self.parent_info.clone().map(|info| info.id),
+      psbt: None,
       inscriptions: inscriptions_output,
     }
   }
@@ -181,7 +251,13 @@ impl Batch {
     locked_utxos: BTreeSet<OutPoint>,
     mut utxos: BTreeMap<OutPoint, Amount>,
     change: [Address; 2],
-  ) -> Result<(Transaction, Transaction, TweakedKeyPair, u64)> {
+  ) -> Result<(
+    Transaction,
+    Transaction,
+    UntweakedKeyPair,
+    TweakedKeyPair,
+    u64,
+  )> {
     if let Some(parent_info) = &self.parent_info {
       assert!(self
         .inscriptions
@@ -190,26 +266,66 @@ impl Batch {
     }
 
     if self.satpoint.is_some() {
-      assert_eq!(
-        self.inscriptions.len(),
-        1,
-        "invariant: satpoint may only be specified when making a single inscription",
+      assert!(
+        self.inscriptions.len() == 1 || self.mode == Mode::SameSat,
+        "invariant: satpoint may only be specified when making a single inscription or in same-sat mode",
       );
     }
 
+    assert_eq!(
+      self.postages.len(),
+      self.inscriptions.len(),
+      "invariant: postages and number of inscriptions doesn't match"
+    );
+
     match self.mode {
-      Mode::SeparateOutputs => assert_eq!(
+      Mode::SatPoints | Mode::SeparateOutputs => assert_eq!(
         self.destinations.len(),
         self.inscriptions.len(),
         "invariant: destination addresses and number of inscriptions doesn't match"
       ),
-      Mode::SharedOutput => assert_eq!(
+      Mode::SameSat | Mode::SharedOutput => assert_eq!(
         self.destinations.len(),
         1,
         "invariant: destination addresses and number of inscriptions doesn't match"
       ),
     }
 
+    if self.mode == Mode::SatPoints {
+      assert_eq!(
+        self.reveal_satpoints.len(),
+        self.inscriptions.len(),
+        "invariant: reveal satpoints and number of inscriptions doesn't match"
+      );
+    } else {
+      assert!(
+        self.reveal_satpoints.is_empty(),
+        "invariant: reveal satpoints may only be specified in satpoints mode"
+      );
+    }
+
+    for (reveal_satpoint, _tx_out) in &self.reveal_satpoints {
+      if let Some((inscribed_satpoint, inscription_id)) = wallet_inscriptions
+        .iter()
+        .find(|(inscribed_satpoint, _)| inscribed_satpoint.outpoint == reveal_satpoint.outpoint)
+      {
+        return Err(anyhow!(
+          "utxo {} already inscribed with inscription {inscription_id} on sat {inscribed_satpoint}",
+          reveal_satpoint.outpoint,
+        ));
+      }
+    }
+
+    let locked_utxos = locked_utxos
+      .into_iter()
+      .chain(
+        self
+          .reveal_satpoints
+          .iter()
+          .map(|(satpoint, _tx_out)| satpoint.outpoint),
+      )
+      .collect::<BTreeSet<OutPoint>>();
+
     let satpoint = if let Some(satpoint) = self.satpoint {
       satpoint
     } else {
@@ -220,7 +336,11 @@ impl Batch {
 
       utxos
         .keys()
-        .find(|outpoint| !inscribed_utxos.contains(outpoint) && !locked_utxos.contains(outpoint))
+        .find(|outpoint| {
+          !inscribed_utxos.contains(outpoint)
+            && !locked_utxos.contains(outpoint)
+            && !self.frozen_utxos.contains(outpoint)
+        })
         .map(|outpoint| SatPoint {
           outpoint: *outpoint,
           offset: 0,
@@ -277,20 +397,18 @@ impl Batch {
 
     let commit_tx_address = Address::p2tr_tweaked(taproot_spend_info.output_key(), chain.network());
 
-    let total_postage = self.postage * u64::try_from(self.inscriptions.len()).unwrap();
+    // In satpoints mode the commit input comes last so that its sats pay the
+    // fee without shifting the inscribed sats. It also carries a dust amount
+    // to the last output, so that the default location of the inscriptions
+    // is in an output and their pointers are respected.
+    let total_postage = match self.mode {
+      Mode::SameSat => self.postages[0],
+      Mode::SatPoints => commit_tx_address.script_pubkey().dust_value(),
+      Mode::SeparateOutputs | Mode::SharedOutput => self.postages.iter().copied().sum(),
+    };
 
-    let mut reveal_inputs = vec![OutPoint::null()];
-    let mut reveal_outputs = self
-      .destinations
-      .iter()
-      .map(|destination| TxOut {
-        script_pubkey: destination.script_pubkey(),
-        value: match self.mode {
-          Mode::SeparateOutputs => self.postage.to_sat(),
-          Mode::SharedOutput => total_postage.to_sat(),
-        },
-      })
-      .collect::<Vec<TxOut>>();
+    let mut reveal_inputs = Vec::new();
+    let mut reveal_outputs = Vec::new();
 
     if let Some(ParentInfo {
       location,
@@ -299,17 +417,33 @@ impl Batch {
       tx_out,
     }) = self.parent_info.clone()
     {
-      reveal_inputs.insert(0, location.outpoint);
-      reveal_outputs.insert(
-        0,
-        TxOut {
-          script_pubkey: destination.script_pubkey(),
-          value: tx_out.value,
-        },
-      );
+      reveal_inputs.push(location.outpoint);
+      reveal_outputs.push(TxOut {
+        script_pubkey: destination.script_pubkey(),
+        value: tx_out.value,
+      });
+    }
+
+    for (satpoint, _tx_out) in &self.reveal_satpoints {
+      reveal_inputs.push(satpoint.outpoint);
     }
 
-    let commit_input = if self.parent_info.is_some() { 1 } else { 0 };
+    let commit_input = reveal_inputs.len();
+
+    reveal_inputs.push(OutPoint::null());
+
+    for (i, destination) in self.destinations.iter().enumerate() {
+      reveal_outputs.push(TxOut {
+        script_pubkey: destination.script_pubkey(),
+        value: match self.mode {
+          Mode::SatPoints if i == self.destinations.len() - 1 => {
+            self.postages[i].to_sat() + total_postage.to_sat()
+          }
+          Mode::SatPoints | Mode::SeparateOutputs => self.postages[i].to_sat(),
+          Mode::SameSat | Mode::SharedOutput => total_postage.to_sat(),
+        },
+      });
+    }
 
     let (_, reveal_fee) = Self::build_reveal_transaction(
       &control_block,
@@ -325,6 +459,7 @@ impl Batch {
       wallet_inscriptions,
       utxos.clone(),
       locked_utxos.clone(),
+      self.frozen_utxos.clone(),
       commit_tx_address.clone(),
       change,
       self.commit_fee_rate,
@@ -353,21 +488,29 @@ impl Batch {
       &reveal_script,
     );
 
-    if reveal_tx.output[commit_input].value
-      < reveal_tx.output[commit_input]
-        .script_pubkey
-        .dust_value()
-        .to_sat()
+    for (i, output) in reveal_tx
+      .output
+      .iter()
+      .enumerate()
+      .skip(if self.parent_info.is_some() { 1 } else { 0 })
     {
-      bail!("commit transaction output would be dust");
+      if output.value < output.script_pubkey.dust_value().to_sat() {
+        bail!("reveal output {i} would be dust");
+      }
     }
 
-    let mut prevouts = vec![unsigned_commit_tx.output[vout].clone()];
+    let mut prevouts = Vec::new();
 
     if let Some(parent_info) = self.parent_info.clone() {
-      prevouts.insert(0, parent_info.tx_out);
+      prevouts.push(parent_info.tx_out);
+    }
+
+    for (_satpoint, tx_out) in &self.reveal_satpoints {
+      prevouts.push(tx_out.clone());
     }
 
+    prevouts.push(unsigned_commit_tx.output[vout].clone());
+
     let mut sighash_cache = SighashCache::new(&mut reveal_tx);
 
     let sighash = sighash_cache
@@ -430,7 +573,13 @@ impl Batch {
     let total_fees =
       Self::calculate_fee(&unsigned_commit_tx, &utxos) + Self::calculate_fee(&reveal_tx, &utxos);
 
-    Ok((unsigned_commit_tx, reveal_tx, recovery_key_pair, total_fees))
+    Ok((
+      unsigned_commit_tx,
+      reveal_tx,
+      key_pair,
+      recovery_key_pair,
+      total_fees,
+    ))
   }
 
   fn backup_recovery_key(
@@ -520,6 +669,10 @@ impl Batch {
 
 #[derive(PartialEq, Debug, Copy, Clone, Serialize, Deserialize)]
 pub(crate) enum Mode {
+  #[serde(rename = "same-sat")]
+  SameSat,
+  #[serde(rename = "satpoints")]
+  SatPoints,
   #[serde(rename = "separate-outputs")]
   SeparateOutputs,
   #[serde(rename = "shared-output")]
@@ -529,9 +682,14 @@ pub(crate) enum Mode {
 #[derive(Deserialize, Default, PartialEq, Debug, Clone)]
 #[serde(deny_unknown_fields)]
 pub(crate) struct BatchEntry {
-  pub(crate) file: PathBuf,
+  pub(crate) delegate: Option<InscriptionId>,
+  pub(crate) destination: Option<Address<NetworkUnchecked>>,
+  pub(crate) file: Option<PathBuf>,
   pub(crate) metadata: Option<serde_yaml::Value>,
   pub(crate) metaprotocol: Option<String>,
+  pub(crate) postage: Option<u64>,
+  pub(crate) sat: Option<Sat>,
+  pub(crate) satpoint: Option<SatPoint>,
 }
 
 impl BatchEntry {
@@ -553,6 +711,8 @@ pub(crate) struct Batchfile {
   pub(crate) inscriptions: Vec<BatchEntry>,
   pub(crate) mode: Mode,
   pub(crate) parent: Option<InscriptionId>,
+  pub(crate) sat: Option<Sat>,
+  pub(crate) satpoint: Option<SatPoint>,
 }
 
 impl Batchfile {
@@ -563,6 +723,55 @@ impl Batchfile {
       bail!("batchfile must contain at least one inscription");
     }
 
+    if batchfile.sat.is_some() && batchfile.satpoint.is_some() {
+      bail!("batchfile cannot set both `sat` and `satpoint`");
+    }
+
+    if batchfile.mode != Mode::SameSat && (batchfile.sat.is_some() || batchfile.satpoint.is_some())
+    {
+      bail!("`sat` and `satpoint` can only be set at the top level in same-sat mode");
+    }
+
+    for entry in &batchfile.inscriptions {
+      if entry.sat.is_some() && entry.satpoint.is_some() {
+        bail!("inscriptions cannot set both `sat` and `satpoint`");
+      }
+
+      match batchfile.mode {
+        Mode::SameSat => {
+          if entry.destination.is_some()
+            || entry.postage.is_some()
+            || entry.sat.is_some()
+            || entry.satpoint.is_some()
+          {
+            bail!(
+              "`destination`, `postage`, `sat` and `satpoint` cannot be set for individual inscriptions in same-sat mode"
+            );
+          }
+        }
+        Mode::SatPoints => {
+          if entry.sat.is_none() && entry.satpoint.is_none() {
+            bail!("each inscription must set `sat` or `satpoint` in satpoints mode");
+          }
+
+          if entry.postage.is_some() {
+            bail!("`postage` cannot be set in satpoints mode");
+          }
+        }
+        Mode::SeparateOutputs | Mode::SharedOutput => {
+          if entry.sat.is_some() || entry.satpoint.is_some() {
+            bail!(
+              "`sat` and `satpoint` can only be set for individual inscriptions in satpoints mode"
+            );
+          }
+
+          if batchfile.mode == Mode::SharedOutput && entry.destination.is_some() {
+            bail!("`destination` cannot be set for individual inscriptions in shared-output mode");
+          }
+        }
+      }
+    }
+
     Ok(batchfile)
   }
 
@@ -571,7 +780,9 @@ impl Batchfile {
     chain: Chain,
     parent_value: Option<u64>,
     metadata: Option<Vec<u8>>,
-    postage: Amount,
+    postages: &[Amount],
+    reveal_satpoints: &[(SatPoint, TxOut)],
+    compress: bool,
   ) -> Result<Vec<Inscription>> {
     assert!(!self.inscriptions.is_empty());
 
@@ -588,17 +799,32 @@ impl Batchfile {
     for (i, entry) in self.inscriptions.iter().enumerate() {
       inscriptions.push(Inscription::from_file(
         chain,
-        &entry.file,
+        entry.file.as_deref(),
+        entry.delegate,
         self.parent,
-        if i == 0 { None } else { Some(pointer) },
+        match self.mode {
+          Mode::SameSat => None,
+          Mode::SatPoints => Some(pointer + reveal_satpoints[i].0.offset),
+          Mode::SeparateOutputs | Mode::SharedOutput => {
+            if i == 0 {
+              None
+            } else {
+              Some(pointer)
+            }
+          }
+        },
         entry.metaprotocol.clone(),
         match &metadata {
           Some(metadata) => Some(metadata.clone()),
           None => entry.metadata()?,
         },
+        compress,
       )?);
 
-      pointer += postage.to_sat();
+      pointer += match self.mode {
+        Mode::SatPoints => reveal_satpoints[i].1.value,
+        Mode::SameSat | Mode::SeparateOutputs | Mode::SharedOutput => postages[i].to_sat(),
+      };
     }
 
     Ok(inscriptions)
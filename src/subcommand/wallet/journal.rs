@@ -0,0 +1,245 @@
+use {
+  super::*,
+  crate::index::BitcoinCoreRpcResultExt,
+  bitcoin::{
+    key::{KeyPair, PrivateKey},
+    secp256k1::Message,
+    sighash::{Prevouts, SighashCache, TapSighashType},
+    taproot::{LeafVersion, Signature, TapLeafHash},
+  },
+  bitcoincore_rpc::bitcoincore_rpc_json::SignRawTransactionInput,
+};
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Status {
+  /// The commit transaction is not known to the wallet.
+  Unsent,
+  /// The commit output is unspent.
+  Committed,
+  /// The commit output is spent by an unconfirmed reveal or sweep.
+  Spending,
+  /// The commit output is spent by a confirmed reveal or sweep, or the commit
+  /// transaction conflicts with a confirmed transaction.
+  Settled,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub(crate) struct Pending {
+  pub(crate) commit: Transaction,
+  pub(crate) commit_input: usize,
+  pub(crate) output: inscribe::Output,
+  pub(crate) recovery_key: PrivateKey,
+  pub(crate) reveal: Transaction,
+  pub(crate) reveal_key: PrivateKey,
+  pub(crate) reveal_prevouts: Vec<TxOut>,
+  #[serde(default)]
+  pub(crate) sweep: Option<Transaction>,
+}
+
+impl Pending {
+  pub(crate) fn commit_outpoint(&self) -> OutPoint {
+    self.reveal.input[self.commit_input].previous_output
+  }
+
+  /// Uses wallet RPCs, since without `-txindex` `getrawtransaction` cannot see
+  /// confirmed transactions.
+  pub(crate) fn status(&self, client: &Client) -> Result<Status> {
+    let commit = self.commit.txid();
+    let outpoint = self.commit_outpoint();
+
+    let Some(info) = client.get_transaction(&commit, None).into_option()? else {
+      return Ok(Status::Unsent);
+    };
+
+    let confirmations = info.info.confirmations;
+
+    if confirmations < 0 {
+      return Ok(Status::Settled);
+    }
+
+    if client
+      .get_tx_out(&outpoint.txid, outpoint.vout, Some(true))?
+      .is_some()
+    {
+      return Ok(Status::Committed);
+    }
+
+    if confirmations > 0 {
+      return Ok(
+        if client
+          .get_tx_out(&outpoint.txid, outpoint.vout, Some(false))?
+          .is_some()
+        {
+          Status::Spending
+        } else {
+          Status::Settled
+        },
+      );
+    }
+
+    // An unconfirmed commit whose output is not in the UTXO set has either been
+    // spent in the mempool or dropped from it, and since anything spending it
+    // is unconfirmed too, `getrawtransaction` can see it
+    if self.is_spent_by(client, &self.reveal)?
+      || match &self.sweep {
+        Some(sweep) => self.is_spent_by(client, sweep)?,
+        None => false,
+      }
+    {
+      Ok(Status::Spending)
+    } else {
+      Ok(Status::Unsent)
+    }
+  }
+
+  fn is_spent_by(&self, client: &Client, tx: &Transaction) -> Result<bool> {
+    Ok(
+      client
+        .get_raw_transaction(&tx.txid(), None)
+        .into_option()?
+        .is_some(),
+    )
+  }
+
+  pub(crate) fn replace_reveal(&mut self, reveal: Transaction, additional_fee: u64) {
+    let txid = reveal.txid();
+
+    self.output.reveal = txid;
+    self.output.total_fees += additional_fee;
+
+    for info in &mut self.output.inscriptions {
+      info.id.txid = txid;
+      info.location.outpoint.txid = txid;
+    }
+
+    self.reveal = reveal;
+  }
+
+  /// Replace the signature spending the commit output in `reveal`, which must
+  /// keep the reveal script and control block of the original reveal.
+  pub(crate) fn sign_commit_input(&self, reveal: &mut Transaction, prevouts: &[TxOut]) {
+    let witness = &self.reveal.input[self.commit_input].witness;
+
+    let reveal_script = ScriptBuf::from(witness.nth(1).unwrap().to_vec());
+    let control_block = witness.nth(2).unwrap().to_vec();
+
+    let sighash = SighashCache::new(&*reveal)
+      .taproot_script_spend_signature_hash(
+        self.commit_input,
+        &Prevouts::All(prevouts),
+        TapLeafHash::from_script(&reveal_script, LeafVersion::TapScript),
+        TapSighashType::Default,
+      )
+      .expect("signature hash should compute");
+
+    let secp256k1 = Secp256k1::new();
+
+    let sig = secp256k1.sign_schnorr(
+      &Message::from_slice(sighash.as_ref()).expect("should be cryptographically secure hash"),
+      &KeyPair::from_secret_key(&secp256k1, &self.reveal_key.inner),
+    );
+
+    reveal.input[self.commit_input].witness = Witness::from_slice(&[
+      Signature {
+        sig,
+        hash_ty: TapSighashType::Default,
+      }
+      .to_vec(),
+      reveal_script.to_bytes(),
+      control_block,
+    ]);
+  }
+
+  pub(crate) fn sign_with_wallet(
+    &self,
+    client: &Client,
+    reveal: &Transaction,
+  ) -> Result<Transaction> {
+    let commit_output = &self.reveal_prevouts[self.commit_input];
+    let commit_outpoint = reveal.input[self.commit_input].previous_output;
+
+    let signed = client.sign_raw_transaction_with_wallet(
+      reveal,
+      Some(&[SignRawTransactionInput {
+        txid: commit_outpoint.txid,
+        vout: commit_outpoint.vout,
+        script_pub_key: commit_output.script_pubkey.clone(),
+        redeem_script: None,
+        amount: Some(Amount::from_sat(commit_output.value)),
+      }]),
+      None,
+    )?;
+
+    if !signed.complete {
+      bail!("failed to sign reveal transaction with wallet");
+    }
+
+    Ok(consensus::encode::deserialize(&signed.hex)?)
+  }
+}
+
+pub(crate) struct Journal {
+  pending: BTreeMap<Txid, Pending>,
+  store: Store,
+}
+
+impl Journal {
+  pub(crate) fn load(options: &Options) -> Result<Self> {
+    let store = Store::new(options, "journal.json")?;
+
+    let pending = store.load()?;
+
+    Ok(Self { pending, store })
+  }
+
+  pub(crate) fn commits(&self) -> Vec<Txid> {
+    self.pending.keys().copied().collect()
+  }
+
+  pub(crate) fn get(&self, commit: Txid) -> Option<&Pending> {
+    self.pending.get(&commit)
+  }
+
+  pub(crate) fn get_by_reveal(&self, reveal: Txid) -> Option<&Pending> {
+    self
+      .pending
+      .values()
+      .find(|pending| pending.output.reveal == reveal)
+  }
+
+  pub(crate) fn insert(&mut self, pending: Pending) -> Result {
+    self.pending.insert(pending.commit.txid(), pending);
+    self.save()
+  }
+
+  /// Remove inscriptions whose commit output has been spent by a confirmed
+  /// transaction.
+  pub(crate) fn prune(&mut self, client: &Client) -> Result {
+    let mut settled = Vec::new();
+
+    for (commit, pending) in &self.pending {
+      if pending.status(client)? == Status::Settled {
+        settled.push(*commit);
+      }
+    }
+
+    if settled.is_empty() {
+      return Ok(());
+    }
+
+    for commit in settled {
+      self.pending.remove(&commit);
+    }
+
+    self.save()
+  }
+
+  pub(crate) fn remove(&mut self, commit: Txid) -> Result {
+    self.pending.remove(&commit);
+    self.save()
+  }
+
+  fn save(&self) -> Result {
+    self.store.save(&self.pending)
+  }
+}
@@ -0,0 +1,36 @@
+use {super::*, crate::wallet::Wallet};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Label {
+  #[arg(help = "Label wallet output <OUTPOINT>.")]
+  outpoint: OutPoint,
+  #[arg(help = "Set label to <LABEL>. Removes existing label if omitted.")]
+  label: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Output {
+  pub output: OutPoint,
+  pub label: Option<String>,
+}
+
+impl Label {
+  pub(crate) fn run(self, options: Options) -> SubcommandResult {
+    let index = Index::open(&options)?;
+    index.update()?;
+
+    if !index
+      .get_unspent_outputs(Wallet::load(&options)?)?
+      .contains_key(&self.outpoint)
+    {
+      bail!("output {} not in wallet", self.outpoint);
+    }
+
+    CoinControl::load(&options)?.set_label(self.outpoint, self.label.clone())?;
+
+    Ok(Box::new(Output {
+      output: self.outpoint,
+      label: self.label,
+    }))
+  }
+}
@@ -0,0 +1,100 @@
+use {
+  super::*,
+  crate::runes::{Edict, Runestone, CLAIM_BIT},
+  bitcoin::blockdata::locktime::absolute::LockTime,
+};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Mint {
+  #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vB.")]
+  fee_rate: FeeRate,
+  #[arg(help = "Mint <RUNE>.")]
+  rune: Rune,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Output {
+  pub rune: Rune,
+  pub amount: String,
+  pub transaction: Txid,
+}
+
+impl Mint {
+  pub(crate) fn run(self, options: Options) -> SubcommandResult {
+    let index = Index::open(&options)?;
+
+    if !index.has_rune_index() {
+      bail!("`ord wallet mint` requires index created with `--index-runes-pre-alpha-i-agree-to-get-rekt` flag");
+    }
+
+    index.update()?;
+
+    let rune = self.rune;
+
+    let (id, entry) = index
+      .rune(rune)?
+      .ok_or_else(|| anyhow!("rune `{rune}` has not been etched"))?;
+
+    let Some(limit) = entry.limit else {
+      bail!("rune `{rune}` is not mintable");
+    };
+
+    if let Some(end) = entry.end {
+      if index.block_count()? >= end {
+        bail!("rune `{rune}` mint term ended at block {end}");
+      }
+    }
+
+    let runestone = Runestone {
+      edicts: vec![Edict {
+        amount: limit,
+        id: u128::from(id) | CLAIM_BIT,
+        output: 1,
+      }],
+      ..Default::default()
+    };
+
+    let client = options.bitcoin_rpc_client_for_wallet_command(false)?;
+
+    let mut transaction = Transaction {
+      version: 2,
+      lock_time: LockTime::ZERO,
+      input: Vec::new(),
+      output: vec![
+        TxOut {
+          script_pubkey: runestone.encipher(),
+          value: 0,
+        },
+        TxOut {
+          script_pubkey: get_change_address(&client, options.chain())?.script_pubkey(),
+          value: 0,
+        },
+      ],
+    };
+
+    fund_rune_transaction(
+      &mut transaction,
+      get_rune_free_cardinals(&index, &options)?,
+      0,
+      1,
+      self.fee_rate,
+    )?;
+
+    let signed_transaction = client
+      .sign_raw_transaction_with_wallet(&transaction, None, None)?
+      .hex;
+
+    let txid = client.send_raw_transaction(&signed_transaction)?;
+
+    Ok(Box::new(Output {
+      rune,
+      amount: Pile {
+        amount: limit,
+        divisibility: entry.divisibility,
+        symbol: entry.symbol,
+      }
+      .to_string(),
+      transaction: txid,
+    }))
+  }
+}
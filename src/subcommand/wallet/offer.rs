@@ -0,0 +1,21 @@
+use super::*;
+
+pub mod accept;
+pub mod create;
+
+#[derive(Debug, Parser)]
+pub(crate) enum Offer {
+  #[command(about = "Accept offer to sell an inscription")]
+  Accept(accept::Accept),
+  #[command(about = "Create offer to sell an inscription")]
+  Create(create::Create),
+}
+
+impl Offer {
+  pub(crate) fn run(self, options: Options) -> SubcommandResult {
+    match self {
+      Self::Accept(accept) => accept.run(options),
+      Self::Create(create) => create.run(options),
+    }
+  }
+}
@@ -0,0 +1,175 @@
+use {
+  super::*,
+  bitcoin::{psbt::Psbt, sighash::TapSighashType},
+  bitcoincore_rpc::json::AddressType,
+};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Accept {
+  #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vB.")]
+  fee_rate: FeeRate,
+  #[arg(long, help = "Refuse to pay more than <MAX_PRICE> for offer.")]
+  max_price: Amount,
+  #[arg(help = "Accept base64-encoded offer <PSBT>.")]
+  psbt: Psbt,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Output {
+  pub inscriptions: Vec<InscriptionId>,
+  pub price: u64,
+  pub transaction: Txid,
+}
+
+impl Accept {
+  pub(crate) fn run(self, options: Options) -> SubcommandResult {
+    let offer = &self.psbt;
+
+    if offer.unsigned_tx.input.len() != 1 || offer.unsigned_tx.output.len() != 1 {
+      bail!("offer PSBT must have exactly one input and one output");
+    }
+
+    let witness = offer.inputs[0]
+      .final_script_witness
+      .clone()
+      .ok_or_else(|| anyhow!("offer input is not signed"))?;
+
+    if witness
+      .nth(0)
+      .and_then(|signature| signature.last().copied())
+      != Some(TapSighashType::SinglePlusAnyoneCanPay as u8)
+    {
+      bail!("offer input must be signed with SIGHASH_SINGLE|SIGHASH_ANYONECANPAY");
+    }
+
+    let seller_outpoint = offer.unsigned_tx.input[0].previous_output;
+    let seller_output = offer.unsigned_tx.output[0].clone();
+
+    if seller_output.value > self.max_price.to_sat() {
+      bail!(
+        "offer price of {} sats exceeds maximum price of {} sats",
+        seller_output.value,
+        self.max_price.to_sat()
+      );
+    }
+
+    let inscriptions = TransactionBuilder::psbt_input_inscriptions(&offer.inputs[0])
+      .ok_or_else(|| anyhow!("offer PSBT contains invalid inscription fields"))?;
+
+    if inscriptions.is_empty() {
+      bail!("offer PSBT does not contain any inscriptions");
+    }
+
+    let index = Index::open(&options)?;
+    index.update()?;
+
+    for (inscription_id, offset) in &inscriptions {
+      let satpoint = SatPoint {
+        outpoint: seller_outpoint,
+        offset: *offset,
+      };
+
+      if index.get_inscription_satpoint_by_id(*inscription_id)? != Some(satpoint) {
+        bail!("offer input {seller_outpoint} does not contain inscription {inscription_id}");
+      }
+    }
+
+    let seller_value = index
+      .get_transaction(seller_outpoint.txid)?
+      .and_then(|transaction| {
+        transaction
+          .output
+          .into_iter()
+          .nth(seller_outpoint.vout.try_into().unwrap())
+      })
+      .ok_or_else(|| anyhow!("output {seller_outpoint} not found"))?
+      .value;
+
+    let client = options.bitcoin_rpc_client_for_wallet_command(false)?;
+
+    let mut cardinals = get_rune_free_cardinals(&index, &options)?;
+
+    cardinals.sort_by_key(|(_outpoint, amount)| *amount);
+
+    let insufficient_funds = || {
+      anyhow!(
+        "wallet does not contain enough cardinal UTXOs, please add additional funds to wallet."
+      )
+    };
+
+    // the padding input comes first so that the inscription, along with the
+    // padding, ends up in the buyer's output
+    if cardinals.is_empty() {
+      return Err(insufficient_funds());
+    }
+
+    let (padding, padding_value) = cardinals.remove(0);
+
+    let inscription_value = padding_value.to_sat() + seller_value;
+
+    let destination = client
+      .get_new_address(None, Some(AddressType::Bech32m))?
+      .require_network(options.chain().network())?;
+
+    let change = get_change_address(&client, options.chain())?.script_pubkey();
+
+    let change_dust_value = change.dust_value().to_sat();
+
+    let mut transaction = Transaction {
+      version: offer.unsigned_tx.version,
+      lock_time: offer.unsigned_tx.lock_time,
+      input: vec![
+        unsigned_input(padding),
+        TxIn {
+          witness,
+          ..offer.unsigned_tx.input[0].clone()
+        },
+      ],
+      output: vec![
+        TxOut {
+          value: inscription_value,
+          script_pubkey: destination.script_pubkey(),
+        },
+        seller_output.clone(),
+        TxOut {
+          value: 0,
+          script_pubkey: change,
+        },
+      ],
+    };
+
+    let mut input_value = inscription_value;
+
+    loop {
+      let fee = estimate_fee(&transaction, self.fee_rate).to_sat();
+
+      let required = inscription_value + seller_output.value + fee + change_dust_value;
+
+      if input_value >= required {
+        transaction.output[2].value = input_value - inscription_value - seller_output.value - fee;
+        break;
+      }
+
+      let (outpoint, value) = cardinals.pop().ok_or_else(insufficient_funds)?;
+
+      transaction.input.push(unsigned_input(outpoint));
+
+      input_value += value.to_sat();
+    }
+
+    let signed_transaction = client
+      .sign_raw_transaction_with_wallet(&transaction, None, None)?
+      .hex;
+
+    let txid = client.send_raw_transaction(&signed_transaction)?;
+
+    Ok(Box::new(Output {
+      inscriptions: inscriptions
+        .into_iter()
+        .map(|(inscription_id, _offset)| inscription_id)
+        .collect(),
+      price: seller_output.value,
+      transaction: txid,
+    }))
+  }
+}
@@ -0,0 +1,100 @@
+use {
+  super::*,
+  crate::wallet::Wallet,
+  bitcoin::{
+    blockdata::{locktime::absolute::LockTime, witness::Witness},
+    psbt::Psbt,
+    sighash::EcdsaSighashType,
+  },
+};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Create {
+  #[arg(help = "Offer <INSCRIPTION_ID> for sale.")]
+  inscription: InscriptionId,
+  #[arg(help = "Sell inscription for <PRICE>.")]
+  price: Amount,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Output {
+  pub inscription: InscriptionId,
+  pub price: u64,
+  pub psbt: String,
+}
+
+impl Create {
+  pub(crate) fn run(self, options: Options) -> SubcommandResult {
+    let index = Index::open(&options)?;
+    index.update()?;
+
+    let client = options.bitcoin_rpc_client_for_wallet_command(false)?;
+
+    let unspent_outputs = index.get_unspent_outputs(Wallet::load(&options)?)?;
+
+    let satpoint = index
+      .get_inscription_satpoint_by_id(self.inscription)?
+      .ok_or_else(|| anyhow!("inscription {} not found", self.inscription))?;
+
+    if !unspent_outputs.contains_key(&satpoint.outpoint) {
+      bail!("inscription {} not in wallet", self.inscription);
+    }
+
+    let inscriptions = index
+      .get_inscriptions(&unspent_outputs)?
+      .into_iter()
+      .filter(|(inscription_satpoint, _)| inscription_satpoint.outpoint == satpoint.outpoint)
+      .collect::<BTreeMap<SatPoint, InscriptionId>>();
+
+    let payment = get_change_address(&client, options.chain())?.script_pubkey();
+
+    let dust_value = payment.dust_value();
+
+    if self.price < dust_value {
+      bail!(
+        "price {} is below dust value {}",
+        self.price.to_sat(),
+        dust_value.to_sat()
+      );
+    }
+
+    let unsigned_transaction = Transaction {
+      version: 1,
+      lock_time: LockTime::ZERO,
+      input: vec![TxIn {
+        previous_output: satpoint.outpoint,
+        script_sig: ScriptBuf::new(),
+        sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+        witness: Witness::new(),
+      }],
+      output: vec![TxOut {
+        value: self.price.to_sat(),
+        script_pubkey: payment,
+      }],
+    };
+
+    let signed_transaction: Transaction = consensus::encode::deserialize(
+      &client
+        .sign_raw_transaction_with_wallet(
+          &unsigned_transaction,
+          None,
+          Some(EcdsaSighashType::SinglePlusAnyoneCanPay.into()),
+        )?
+        .hex,
+    )?;
+
+    let mut psbt = Psbt::from_unsigned_tx(unsigned_transaction)?;
+
+    psbt.inputs[0].final_script_witness = Some(signed_transaction.input[0].witness.clone());
+
+    add_witness_utxos(&index, &mut psbt)?;
+
+    TransactionBuilder::annotate_psbt_inscriptions(&mut psbt, &inscriptions);
+
+    Ok(Box::new(Output {
+      inscription: self.inscription,
+      price: self.price.to_sat(),
+      psbt: psbt.to_string(),
+    }))
+  }
+}
@@ -4,17 +4,24 @@ use {super::*, crate::wallet::Wallet};
 pub struct Output {
   pub output: OutPoint,
   pub amount: u64,
+  pub frozen: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub label: Option<String>,
 }
 
 pub(crate) fn run(options: Options) -> SubcommandResult {
   let index = Index::open(&options)?;
   index.update()?;
 
+  let coin_control = CoinControl::load(&options)?;
+
   let mut outputs = Vec::new();
   for (output, amount) in index.get_unspent_outputs(Wallet::load(&options)?)? {
     outputs.push(Output {
       output,
       amount: amount.to_sat(),
+      frozen: coin_control.is_frozen(output),
+      label: coin_control.label(output).cloned(),
     });
   }
 
@@ -0,0 +1,172 @@
+use super::{
+  inscribe,
+  journal::{Pending, Status},
+  *,
+};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Resume {
+  #[arg(
+    long,
+    help = "Re-sign reveal transactions with fee rate of <FEE_RATE> sats/vB, paying the additional fee from their last output."
+  )]
+  fee_rate: Option<FeeRate>,
+  #[arg(
+    help = "Resume inscription with commit transaction <COMMIT>. Defaults to all pending inscriptions."
+  )]
+  commit: Option<Txid>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Output {
+  pub inscriptions: Vec<inscribe::Output>,
+}
+
+impl Resume {
+  pub(crate) fn run(self, options: Options) -> SubcommandResult {
+    let client = options.bitcoin_rpc_client_for_wallet_command(false)?;
+
+    let mut journal = Journal::load(&options)?;
+
+    journal.prune(&client)?;
+
+    let commits = match self.commit {
+      Some(commit) => {
+        if journal.get(commit).is_none() {
+          bail!("no pending inscription with commit transaction {commit}");
+        }
+        vec![commit]
+      }
+      None => journal.commits(),
+    };
+
+    let mut inscriptions = Vec::new();
+
+    for commit in commits {
+      let mut pending = journal.get(commit).unwrap().clone();
+
+      if pending.sweep.is_some() {
+        if self.commit.is_some() {
+          bail!("inscription with commit transaction {commit} has been abandoned");
+        }
+        continue;
+      }
+
+      let status = pending.status(&client)?;
+
+      if matches!(status, Status::Spending | Status::Settled) {
+        continue;
+      }
+
+      // commit transactions created with `--psbt` are signed and broadcast
+      // externally
+      if status == Status::Unsent
+        && pending
+          .commit
+          .input
+          .iter()
+          .all(|input| input.witness.is_empty())
+      {
+        if self.commit.is_some() {
+          bail!("commit transaction {commit} is unsigned and has not been broadcast");
+        }
+        continue;
+      }
+
+      Self::resume(&client, &mut pending, status, self.fee_rate)?;
+
+      inscriptions.push(pending.output.clone());
+
+      journal.insert(pending)?;
+    }
+
+    Ok(Box::new(Output { inscriptions }))
+  }
+
+  fn resume(
+    client: &Client,
+    pending: &mut Pending,
+    status: Status,
+    fee_rate: Option<FeeRate>,
+  ) -> Result {
+    if status == Status::Unsent {
+      client.send_raw_transaction(&pending.commit)?;
+    }
+
+    if let Some(fee_rate) = fee_rate {
+      let mut reveal = pending.reveal.clone();
+
+      let fee = Self::bump(&mut reveal, pending, fee_rate)?;
+
+      if reveal.input.len() > 1 {
+        reveal = pending.sign_with_wallet(client, &reveal)?;
+      }
+
+      pending.replace_reveal(reveal, fee);
+    }
+
+    client
+      .send_raw_transaction(&pending.reveal)
+      .with_context(|| {
+        format!(
+          "failed to send reveal transaction {}",
+          pending.reveal.txid()
+        )
+      })?;
+
+    Ok(())
+  }
+
+  fn bump(reveal: &mut Transaction, pending: &Pending, fee_rate: FeeRate) -> Result<u64> {
+    let input_value = pending
+      .reveal_prevouts
+      .iter()
+      .map(|tx_out| tx_out.value)
+      .sum::<u64>();
+
+    let output_value = reveal.output.iter().map(|tx_out| tx_out.value).sum::<u64>();
+
+    let fee = input_value - output_value;
+
+    let target = fee_rate.fee(reveal.vsize()).to_sat();
+
+    if target <= fee {
+      bail!("reveal transaction already pays {fee} sats, which is at least {target} sats at new fee rate");
+    }
+
+    let vout = reveal.output.len() - 1;
+
+    let last = &mut reveal.output[vout];
+
+    let minimum = pending
+      .output
+      .inscriptions
+      .iter()
+      .filter(|info| info.location.outpoint.vout as usize == vout)
+      .map(|info| info.location.offset + 1)
+      .max()
+      .unwrap_or_default()
+      .max(last.script_pubkey.dust_value().to_sat());
+
+    last.value = last
+      .value
+      .checked_sub(target - fee)
+      .filter(|value| *value >= minimum)
+      .ok_or_else(|| {
+        anyhow!(
+          "reveal transaction output {vout} cannot pay additional fee of {} sats",
+          target - fee
+        )
+      })?;
+
+    for (i, input) in reveal.input.iter_mut().enumerate() {
+      if i != pending.commit_input {
+        input.witness.clear();
+      }
+    }
+
+    pending.sign_commit_input(reveal, &pending.reveal_prevouts);
+
+    Ok(target - fee)
+  }
+}
@@ -0,0 +1,34 @@
+use {super::*, crate::wallet::Wallet};
+
+#[derive(Serialize, Deserialize)]
+pub struct Output {
+  pub runes: BTreeMap<Rune, String>,
+}
+
+pub(crate) fn run(options: Options) -> SubcommandResult {
+  let index = Index::open(&options)?;
+
+  if !index.has_rune_index() {
+    bail!("`ord wallet runes` requires index created with `--index-runes-pre-alpha-i-agree-to-get-rekt` flag");
+  }
+
+  index.update()?;
+
+  let mut balances: BTreeMap<Rune, Pile> = BTreeMap::new();
+
+  for outpoint in index.get_unspent_outputs(Wallet::load(&options)?)?.keys() {
+    for (rune, pile) in index.get_rune_balances_for_outpoint(*outpoint)? {
+      balances
+        .entry(rune)
+        .and_modify(|balance| balance.amount += pile.amount)
+        .or_insert(pile);
+    }
+  }
+
+  Ok(Box::new(Output {
+    runes: balances
+      .into_iter()
+      .map(|(rune, pile)| (rune, pile.to_string()))
+      .collect(),
+  }))
+}
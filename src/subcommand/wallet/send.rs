@@ -1,4 +1,13 @@
-use {super::*, crate::subcommand::wallet::transaction_builder::Target, crate::wallet::Wallet};
+use {
+  super::*,
+  crate::{
+    runes::{Edict, Runestone},
+    subcommand::wallet::transaction_builder::Target,
+    wallet::Wallet,
+  },
+  bitcoin::blockdata::{locktime::absolute::LockTime, witness::Witness},
+  std::collections::BTreeSet,
+};
 
 #[derive(Debug, Parser, Clone)]
 pub(crate) struct Send {
@@ -6,16 +15,29 @@ pub(crate) struct Send {
   outgoing: Outgoing,
   #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vB")]
   fee_rate: FeeRate,
+  #[arg(
+    long,
+    value_delimiter = ',',
+    help = "Only use comma-separated outpoints <INPUTS> as cardinal inputs."
+  )]
+  pub(crate) inputs: Vec<OutPoint>,
   #[arg(
     long,
     help = "Target amount of postage to include with sent inscriptions. Default `10000sat`"
   )]
   pub(crate) postage: Option<Amount>,
+  #[arg(
+    long,
+    help = "Output an unsigned PSBT for signing by an external signer instead of signing and broadcasting the transaction."
+  )]
+  pub(crate) psbt: bool,
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct Output {
   pub transaction: Txid,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub psbt: Option<String>,
 }
 
 impl Send {
@@ -36,6 +58,9 @@ impl Send {
 
     let locked_outputs = index.get_locked_outputs(Wallet::load(&options)?)?;
 
+    let frozen_outputs =
+      CoinControl::load(&options)?.excluded_utxos(&index, &unspent_outputs, &self.inputs)?;
+
     let inscriptions = index.get_inscriptions(&unspent_outputs)?;
 
     let satpoint = match self.outgoing {
@@ -51,9 +76,43 @@ impl Send {
         .get_inscription_satpoint_by_id(id)?
         .ok_or_else(|| anyhow!("Inscription {id} not found"))?,
       Outgoing::Amount(amount) => {
-        Self::lock_inscriptions(&client, inscriptions, unspent_outputs)?;
+        if self.psbt {
+          bail!("--psbt is only supported when sending sats or inscriptions");
+        }
+        if !self.inputs.is_empty() {
+          bail!("--inputs is only supported when sending sats or inscriptions");
+        }
+        Self::lock_outputs(&client, inscriptions, &frozen_outputs, unspent_outputs)?;
         let txid = Self::send_amount(&client, amount, address, self.fee_rate.n())?;
-        return Ok(Box::new(Output { transaction: txid }));
+        return Ok(Box::new(Output {
+          transaction: txid,
+          psbt: None,
+        }));
+      }
+      Outgoing::Rune { decimal, rune } => {
+        if self.psbt {
+          bail!("--psbt is only supported when sending sats or inscriptions");
+        }
+        if !self.inputs.is_empty() {
+          bail!("--inputs is only supported when sending sats or inscriptions");
+        }
+        let txid = Self::send_runes(
+          address,
+          chain,
+          &client,
+          decimal,
+          self.fee_rate,
+          &frozen_outputs,
+          &index,
+          inscriptions,
+          locked_outputs,
+          rune,
+          unspent_outputs,
+        )?;
+        return Ok(Box::new(Output {
+          transaction: txid,
+          psbt: None,
+        }));
       }
     };
 
@@ -68,17 +127,30 @@ impl Send {
       Target::Postage
     };
 
-    let unsigned_transaction = TransactionBuilder::new(
+    let transaction_builder = TransactionBuilder::new(
       satpoint,
       inscriptions,
       unspent_outputs,
       locked_outputs,
+      frozen_outputs,
       address.clone(),
       change,
       self.fee_rate,
       postage,
-    )
-    .build_transaction()?;
+    );
+
+    if self.psbt {
+      let mut psbt = transaction_builder.build_psbt()?;
+
+      add_witness_utxos(&index, &mut psbt)?;
+
+      return Ok(Box::new(Output {
+        transaction: psbt.unsigned_tx.txid(),
+        psbt: Some(psbt.to_string()),
+      }));
+    }
+
+    let unsigned_transaction = transaction_builder.build_transaction()?;
 
     let signed_tx = client
       .sign_raw_transaction_with_wallet(&unsigned_transaction, None, None)?
@@ -86,12 +158,18 @@ impl Send {
 
     let txid = client.send_raw_transaction(&signed_tx)?;
 
-    Ok(Box::new(Output { transaction: txid }))
+    Ok(Box::new(Output {
+      transaction: txid,
+      psbt: None,
+    }))
   }
 
-  fn lock_inscriptions(
+  /// Lock inscribed and frozen outputs so that Bitcoin Core does not select
+  /// them when funding `sendtoaddress`.
+  fn lock_outputs(
     client: &Client,
     inscriptions: BTreeMap<SatPoint, InscriptionId>,
+    frozen_outputs: &BTreeSet<OutPoint>,
     unspent_outputs: BTreeMap<bitcoin::OutPoint, bitcoin::Amount>,
   ) -> Result {
     let all_inscription_outputs = inscriptions
@@ -101,7 +179,7 @@ impl Send {
 
     let wallet_inscription_outputs = unspent_outputs
       .keys()
-      .filter(|utxo| all_inscription_outputs.contains(utxo))
+      .filter(|utxo| all_inscription_outputs.contains(utxo) || frozen_outputs.contains(utxo))
       .cloned()
       .collect::<Vec<OutPoint>>();
 
@@ -112,6 +190,127 @@ impl Send {
     Ok(())
   }
 
+  fn send_runes(
+    address: Address,
+    chain: Chain,
+    client: &Client,
+    decimal: Decimal,
+    fee_rate: FeeRate,
+    frozen_outputs: &BTreeSet<OutPoint>,
+    index: &Index,
+    inscriptions: BTreeMap<SatPoint, InscriptionId>,
+    locked_outputs: BTreeSet<OutPoint>,
+    rune: Rune,
+    unspent_outputs: BTreeMap<OutPoint, Amount>,
+  ) -> Result<Txid> {
+    if !index.has_rune_index() {
+      bail!("sending runes with `ord send` requires index created with `--index-runes-pre-alpha-i-agree-to-get-rekt` flag");
+    }
+
+    let (id, entry) = index
+      .rune(rune)?
+      .ok_or_else(|| anyhow!("rune `{rune}` has not been etched"))?;
+
+    let amount = decimal.to_amount(entry.divisibility)?;
+
+    if amount == 0 {
+      bail!("cannot send zero `{rune}`");
+    }
+
+    let inscribed_outputs = inscriptions
+      .keys()
+      .map(|satpoint| satpoint.outpoint)
+      .collect::<BTreeSet<OutPoint>>();
+
+    let mut input = Vec::new();
+    let mut input_runes = 0;
+    let mut input_value = 0;
+    let mut cardinals = Vec::new();
+
+    for (outpoint, value) in unspent_outputs {
+      if inscribed_outputs.contains(&outpoint) || locked_outputs.contains(&outpoint) {
+        continue;
+      }
+
+      let balances = index.get_rune_balances_for_outpoint(outpoint)?;
+
+      if balances.is_empty() {
+        if !frozen_outputs.contains(&outpoint) {
+          cardinals.push((outpoint, value));
+        }
+        continue;
+      }
+
+      if input_runes >= amount {
+        continue;
+      }
+
+      if let Some((_, pile)) = balances.iter().find(|(balance, _)| *balance == rune) {
+        input.push(outpoint);
+        input_runes += pile.amount;
+        input_value += value.to_sat();
+      }
+    }
+
+    if input_runes < amount {
+      bail!(
+        "insufficient `{rune}` balance, only {} in wallet",
+        Pile {
+          amount: input_runes,
+          divisibility: entry.divisibility,
+          symbol: entry.symbol,
+        }
+      );
+    }
+
+    let runestone = Runestone {
+      edicts: vec![Edict {
+        amount,
+        id: id.into(),
+        output: 2,
+      }],
+      ..Default::default()
+    };
+
+    // Runes not allocated by the edict are assigned to the first
+    // non-OP_RETURN output, so the change output receives the remainder
+    let mut transaction = Transaction {
+      version: 2,
+      lock_time: LockTime::ZERO,
+      input: input
+        .into_iter()
+        .map(|previous_output| TxIn {
+          previous_output,
+          script_sig: ScriptBuf::new(),
+          sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+          witness: Witness::new(),
+        })
+        .collect(),
+      output: vec![
+        TxOut {
+          script_pubkey: runestone.encipher(),
+          value: 0,
+        },
+        TxOut {
+          script_pubkey: get_change_address(client, chain)?.script_pubkey(),
+          value: 0,
+        },
+        TxOut {
+          script_pubkey: address.script_pubkey(),
+          value: TransactionBuilder::TARGET_POSTAGE.to_sat(),
+        },
+      ],
+    };
+
+    fund_rune_transaction(&mut transaction, cardinals, input_value, 1, fee_rate)?;
+
+    let signed_transaction = client
+      .sign_raw_transaction_with_wallet(&transaction, None, None)?
+      .hex;
+
+    Ok(client.send_raw_transaction(&signed_transaction)?)
+  }
+
   fn send_amount(client: &Client, amount: Amount, address: Address, fee_rate: f64) -> Result<Txid> {
     Ok(client.call(
       "sendtoaddress",
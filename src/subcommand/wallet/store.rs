@@ -0,0 +1,59 @@
+use {super::*, serde::de::DeserializeOwned, std::io::Write};
+
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+
+/// A JSON file in the wallet's data directory, such as the journal or coin
+/// control state.
+pub(crate) struct Store {
+  path: PathBuf,
+}
+
+impl Store {
+  pub(crate) fn new(options: &Options, name: &str) -> Result<Self> {
+    Ok(Self {
+      path: options
+        .data_dir()?
+        .join("wallets")
+        .join(&options.wallet)
+        .join(name),
+    })
+  }
+
+  pub(crate) fn load<T: DeserializeOwned + Default>(&self) -> Result<T> {
+    if !self.path.exists() {
+      return Ok(T::default());
+    }
+
+    serde_json::from_reader(File::open(&self.path)?)
+      .with_context(|| format!("failed to parse {}", self.path.display()))
+  }
+
+  /// Write `value` to a temporary file and rename it into place, so a crash
+  /// never leaves a truncated file behind.
+  pub(crate) fn save<T: Serialize>(&self, value: &T) -> Result {
+    fs::create_dir_all(self.path.parent().unwrap())?;
+
+    let tmp = self.path.with_extension("json.tmp");
+
+    if tmp.exists() {
+      fs::remove_file(&tmp)?;
+    }
+
+    let mut options = fs::OpenOptions::new();
+
+    options.write(true).create_new(true);
+
+    // the journal contains private keys, so no store is world-readable
+    #[cfg(unix)]
+    options.mode(0o600);
+
+    options
+      .open(&tmp)?
+      .write_all(serde_json::to_string_pretty(value)?.as_bytes())?;
+
+    fs::rename(&tmp, &self.path)?;
+
+    Ok(())
+  }
+}
@@ -14,6 +14,11 @@
 //! to send, the wallets current UTXOs and their sat ranges, and the
 //! recipient's address. To build the transaction call `Transaction::build_transaction`.
 //!
+//! `TransactionBuilder::build_psbt` builds the same transaction as an unsigned
+//! PSBT for external signers. Inputs carrying inscriptions are annotated with
+//! proprietary `ord` fields mapping each inscription ID to its offset within
+//! the input.
+//!
 //! `Target::Postage` ensures that the
 //! outgoing value is at most 20,000 sats, reducing it to 10,000 sats if coin
 //! selection requires adding excess value.
@@ -35,6 +40,7 @@ use {
   super::*,
   bitcoin::{
     blockdata::{locktime::absolute::LockTime, witness::Witness},
+    psbt::{self, raw::ProprietaryKey, Psbt},
     Amount, ScriptBuf,
   },
   std::{
@@ -102,6 +108,7 @@ pub struct TransactionBuilder {
   amounts: BTreeMap<OutPoint, Amount>,
   change_addresses: BTreeSet<Address>,
   fee_rate: FeeRate,
+  frozen_utxos: BTreeSet<OutPoint>,
   inputs: Vec<OutPoint>,
   inscriptions: BTreeMap<SatPoint, InscriptionId>,
   outgoing: SatPoint,
@@ -121,12 +128,15 @@ impl TransactionBuilder {
   const SCHNORR_SIGNATURE_SIZE: usize = 64;
   pub(crate) const TARGET_POSTAGE: Amount = Amount::from_sat(10_000);
   pub(crate) const MAX_POSTAGE: Amount = Amount::from_sat(2 * 10_000);
+  pub(crate) const PSBT_PROPRIETARY_PREFIX: &'static [u8] = b"ord";
+  pub(crate) const PSBT_INSCRIPTION_SUBTYPE: u8 = 0;
 
   pub fn new(
     outgoing: SatPoint,
     inscriptions: BTreeMap<SatPoint, InscriptionId>,
     amounts: BTreeMap<OutPoint, Amount>,
     locked_utxos: BTreeSet<OutPoint>,
+    frozen_utxos: BTreeSet<OutPoint>,
     recipient: Address,
     change: [Address; 2],
     fee_rate: FeeRate,
@@ -138,6 +148,7 @@ impl TransactionBuilder {
       amounts,
       change_addresses: change.iter().cloned().collect(),
       fee_rate,
+      frozen_utxos,
       inputs: Vec::new(),
       inscriptions,
       outgoing,
@@ -183,6 +194,52 @@ impl TransactionBuilder {
       .build()
   }
 
+  pub fn build_psbt(self) -> Result<Psbt> {
+    let inscriptions = self.inscriptions.clone();
+
+    let mut psbt = Psbt::from_unsigned_tx(self.build_transaction()?).unwrap();
+
+    Self::annotate_psbt_inscriptions(&mut psbt, &inscriptions);
+
+    Ok(psbt)
+  }
+
+  pub(crate) fn annotate_psbt_inscriptions(
+    psbt: &mut Psbt,
+    inscriptions: &BTreeMap<SatPoint, InscriptionId>,
+  ) {
+    for (input, psbt_input) in psbt.unsigned_tx.input.iter().zip(psbt.inputs.iter_mut()) {
+      for (satpoint, inscription_id) in inscriptions {
+        if satpoint.outpoint == input.previous_output {
+          psbt_input.proprietary.insert(
+            ProprietaryKey {
+              prefix: Self::PSBT_PROPRIETARY_PREFIX.to_vec(),
+              subtype: Self::PSBT_INSCRIPTION_SUBTYPE,
+              key: inscription_id.to_string().into_bytes(),
+            },
+            satpoint.offset.to_le_bytes().to_vec(),
+          );
+        }
+      }
+    }
+  }
+
+  pub(crate) fn psbt_input_inscriptions(input: &psbt::Input) -> Option<Vec<(InscriptionId, u64)>> {
+    input
+      .proprietary
+      .iter()
+      .filter(|(key, _value)| {
+        key.prefix == Self::PSBT_PROPRIETARY_PREFIX && key.subtype == Self::PSBT_INSCRIPTION_SUBTYPE
+      })
+      .map(|(key, value)| {
+        Some((
+          std::str::from_utf8(&key.key).ok()?.parse().ok()?,
+          u64::from_le_bytes(value.as_slice().try_into().ok()?),
+        ))
+      })
+      .collect()
+  }
+
   fn select_outgoing(mut self) -> Result<Self> {
     let dust_limit = self
       .unused_change_addresses
@@ -668,7 +725,10 @@ impl TransactionBuilder {
 
     let mut best_match = None;
     for utxo in &self.utxos {
-      if inscribed_utxos.contains(utxo) || self.locked_utxos.contains(utxo) {
+      if inscribed_utxos.contains(utxo)
+        || self.locked_utxos.contains(utxo)
+        || self.frozen_utxos.contains(utxo)
+      {
         continue;
       }
 
@@ -728,6 +788,7 @@ mod tests {
       BTreeMap::new(),
       utxos.clone().into_iter().collect(),
       BTreeSet::new(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       FeeRate::try_from(1.0).unwrap(),
@@ -761,6 +822,7 @@ mod tests {
     let tx_builder = TransactionBuilder {
       amounts,
       fee_rate: FeeRate::try_from(1.0).unwrap(),
+      frozen_utxos: BTreeSet::new(),
       utxos: BTreeSet::new(),
       outgoing: satpoint(1, 0),
       inscriptions: BTreeMap::new(),
@@ -792,6 +854,51 @@ mod tests {
     )
   }
 
+  #[test]
+  fn psbt_inputs_are_annotated_with_inscriptions() {
+    let utxos = vec![
+      (outpoint(1), Amount::from_sat(10_000)),
+      (outpoint(2), Amount::from_sat(20_000)),
+    ];
+
+    let psbt = TransactionBuilder::new(
+      satpoint(1, 0),
+      BTreeMap::from([(satpoint(1, 0), inscription_id(1))]),
+      utxos.into_iter().collect(),
+      BTreeSet::new(),
+      BTreeSet::new(),
+      recipient(),
+      [change(0), change(1)],
+      FeeRate::try_from(1.0).unwrap(),
+      Target::Postage,
+    )
+    .build_psbt()
+    .unwrap();
+
+    assert_eq!(psbt.unsigned_tx.input[0].previous_output, outpoint(1));
+
+    assert_eq!(
+      psbt.inputs[0].proprietary,
+      BTreeMap::from([(
+        ProprietaryKey {
+          prefix: b"ord".to_vec(),
+          subtype: 0,
+          key: inscription_id(1).to_string().into_bytes(),
+        },
+        0u64.to_le_bytes().to_vec(),
+      )])
+    );
+
+    assert!(psbt.inputs[1..]
+      .iter()
+      .all(|input| input.proprietary.is_empty()));
+
+    assert_eq!(
+      TransactionBuilder::psbt_input_inscriptions(&psbt.inputs[0]),
+      Some(vec![(inscription_id(1), 0)])
+    );
+  }
+
   #[test]
   fn transactions_are_rbf() {
     let utxos = vec![(outpoint(1), Amount::from_sat(5_000))];
@@ -801,6 +908,7 @@ mod tests {
       BTreeMap::new(),
       utxos.into_iter().collect(),
       BTreeSet::new(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       FeeRate::try_from(1.0).unwrap(),
@@ -821,6 +929,7 @@ mod tests {
         BTreeMap::new(),
         utxos.into_iter().collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
@@ -846,6 +955,7 @@ mod tests {
       BTreeMap::new(),
       utxos.into_iter().collect(),
       BTreeSet::new(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       FeeRate::try_from(1.0).unwrap(),
@@ -871,6 +981,7 @@ mod tests {
         BTreeMap::new(),
         utxos.into_iter().collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
@@ -896,6 +1007,7 @@ mod tests {
         BTreeMap::new(),
         utxos.into_iter().collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
@@ -919,6 +1031,7 @@ mod tests {
         BTreeMap::new(),
         utxos.into_iter().collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
@@ -942,6 +1055,7 @@ mod tests {
         BTreeMap::new(),
         utxos.into_iter().collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
@@ -971,6 +1085,7 @@ mod tests {
         .into_iter()
         .collect(),
       BTreeSet::new(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       FeeRate::try_from(1.0).unwrap(),
@@ -990,6 +1105,7 @@ mod tests {
         .into_iter()
         .collect(),
       BTreeSet::new(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       FeeRate::try_from(1.0).unwrap(),
@@ -1009,6 +1125,7 @@ mod tests {
         .into_iter()
         .collect(),
       BTreeSet::new(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       FeeRate::try_from(1.0).unwrap(),
@@ -1028,6 +1145,7 @@ mod tests {
         .into_iter()
         .collect(),
       BTreeSet::new(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       FeeRate::try_from(1.0).unwrap(),
@@ -1054,6 +1172,7 @@ mod tests {
         .into_iter()
         .collect(),
       BTreeSet::new(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       FeeRate::try_from(1.0).unwrap(),
@@ -1077,6 +1196,7 @@ mod tests {
         BTreeMap::new(),
         utxos.into_iter().collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
@@ -1105,6 +1225,7 @@ mod tests {
       BTreeMap::new(),
       utxos.into_iter().collect(),
       BTreeSet::new(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       FeeRate::try_from(1.0).unwrap(),
@@ -1126,6 +1247,7 @@ mod tests {
         BTreeMap::new(),
         utxos.into_iter().collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
@@ -1154,6 +1276,7 @@ mod tests {
         BTreeMap::new(),
         utxos.into_iter().collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
@@ -1179,6 +1302,7 @@ mod tests {
       BTreeMap::new(),
       utxos.into_iter().collect(),
       BTreeSet::new(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       FeeRate::try_from(1.0).unwrap(),
@@ -1207,6 +1331,7 @@ mod tests {
       BTreeMap::new(),
       utxos.into_iter().collect(),
       BTreeSet::new(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       FeeRate::try_from(1.0).unwrap(),
@@ -1233,6 +1358,7 @@ mod tests {
       BTreeMap::new(),
       utxos.into_iter().collect(),
       BTreeSet::new(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       FeeRate::try_from(1.0).unwrap(),
@@ -1256,6 +1382,7 @@ mod tests {
       BTreeMap::new(),
       utxos.into_iter().collect(),
       BTreeSet::new(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       FeeRate::try_from(1.0).unwrap(),
@@ -1279,6 +1406,7 @@ mod tests {
     TransactionBuilder {
       amounts,
       fee_rate: FeeRate::try_from(1.0).unwrap(),
+      frozen_utxos: BTreeSet::new(),
       utxos: BTreeSet::new(),
       locked_utxos: BTreeSet::new(),
       outgoing: satpoint(1, 0),
@@ -1309,6 +1437,7 @@ mod tests {
     TransactionBuilder {
       amounts,
       fee_rate: FeeRate::try_from(1.0).unwrap(),
+      frozen_utxos: BTreeSet::new(),
       utxos: BTreeSet::new(),
       locked_utxos: BTreeSet::new(),
       outgoing: satpoint(1, 0),
@@ -1341,6 +1470,7 @@ mod tests {
         BTreeMap::from([(satpoint(2, 10 * COIN_VALUE), inscription_id(1))]),
         utxos.into_iter().collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
@@ -1361,6 +1491,7 @@ mod tests {
         BTreeMap::from([(satpoint(1, 500), inscription_id(1))]),
         utxos.into_iter().collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
@@ -1386,6 +1517,7 @@ mod tests {
       BTreeMap::from([(satpoint(1, 0), inscription_id(1))]),
       utxos.into_iter().collect(),
       BTreeSet::new(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       fee_rate,
@@ -1418,6 +1550,7 @@ mod tests {
         BTreeMap::new(),
         utxos.into_iter().collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
@@ -1446,6 +1579,7 @@ mod tests {
         BTreeMap::new(),
         utxos.into_iter().collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
@@ -1471,6 +1605,7 @@ mod tests {
         BTreeMap::from([(satpoint(1, 500), inscription_id(1))]),
         utxos.into_iter().collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
@@ -1497,6 +1632,7 @@ mod tests {
         BTreeMap::new(),
         utxos.into_iter().collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
@@ -1520,6 +1656,7 @@ mod tests {
         BTreeMap::new(),
         utxos.into_iter().collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(4.0).unwrap(),
@@ -1562,6 +1699,7 @@ mod tests {
           .into_iter()
           .collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
@@ -1587,6 +1725,7 @@ mod tests {
           .into_iter()
           .collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(1.0).unwrap(),
@@ -1612,6 +1751,7 @@ mod tests {
           .into_iter()
           .collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(5.0).unwrap(),
@@ -1637,6 +1777,7 @@ mod tests {
           .into_iter()
           .collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(6.0).unwrap(),
@@ -1657,6 +1798,7 @@ mod tests {
           .into_iter()
           .collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [recipient(), change(1)],
         FeeRate::try_from(0.0).unwrap(),
@@ -1677,6 +1819,7 @@ mod tests {
           .into_iter()
           .collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(0)],
         FeeRate::try_from(0.0).unwrap(),
@@ -1697,6 +1840,7 @@ mod tests {
           .into_iter()
           .collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(2.0).unwrap(),
@@ -1722,6 +1866,7 @@ mod tests {
           .into_iter()
           .collect(),
         BTreeSet::new(),
+        BTreeSet::new(),
         recipient(),
         [change(0), change(1)],
         FeeRate::try_from(250.0).unwrap(),
@@ -1753,6 +1898,7 @@ mod tests {
       BTreeMap::new(),
       utxos.clone().into_iter().collect(),
       BTreeSet::new(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       FeeRate::try_from(1.0).unwrap(),
@@ -1798,6 +1944,7 @@ mod tests {
       BTreeMap::new(),
       utxos.clone().into_iter().collect(),
       BTreeSet::new(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       FeeRate::try_from(1.0).unwrap(),
@@ -1850,6 +1997,7 @@ mod tests {
       BTreeMap::new(),
       utxos.into_iter().collect(),
       BTreeSet::new(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       FeeRate::try_from(1.0).unwrap(),
@@ -1905,6 +2053,7 @@ mod tests {
       BTreeMap::from([(satpoint(1, 0), inscription_id(1))]),
       utxos.into_iter().collect(),
       BTreeSet::new(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       fee_rate,
@@ -1940,6 +2089,7 @@ mod tests {
       BTreeMap::new(),
       utxos.into_iter().collect(),
       locked_utxos.into_iter().collect(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       FeeRate::try_from(1.0).unwrap(),
@@ -1965,6 +2115,7 @@ mod tests {
       BTreeMap::new(),
       utxos.into_iter().collect(),
       locked_utxos.into_iter().collect(),
+      BTreeSet::new(),
       recipient(),
       [change(0), change(1)],
       FeeRate::try_from(1.0).unwrap(),
@@ -1979,4 +2130,69 @@ mod tests {
       outpoint(2),
     );
   }
+
+  #[test]
+  fn select_cardinal_utxo_ignores_frozen_utxos() {
+    let utxos = vec![
+      (outpoint(1), Amount::from_sat(500)),
+      (outpoint(2), Amount::from_sat(500)),
+    ];
+    let frozen_utxos = vec![outpoint(1)];
+
+    let mut tx_builder = TransactionBuilder::new(
+      satpoint(0, 0),
+      BTreeMap::new(),
+      utxos.into_iter().collect(),
+      BTreeSet::new(),
+      frozen_utxos.into_iter().collect(),
+      recipient(),
+      [change(0), change(1)],
+      FeeRate::try_from(1.0).unwrap(),
+      Target::Value(Amount::from_sat(10_000)),
+    );
+
+    assert_eq!(
+      tx_builder
+        .select_cardinal_utxo(Amount::from_sat(500), false)
+        .unwrap()
+        .0,
+      outpoint(2),
+    );
+
+    assert_eq!(
+      tx_builder.select_cardinal_utxo(Amount::from_sat(500), false),
+      Err(Error::NotEnoughCardinalUtxos),
+    );
+  }
+
+  #[test]
+  fn frozen_outgoing_utxo_can_be_sent() {
+    let utxos = vec![
+      (outpoint(1), Amount::from_sat(10_000)),
+      (outpoint(2), Amount::from_sat(5_000)),
+    ];
+
+    let transaction = TransactionBuilder::new(
+      satpoint(1, 0),
+      BTreeMap::new(),
+      utxos.into_iter().collect(),
+      BTreeSet::new(),
+      [outpoint(1), outpoint(2)].into_iter().collect(),
+      recipient(),
+      [change(0), change(1)],
+      FeeRate::try_from(1.0).unwrap(),
+      Target::Postage,
+    )
+    .build_transaction()
+    .unwrap();
+
+    assert_eq!(
+      transaction
+        .input
+        .iter()
+        .map(|input| input.previous_output)
+        .collect::<Vec<OutPoint>>(),
+      [outpoint(1)],
+    );
+  }
 }
@@ -0,0 +1,53 @@
+use {super::*, crate::wallet::Wallet};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Unfreeze {
+  #[arg(
+    required = true,
+    help = "Allow <OUTPOINT> to be used as a cardinal input again."
+  )]
+  outpoints: Vec<OutPoint>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Output {
+  pub frozen: Vec<OutPoint>,
+}
+
+impl Unfreeze {
+  pub(crate) fn run(self, options: Options) -> SubcommandResult {
+    let mut coin_control = CoinControl::load(&options)?;
+
+    for outpoint in &self.outpoints {
+      if !coin_control.is_frozen(*outpoint) {
+        bail!("output {outpoint} is not frozen");
+      }
+    }
+
+    let index = Index::open(&options)?;
+
+    let locked_outputs = index.get_locked_outputs(Wallet::load(&options)?)?;
+
+    // frozen outputs are locked in Bitcoin Core when sending amounts
+    let unlock = self
+      .outpoints
+      .iter()
+      .filter(|outpoint| locked_outputs.contains(outpoint))
+      .copied()
+      .collect::<Vec<OutPoint>>();
+
+    if !unlock.is_empty()
+      && !options
+        .bitcoin_rpc_client_for_wallet_command(false)?
+        .unlock_unspent(&unlock)?
+    {
+      bail!("failed to unlock frozen UTXOs");
+    }
+
+    coin_control.unfreeze(&self.outpoints)?;
+
+    Ok(Box::new(Output {
+      frozen: coin_control.frozen().iter().copied().collect(),
+    }))
+  }
+}
@@ -1,49 +1,69 @@
 use {super::*, boilerplate::Boilerplate};
 
 pub(crate) use {
+  address::{AddressHtml, AddressJson},
   block::{BlockHtml, BlockJson},
   blocks::BlocksHtml,
-  children::ChildrenHtml,
+  children::{ChildrenHtml, ChildrenJson},
   clock::ClockSvg,
+  collection::{CollectionHtml, CollectionJson},
+  content_hash::{ContentHashHtml, ContentHashJson},
   home::HomeHtml,
   iframe::Iframe,
   input::InputHtml,
-  inscription::{InscriptionHtml, InscriptionJson},
+  inscription::{InscriptionHtml, InscriptionJson, InscriptionRecursiveJson},
+  inscription_history::{InscriptionHistoryHtml, InscriptionHistoryJson},
   inscriptions::{InscriptionsHtml, InscriptionsJson},
   inscriptions_block::InscriptionsBlockHtml,
+  inscriptions_filtered::{InscriptionsFilteredHtml, InscriptionsFilteredJson},
   metadata::MetadataHtml,
+  metaprotocol::{metaprotocol_path, MetaprotocolHtml, MetaprotocolJson},
+  metaprotocols::{MetaprotocolsHtml, MetaprotocolsJson},
   output::{OutputHtml, OutputJson},
   page_config::PageConfig,
   preview::{
     PreviewAudioHtml, PreviewCodeHtml, PreviewImageHtml, PreviewMarkdownHtml, PreviewModelHtml,
     PreviewPdfHtml, PreviewTextHtml, PreviewUnknownHtml, PreviewVideoHtml,
   },
+  provenance_tree::ProvenanceTree,
   range::RangeHtml,
   rare::RareTxt,
   rune::RuneHtml,
+  rune_holders::{RuneHoldersHtml, RuneHoldersJson},
   runes::RunesHtml,
-  sat::{SatHtml, SatJson},
+  sat::{SatHtml, SatInscriptionsJson, SatJson},
+  search::{SearchHtml, SearchJson},
   transaction::TransactionHtml,
 };
 
+pub mod address;
 pub mod block;
 mod blocks;
 mod children;
 mod clock;
+pub mod collection;
+pub mod content_hash;
 mod home;
 mod iframe;
 mod input;
 pub mod inscription;
+pub mod inscription_history;
 pub mod inscriptions;
 mod inscriptions_block;
+pub mod inscriptions_filtered;
 mod metadata;
+pub mod metaprotocol;
+pub mod metaprotocols;
 pub mod output;
 mod preview;
+mod provenance_tree;
 mod range;
 mod rare;
 mod rune;
+pub mod rune_holders;
 mod runes;
 pub mod sat;
+pub mod search;
 mod transaction;
 
 #[derive(Boilerplate)]
@@ -0,0 +1,101 @@
+use super::*;
+
+#[derive(Boilerplate)]
+pub(crate) struct AddressHtml {
+  pub(crate) address: Address,
+  pub(crate) cardinal: u64,
+  pub(crate) inscriptions: Vec<InscriptionId>,
+  pub(crate) outputs: Vec<OutPoint>,
+  pub(crate) runes: Vec<(Rune, Pile)>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct AddressJson {
+  pub cardinal: u64,
+  pub inscriptions: Vec<InscriptionId>,
+  pub outputs: Vec<OutPoint>,
+  pub runes: BTreeMap<Rune, u128>,
+}
+
+impl PageContent for AddressHtml {
+  fn title(&self) -> String {
+    format!("Address {}", self.address)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn empty() {
+    assert_regex_match!(
+      AddressHtml {
+        address: address(),
+        cardinal: 0,
+        inscriptions: Vec::new(),
+        outputs: Vec::new(),
+        runes: Vec::new(),
+      },
+      "
+        <h1>Address <span class=monospace>bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4</span></h1>
+        <dl>
+          <dt>cardinal balance</dt><dd>0</dd>
+        </dl>
+        <h2>0 Outputs</h2>
+        <ul class=monospace>
+        </ul>
+      "
+      .unindent()
+    );
+  }
+
+  #[test]
+  fn with_outputs_inscriptions_and_runes() {
+    assert_regex_match!(
+      AddressHtml {
+        address: address(),
+        cardinal: 3,
+        inscriptions: vec![inscription_id(1)],
+        outputs: vec![outpoint(1), outpoint(2)],
+        runes: vec![(
+          Rune(0),
+          Pile {
+            amount: 11,
+            divisibility: 1,
+            symbol: None,
+          }
+        )],
+      },
+      "
+        <h1>Address <span class=monospace>bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4</span></h1>
+        <dl>
+          <dt>cardinal balance</dt><dd>3</dd>
+          <dt>inscriptions</dt>
+          <dd class=thumbnails>
+            <a href=/inscription/1{64}i1><iframe .* src=/preview/1{64}i1></iframe></a>
+          </dd>
+          <dt>runes</dt>
+          <dd>
+            <table>
+              <tr>
+                <th>rune</th>
+                <th>balance</th>
+              </tr>
+              <tr>
+                <td><a href=/rune/A>A</a></td>
+                <td>1.1</td>
+              </tr>
+            </table>
+          </dd>
+        </dl>
+        <h2>2 Outputs</h2>
+        <ul class=monospace>
+          <li><a href=/output/1{64}:1>1{64}:1</a></li>
+          <li><a href=/output/2{64}:2>2{64}:2</a></li>
+        </ul>
+      "
+      .unindent()
+    );
+  }
+}
@@ -0,0 +1,114 @@
+use {super::*, crate::index::Collection};
+
+#[derive(Boilerplate)]
+pub(crate) struct CollectionHtml {
+  pub(crate) parent: InscriptionId,
+  pub(crate) parent_number: i64,
+  pub(crate) collection: Collection,
+  pub(crate) children: Vec<InscriptionId>,
+  pub(crate) prev_page: Option<usize>,
+  pub(crate) next_page: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct CollectionJson {
+  pub child_count: u64,
+  pub first_height: Option<u64>,
+  pub floor_rarity: Option<Rarity>,
+  pub ids: Vec<InscriptionId>,
+  pub last_height: Option<u64>,
+  pub more: bool,
+  pub page: usize,
+  pub parent: InscriptionId,
+  pub parent_number: i64,
+  pub traits: BTreeMap<String, BTreeMap<String, u64>>,
+}
+
+impl PageContent for CollectionHtml {
+  fn title(&self) -> String {
+    format!("Inscription {} Collection", self.parent_number)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn empty() {
+    assert_regex_match!(
+      CollectionHtml {
+        parent: inscription_id(1),
+        parent_number: 0,
+        collection: Collection::default(),
+        children: Vec::new(),
+        prev_page: None,
+        next_page: None,
+      },
+      "
+        <h1><a href=/inscription/1{64}i1>Inscription 0</a> Collection</h1>
+        <dl>
+          <dt>children</dt>
+          <dd><a href=/children/1{64}i1>0</a></dd>
+        </dl>
+        <h3>No children</h3>
+      "
+      .unindent()
+    );
+  }
+
+  #[test]
+  fn with_stats_traits_and_pagination() {
+    assert_regex_match!(
+      CollectionHtml {
+        parent: inscription_id(1),
+        parent_number: 0,
+        collection: Collection {
+          child_count: 101,
+          first_height: Some(2),
+          floor_rarity: Some(Rarity::Common),
+          last_height: Some(5),
+          traits: [(
+            "color".into(),
+            [("blue".into(), 1), ("red".into(), 100)].into()
+          )]
+          .into(),
+        },
+        children: vec![inscription_id(2), inscription_id(3)],
+        prev_page: Some(1),
+        next_page: Some(3),
+      },
+      "
+        <h1><a href=/inscription/1{64}i1>Inscription 0</a> Collection</h1>
+        <dl>
+          <dt>children</dt>
+          <dd><a href=/children/1{64}i1>101</a></dd>
+          <dt>floor rarity</dt>
+          <dd><span class=common>common</span></dd>
+          <dt>minted</dt>
+          <dd><a href=/block/2>2</a>–<a href=/block/5>5</a></dd>
+        </dl>
+        <h2>Traits</h2>
+        <dl>
+          <dt>color</dt>
+          <dd>
+            <ul>
+              <li>blue \\(1\\)</li>
+              <li>red \\(100\\)</li>
+            </ul>
+          </dd>
+        </dl>
+        <h2>Children</h2>
+        <div class=thumbnails>
+          <a href=/inscription/2{64}i2><iframe .* src=/preview/2{64}i2></iframe></a>
+          <a href=/inscription/3{64}i3><iframe .* src=/preview/3{64}i3></iframe></a>
+        </div>
+        <div class=center>
+          <a class=prev href=/collection/1{64}i1/1>prev</a>
+          <a class=next href=/collection/1{64}i1/3>next</a>
+        </div>
+      "
+      .unindent()
+    );
+  }
+}
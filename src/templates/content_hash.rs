@@ -0,0 +1,83 @@
+use super::*;
+
+#[derive(Boilerplate)]
+pub(crate) struct ContentHashHtml {
+  pub(crate) content_hash: sha256::Hash,
+  pub(crate) count: u64,
+  pub(crate) inscriptions: Vec<InscriptionId>,
+  pub(crate) prev_page: Option<usize>,
+  pub(crate) next_page: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContentHashJson {
+  pub content_hash: sha256::Hash,
+  pub count: u64,
+  pub ids: Vec<InscriptionId>,
+  pub more: bool,
+  pub page: usize,
+}
+
+impl PageContent for ContentHashHtml {
+  fn title(&self) -> String {
+    format!("Content Hash {}", self.content_hash)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn without_prev_and_next() {
+    assert_regex_match!(
+      ContentHashHtml {
+        content_hash: sha256::Hash::all_zeros(),
+        count: 2,
+        inscriptions: vec![inscription_id(1), inscription_id(2)],
+        prev_page: None,
+        next_page: None,
+      },
+      "
+        <h1>Content Hash</h1>
+        <dl>
+          <dt>hash</dt>
+          <dd class=monospace>0{64}</dd>
+          <dt>inscriptions</dt>
+          <dd>2</dd>
+        </dl>
+        <div class=thumbnails>
+          <a href=/inscription/1{64}i1><iframe .* src=/preview/1{64}i1></iframe></a>
+          <a href=/inscription/2{64}i2><iframe .* src=/preview/2{64}i2></iframe></a>
+        </div>
+        <div class=center>
+        prev
+        next
+        </div>
+      "
+      .unindent()
+    );
+  }
+
+  #[test]
+  fn with_prev_and_next() {
+    assert_regex_match!(
+      ContentHashHtml {
+        content_hash: sha256::Hash::all_zeros(),
+        count: 301,
+        inscriptions: vec![inscription_id(1)],
+        prev_page: Some(1),
+        next_page: Some(3),
+      },
+      "
+        <h1>Content Hash</h1>
+        .*
+        <div class=center>
+          <a class=prev href=/content-hash/0{64}/1>prev</a>
+          <a class=next href=/content-hash/0{64}/3>next</a>
+        </div>
+      "
+      .unindent()
+    );
+  }
+}
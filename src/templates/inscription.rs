@@ -1,9 +1,12 @@
-use super::*;
+use {super::*, crate::subcommand::provenance};
 
 #[derive(Boilerplate, Default)]
 pub(crate) struct InscriptionHtml {
   pub(crate) chain: Chain,
+  pub(crate) charms: u16,
   pub(crate) children: Vec<InscriptionId>,
+  pub(crate) duplicate_count: u64,
+  pub(crate) duplicates: Vec<InscriptionId>,
   pub(crate) genesis_fee: u64,
   pub(crate) genesis_height: u64,
   pub(crate) inscription: Inscription,
@@ -13,6 +16,7 @@ pub(crate) struct InscriptionHtml {
   pub(crate) output: Option<TxOut>,
   pub(crate) parent: Option<InscriptionId>,
   pub(crate) previous: Option<InscriptionId>,
+  pub(crate) provenance: Option<provenance::Output>,
   pub(crate) rune: Option<Rune>,
   pub(crate) sat: Option<Sat>,
   pub(crate) satpoint: SatPoint,
@@ -22,6 +26,7 @@ pub(crate) struct InscriptionHtml {
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct InscriptionJson {
   pub address: Option<String>,
+  pub charms: Vec<Charm>,
   pub children: Vec<InscriptionId>,
   pub content_length: Option<usize>,
   pub content_type: Option<String>,
@@ -39,9 +44,22 @@ pub struct InscriptionJson {
   pub timestamp: i64,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct InscriptionRecursiveJson {
+  pub content_length: Option<usize>,
+  pub content_type: Option<String>,
+  pub height: u64,
+  pub id: InscriptionId,
+  pub number: i64,
+  pub sat: Option<Sat>,
+  pub satpoint: SatPoint,
+  pub timestamp: i64,
+}
+
 impl InscriptionJson {
   pub fn new(
     chain: Chain,
+    charms: u16,
     children: Vec<InscriptionId>,
     genesis_fee: u64,
     genesis_height: u64,
@@ -59,6 +77,7 @@ impl InscriptionJson {
   ) -> Self {
     Self {
       inscription_id,
+      charms: Charm::charms(charms),
       children,
       inscription_number,
       genesis_height,
@@ -124,6 +143,8 @@ mod tests {
           <dd>10 bytes</dd>
           <dt>content type</dt>
           <dd>text/plain;charset=utf-8</dd>
+          <dt>content hash</dt>
+          <dd><a class=monospace href=/content-hash/0b21b7db59cd154904fac6336fa7d2be1bab38d632794f281549584068cdcb74>0b21b7db59cd154904fac6336fa7d2be1bab38d632794f281549584068cdcb74</a></dd>
           <dt>timestamp</dt>
           <dd><time>1970-01-01 00:00:00 UTC</time></dd>
           <dt>genesis height</dt>
@@ -138,6 +159,8 @@ mod tests {
           <dd><a class=monospace href=/output/1{64}:1>1{64}:1</a></dd>
           <dt>offset</dt>
           <dd>0</dd>
+          <dt>history</dt>
+          <dd><a href=/inscription/1{64}i1/history>link</a></dd>
           <dt>ethereum teleburn address</dt>
           <dd>0xa1DfBd1C519B9323FD7Fd8e498Ac16c2E502F059</dd>
         </dl>
@@ -238,6 +261,12 @@ mod tests {
   fn with_cursed_and_unbound() {
     assert_regex_match!(
       InscriptionHtml {
+        charms: {
+          let mut charms = 0;
+          Charm::Cursed.set(&mut charms);
+          Charm::Unbound.set(&mut charms);
+          charms
+        },
         genesis_fee: 1,
         inscription: inscription("text/plain;charset=utf-8", "HELLOWORLD"),
         inscription_id: inscription_id(2),
@@ -254,6 +283,13 @@ mod tests {
         <h1>Inscription -1</h1>
         .*
         <dl>
+          <dt>id</dt>
+          <dd class=monospace>2{64}i2</dd>
+          <dt>charms</dt>
+          <dd>
+            <span title=cursed>👹</span>
+            <span title=unbound>🔓</span>
+          </dd>
           .*
           <dt>location</dt>
           <dd class=monospace>0{64}:0:0</dd>
@@ -266,6 +302,40 @@ mod tests {
     );
   }
 
+  #[test]
+  fn with_duplicate() {
+    assert_regex_match!(
+      InscriptionHtml {
+        duplicate_count: 1,
+        duplicates: vec![inscription_id(2)],
+        genesis_fee: 1,
+        inscription: inscription("text/plain;charset=utf-8", "HELLOWORLD"),
+        inscription_id: inscription_id(1),
+        inscription_number: 1,
+        satpoint: satpoint(1, 0),
+        timestamp: timestamp(0),
+        ..Default::default()
+      },
+      "
+        <h1>Inscription 1</h1>
+        .*
+          <dt>content hash</dt>
+          <dd><a class=monospace href=/content-hash/0b21b7db59cd154904fac6336fa7d2be1bab38d632794f281549584068cdcb74>0b21b7db59cd154904fac6336fa7d2be1bab38d632794f281549584068cdcb74</a></dd>
+          <dt>duplicates</dt>
+          <dd>
+            <div class=thumbnails>
+              <a href=/inscription/2{64}i2><iframe .* src=/preview/2{64}i2></iframe></a>
+            </div>
+            <div class=center>
+              <a href=/content-hash/0b21b7db59cd154904fac6336fa7d2be1bab38d632794f281549584068cdcb74>1 other inscription shares this content</a>
+            </div>
+          </dd>
+        .*
+      "
+      .unindent()
+    );
+  }
+
   #[test]
   fn with_parent() {
     assert_regex_match!(
@@ -298,6 +368,8 @@ mod tests {
           <dd>10 bytes</dd>
           <dt>content type</dt>
           <dd>text/plain;charset=utf-8</dd>
+          <dt>content hash</dt>
+          <dd><a class=monospace href=/content-hash/0b21b7db59cd154904fac6336fa7d2be1bab38d632794f281549584068cdcb74>0b21b7db59cd154904fac6336fa7d2be1bab38d632794f281549584068cdcb74</a></dd>
           <dt>timestamp</dt>
           <dd><time>1970-01-01 00:00:00 UTC</time></dd>
           <dt>genesis height</dt>
@@ -312,6 +384,8 @@ mod tests {
           <dd><a class=monospace href=/output/1{64}:1>1{64}:1</a></dd>
           <dt>offset</dt>
           <dd>0</dd>
+          <dt>history</dt>
+          <dd><a href=/inscription/1{64}i1/history>link</a></dd>
           <dt>ethereum teleburn address</dt>
           <dd>0xa1DfBd1C519B9323FD7Fd8e498Ac16c2E502F059</dd>
         </dl>
@@ -348,6 +422,7 @@ mod tests {
             </div>
             <div class=center>
               <a href=/children/1{64}i1>all</a>
+              <a href=/collection/1{64}i1>collection</a>
             </div>
           </dd>
           <dt>id</dt>
@@ -360,6 +435,8 @@ mod tests {
           <dd>10 bytes</dd>
           <dt>content type</dt>
           <dd>text/plain;charset=utf-8</dd>
+          <dt>content hash</dt>
+          <dd><a class=monospace href=/content-hash/0b21b7db59cd154904fac6336fa7d2be1bab38d632794f281549584068cdcb74>0b21b7db59cd154904fac6336fa7d2be1bab38d632794f281549584068cdcb74</a></dd>
           <dt>timestamp</dt>
           <dd><time>1970-01-01 00:00:00 UTC</time></dd>
           <dt>genesis height</dt>
@@ -374,6 +451,8 @@ mod tests {
           <dd><a class=monospace href=/output/1{64}:1>1{64}:1</a></dd>
           <dt>offset</dt>
           <dd>0</dd>
+          <dt>history</dt>
+          <dd><a href=/inscription/1{64}i1/history>link</a></dd>
           <dt>ethereum teleburn address</dt>
           <dd>0xa1DfBd1C519B9323FD7Fd8e498Ac16c2E502F059</dd>
         </dl>
@@ -409,6 +488,7 @@ mod tests {
             </div>
             <div class=center>
               <a href=/children/1{64}i1>all</a>
+              <a href=/collection/1{64}i1>collection</a>
             </div>
           </dd>
           <dt>id</dt>
@@ -421,6 +501,8 @@ mod tests {
           <dd>10 bytes</dd>
           <dt>content type</dt>
           <dd>text/plain;charset=utf-8</dd>
+          <dt>content hash</dt>
+          <dd><a class=monospace href=/content-hash/0b21b7db59cd154904fac6336fa7d2be1bab38d632794f281549584068cdcb74>0b21b7db59cd154904fac6336fa7d2be1bab38d632794f281549584068cdcb74</a></dd>
           <dt>timestamp</dt>
           <dd><time>1970-01-01 00:00:00 UTC</time></dd>
           <dt>genesis height</dt>
@@ -435,6 +517,8 @@ mod tests {
           <dd><a class=monospace href=/output/1{64}:1>1{64}:1</a></dd>
           <dt>offset</dt>
           <dd>0</dd>
+          <dt>history</dt>
+          <dd><a href=/inscription/1{64}i1/history>link</a></dd>
           <dt>ethereum teleburn address</dt>
           <dd>0xa1DfBd1C519B9323FD7Fd8e498Ac16c2E502F059</dd>
         </dl>
@@ -0,0 +1,151 @@
+use super::*;
+
+#[derive(Boilerplate)]
+pub(crate) struct InscriptionHistoryHtml {
+  pub(crate) inscription_id: InscriptionId,
+  pub(crate) inscription_number: i64,
+  pub(crate) transfers: Vec<TransferEntry>,
+  pub(crate) prev_page: Option<usize>,
+  pub(crate) next_page: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct InscriptionHistoryJson {
+  pub transfers: Vec<InscriptionTransferJson>,
+  pub prev_page: Option<usize>,
+  pub next_page: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct InscriptionTransferJson {
+  pub height: u64,
+  pub new_satpoint: SatPoint,
+  pub old_satpoint: Option<SatPoint>,
+  pub txid: Txid,
+}
+
+impl InscriptionHistoryJson {
+  pub(crate) fn new(
+    transfers: Vec<TransferEntry>,
+    prev_page: Option<usize>,
+    next_page: Option<usize>,
+  ) -> Self {
+    Self {
+      transfers: transfers
+        .into_iter()
+        .map(|transfer| InscriptionTransferJson {
+          height: transfer.height,
+          new_satpoint: transfer.new_satpoint,
+          old_satpoint: transfer.old_satpoint,
+          txid: transfer.txid,
+        })
+        .collect(),
+      prev_page,
+      next_page,
+    }
+  }
+}
+
+impl PageContent for InscriptionHistoryHtml {
+  fn title(&self) -> String {
+    format!("Inscription {} History", self.inscription_number)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn without_prev_and_next() {
+    assert_regex_match!(
+      InscriptionHistoryHtml {
+        inscription_id: inscription_id(1),
+        inscription_number: 0,
+        transfers: vec![
+          TransferEntry {
+            height: 1,
+            new_satpoint: satpoint(1, 0),
+            old_satpoint: None,
+            txid: txid(1),
+          },
+          TransferEntry {
+            height: 2,
+            new_satpoint: satpoint(2, 0),
+            old_satpoint: Some(satpoint(1, 0)),
+            txid: txid(2),
+          },
+        ],
+        prev_page: None,
+        next_page: None,
+      },
+      "
+        <h1><a href=/inscription/1{64}i1>Inscription 0</a> History</h1>
+        <table>
+          .*
+          <tr>
+            <td><a href=/block/1>1</a></td>
+            <td><a class=monospace href=/tx/1{64}>1{64}</a></td>
+            <td>genesis</td>
+            <td class=monospace>1{64}:1:0</td>
+          </tr>
+          <tr>
+            <td><a href=/block/2>2</a></td>
+            <td><a class=monospace href=/tx/2{64}>2{64}</a></td>
+            <td class=monospace>1{64}:1:0</td>
+            <td class=monospace>2{64}:2:0</td>
+          </tr>
+        </table>
+        <div class=center>
+        prev
+        next
+        </div>
+      "
+      .unindent()
+    );
+  }
+
+  #[test]
+  fn with_prev_and_next() {
+    assert_regex_match!(
+      InscriptionHistoryHtml {
+        inscription_id: inscription_id(1),
+        inscription_number: 0,
+        transfers: vec![TransferEntry {
+          height: 1,
+          new_satpoint: satpoint(1, 0),
+          old_satpoint: None,
+          txid: txid(1),
+        }],
+        prev_page: Some(1),
+        next_page: Some(3),
+      },
+      "
+        <h1><a href=/inscription/1{64}i1>Inscription 0</a> History</h1>
+        .*
+          <a class=prev href=/inscription/1{64}i1/history/1>prev</a>
+          <a class=next href=/inscription/1{64}i1/history/3>next</a>
+        .*
+      "
+      .unindent()
+    );
+  }
+
+  #[test]
+  fn empty() {
+    assert_regex_match!(
+      InscriptionHistoryHtml {
+        inscription_id: inscription_id(1),
+        inscription_number: 0,
+        transfers: Vec::new(),
+        prev_page: None,
+        next_page: None,
+      },
+      "
+        <h1><a href=/inscription/1{64}i1>Inscription 0</a> History</h1>
+        <h3>No history</h3>
+      "
+      .unindent()
+    );
+  }
+}
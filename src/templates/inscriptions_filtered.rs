@@ -0,0 +1,74 @@
+use super::*;
+
+#[derive(Boilerplate)]
+pub(crate) struct InscriptionsFilteredHtml {
+  pub(crate) inscriptions: Vec<InscriptionId>,
+  pub(crate) prev_page: Option<usize>,
+  pub(crate) next_page: Option<usize>,
+  pub(crate) query: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct InscriptionsFilteredJson {
+  pub ids: Vec<InscriptionId>,
+  pub more: bool,
+  pub page: usize,
+}
+
+impl PageContent for InscriptionsFilteredHtml {
+  fn title(&self) -> String {
+    "Inscriptions".into()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn without_prev_and_next() {
+    assert_regex_match!(
+      InscriptionsFilteredHtml {
+        inscriptions: vec![inscription_id(1), inscription_id(2)],
+        prev_page: None,
+        next_page: None,
+        query: "content_type=image/&".into(),
+      },
+      "
+        <h1>Inscriptions</h1>
+        <div class=thumbnails>
+          <a href=/inscription/1{64}i1><iframe .* src=/preview/1{64}i1></iframe></a>
+          <a href=/inscription/2{64}i2><iframe .* src=/preview/2{64}i2></iframe></a>
+        </div>
+        <div class=center>
+        prev
+        next
+        </div>
+      "
+      .unindent()
+    );
+  }
+
+  #[test]
+  fn with_prev_and_next() {
+    assert_regex_match!(
+      InscriptionsFilteredHtml {
+        inscriptions: vec![inscription_id(1)],
+        prev_page: Some(1),
+        next_page: Some(3),
+        query: "content_type=image/&order=oldest&".into(),
+      },
+      "
+        <h1>Inscriptions</h1>
+        <div class=thumbnails>
+          <a href=/inscription/1{64}i1><iframe .* src=/preview/1{64}i1></iframe></a>
+        </div>
+        <div class=center>
+        <a class=prev href=/inscriptions\\?content_type=image/&amp;order=oldest&amp;page=1>prev</a>
+        <a class=next href=/inscriptions\\?content_type=image/&amp;order=oldest&amp;page=3>next</a>
+        </div>
+      "
+      .unindent()
+    );
+  }
+}
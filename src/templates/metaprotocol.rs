@@ -0,0 +1,117 @@
+use {
+  super::*,
+  percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC},
+};
+
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+  .remove(b'-')
+  .remove(b'.')
+  .remove(b'_')
+  .remove(b'~');
+
+pub(crate) fn metaprotocol_path(metaprotocol: &str) -> String {
+  format!(
+    "/metaprotocol/{}",
+    utf8_percent_encode(metaprotocol, PATH_SEGMENT)
+  )
+}
+
+#[derive(Boilerplate)]
+pub(crate) struct MetaprotocolHtml {
+  pub(crate) metaprotocol: String,
+  pub(crate) count: u64,
+  pub(crate) inscriptions: Vec<InscriptionId>,
+  pub(crate) prev_page: Option<usize>,
+  pub(crate) next_page: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct MetaprotocolJson {
+  pub count: u64,
+  pub ids: Vec<InscriptionId>,
+  pub metaprotocol: String,
+  pub more: bool,
+  pub page: usize,
+}
+
+impl PageContent for MetaprotocolHtml {
+  fn title(&self) -> String {
+    format!("Metaprotocol {}", self.metaprotocol)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn without_prev_and_next() {
+    assert_regex_match!(
+      MetaprotocolHtml {
+        metaprotocol: "brc-20".into(),
+        count: 2,
+        inscriptions: vec![inscription_id(1), inscription_id(2)],
+        prev_page: None,
+        next_page: None,
+      },
+      "
+        <h1>Metaprotocol brc-20</h1>
+        <dl>
+          <dt>inscriptions</dt>
+          <dd>2</dd>
+        </dl>
+        <div class=thumbnails>
+          <a href=/inscription/1{64}i1><iframe .* src=/preview/1{64}i1></iframe></a>
+          <a href=/inscription/2{64}i2><iframe .* src=/preview/2{64}i2></iframe></a>
+        </div>
+        <div class=center>
+        prev
+        next
+        </div>
+      "
+      .unindent()
+    );
+  }
+
+  #[test]
+  fn with_prev_and_next() {
+    assert_regex_match!(
+      MetaprotocolHtml {
+        metaprotocol: "brc-20".into(),
+        count: 301,
+        inscriptions: vec![inscription_id(1)],
+        prev_page: Some(1),
+        next_page: Some(3),
+      },
+      "
+        <h1>Metaprotocol brc-20</h1>
+        .*
+        <div class=center>
+          <a class=prev href=\"/metaprotocol/brc-20/1\">prev</a>
+          <a class=next href=\"/metaprotocol/brc-20/3\">next</a>
+        </div>
+      "
+      .unindent()
+    );
+  }
+
+  #[test]
+  fn metaprotocol_is_percent_encoded_in_links() {
+    assert_regex_match!(
+      MetaprotocolHtml {
+        metaprotocol: "a b/\"><".into(),
+        count: 1,
+        inscriptions: vec![inscription_id(1)],
+        prev_page: Some(0),
+        next_page: None,
+      },
+      "
+        <h1>Metaprotocol a b/&quot;&gt;&lt;</h1>
+        .*
+          <a class=prev href=\"/metaprotocol/a%20b%2F%22%3E%3C/0\">prev</a>
+        .*
+      "
+      .unindent()
+    );
+  }
+}
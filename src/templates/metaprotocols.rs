@@ -0,0 +1,70 @@
+use super::*;
+
+#[derive(Boilerplate)]
+pub(crate) struct MetaprotocolsHtml {
+  pub(crate) metaprotocols: Vec<(String, u64)>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct MetaprotocolsJson {
+  pub metaprotocols: Vec<MetaprotocolCountJson>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct MetaprotocolCountJson {
+  pub count: u64,
+  pub metaprotocol: String,
+}
+
+impl MetaprotocolsJson {
+  pub(crate) fn new(metaprotocols: Vec<(String, u64)>) -> Self {
+    Self {
+      metaprotocols: metaprotocols
+        .into_iter()
+        .map(|(metaprotocol, count)| MetaprotocolCountJson {
+          count,
+          metaprotocol,
+        })
+        .collect(),
+    }
+  }
+}
+
+impl PageContent for MetaprotocolsHtml {
+  fn title(&self) -> String {
+    "Metaprotocols".to_string()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn empty() {
+    assert_eq!(
+      MetaprotocolsHtml {
+        metaprotocols: Vec::new(),
+      }
+      .to_string(),
+      "<h1>Metaprotocols</h1>\n<h3>No metaprotocols</h3>\n"
+    );
+  }
+
+  #[test]
+  fn with_metaprotocols() {
+    assert_regex_match!(
+      MetaprotocolsHtml {
+        metaprotocols: vec![("brc-20".into(), 2), ("foo".into(), 1)],
+      },
+      "
+        <h1>Metaprotocols</h1>
+        <ul>
+          <li><a href=\"/metaprotocol/brc-20\">brc-20</a> \\(2\\)</li>
+          <li><a href=\"/metaprotocol/foo\">foo</a> \\(1\\)</li>
+        </ul>
+      "
+      .unindent()
+    );
+  }
+}
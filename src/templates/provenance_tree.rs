@@ -0,0 +1,180 @@
+use {
+  super::*,
+  crate::subcommand::provenance::{self, Descendant},
+};
+
+pub(crate) struct ProvenanceTree<'a> {
+  provenance: &'a provenance::Output,
+}
+
+impl<'a> ProvenanceTree<'a> {
+  pub(crate) fn new(provenance: &'a provenance::Output) -> Trusted<Self> {
+    Trusted(Self { provenance })
+  }
+
+  fn descendants(
+    f: &mut Formatter,
+    parent: InscriptionId,
+    descendants: &[Descendant],
+    truncated: bool,
+  ) -> fmt::Result {
+    if descendants.is_empty() {
+      if truncated {
+        write!(f, " <a href=/children/{parent}>…</a>")?;
+      }
+
+      return Ok(());
+    }
+
+    write!(f, "<ul>")?;
+
+    for descendant in descendants {
+      let id = descendant.id;
+
+      write!(f, "<li><a class=monospace href=/inscription/{id}>{id}</a>")?;
+
+      Self::descendants(f, id, &descendant.children, descendant.truncated)?;
+
+      write!(f, "</li>")?;
+    }
+
+    if truncated {
+      write!(f, "<li><a href=/children/{parent}>and more</a></li>")?;
+    }
+
+    write!(f, "</ul>")
+  }
+}
+
+impl Display for ProvenanceTree<'_> {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    let provenance = self.provenance;
+
+    let mut open = 0;
+
+    write!(f, "<ul class=provenance>")?;
+
+    if provenance.ancestors_truncated {
+      write!(f, "<li>…<ul>")?;
+      open += 1;
+    }
+
+    for ancestor in provenance.ancestors.iter().rev() {
+      write!(
+        f,
+        "<li><a class=monospace href=/inscription/{ancestor}>{ancestor}</a><ul>"
+      )?;
+      open += 1;
+    }
+
+    write!(
+      f,
+      "<li><span class=monospace>{}</span>",
+      provenance.inscription
+    )?;
+
+    Self::descendants(
+      f,
+      provenance.inscription,
+      &provenance.descendants,
+      provenance.descendants_truncated,
+    )?;
+
+    write!(f, "</li>")?;
+
+    for _ in 0..open {
+      write!(f, "</ul></li>")?;
+    }
+
+    write!(f, "</ul>")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn ancestors_and_descendants() {
+    assert_eq!(
+      ProvenanceTree::new(&provenance::Output {
+        ancestors: vec![inscription_id(2), inscription_id(1)],
+        ancestors_truncated: false,
+        descendants: vec![Descendant {
+          children: Vec::new(),
+          id: inscription_id(4),
+          truncated: true,
+        }],
+        descendants_truncated: false,
+        inscription: inscription_id(3),
+      })
+      .0
+      .to_string(),
+      format!(
+        "<ul class=provenance>\
+          <li><a class=monospace href=/inscription/{0}>{0}</a><ul>\
+          <li><a class=monospace href=/inscription/{1}>{1}</a><ul>\
+          <li><span class=monospace>{2}</span><ul>\
+          <li><a class=monospace href=/inscription/{3}>{3}</a> <a href=/children/{3}>…</a></li>\
+          </ul></li>\
+          </ul></li>\
+          </ul></li>\
+          </ul>",
+        inscription_id(1),
+        inscription_id(2),
+        inscription_id(3),
+        inscription_id(4),
+      ),
+    );
+  }
+
+  #[test]
+  fn truncated_ancestors_and_many_children() {
+    let tree = ProvenanceTree::new(&provenance::Output {
+      ancestors: vec![inscription_id(1)],
+      ancestors_truncated: true,
+      descendants: (0..8)
+        .map(|_| Descendant {
+          children: Vec::new(),
+          id: inscription_id(3),
+          truncated: false,
+        })
+        .collect(),
+      descendants_truncated: true,
+      inscription: inscription_id(2),
+    })
+    .0
+    .to_string();
+
+    assert!(tree.starts_with("<ul class=provenance><li>…<ul><li><a class=monospace"));
+    assert_eq!(
+      tree
+        .matches(&format!("href=/inscription/{}", inscription_id(3)))
+        .count(),
+      8
+    );
+    assert!(tree.contains(&format!(
+      "<li><a href=/children/{}>and more</a></li>",
+      inscription_id(2)
+    )));
+  }
+
+  #[test]
+  fn inscription_with_unwalked_children_links_to_children() {
+    assert_eq!(
+      ProvenanceTree::new(&provenance::Output {
+        ancestors: Vec::new(),
+        ancestors_truncated: false,
+        descendants: Vec::new(),
+        descendants_truncated: true,
+        inscription: inscription_id(1),
+      })
+      .0
+      .to_string(),
+      format!(
+        "<ul class=provenance><li><span class=monospace>{0}</span> <a href=/children/{0}>…</a></li></ul>",
+        inscription_id(1),
+      ),
+    );
+  }
+}
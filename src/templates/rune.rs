@@ -63,6 +63,8 @@ mod tests {
   <dd>\$123456789.123456789</dd>
   <dt>burned</dt>
   <dd>\$123456789.123456789</dd>
+  <dt>holders</dt>
+  <dd><a href=/rune/BCGDENLQRQWDSLRUGSNLBTMFIJAV/holders>link</a></dd>
   <dt>divisibility</dt>
   <dd>9</dd>
   <dt>symbol</dt>
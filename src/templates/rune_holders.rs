@@ -0,0 +1,157 @@
+use super::*;
+
+#[derive(Boilerplate)]
+pub(crate) struct RuneHoldersHtml {
+  pub(crate) entry: RuneEntry,
+  pub(crate) holders: Vec<RuneHolder>,
+  pub(crate) prev_page: Option<usize>,
+  pub(crate) next_page: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RuneHoldersJson {
+  pub holders: Vec<RuneHolderJson>,
+  pub prev_page: Option<usize>,
+  pub next_page: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RuneHolderJson {
+  pub address: Option<Address<NetworkUnchecked>>,
+  pub amount: u128,
+  pub outpoints: Vec<OutPoint>,
+}
+
+impl RuneHoldersJson {
+  pub(crate) fn new(
+    holders: Vec<RuneHolder>,
+    prev_page: Option<usize>,
+    next_page: Option<usize>,
+  ) -> Self {
+    Self {
+      holders: holders
+        .into_iter()
+        .map(|holder| RuneHolderJson {
+          address: holder
+            .address
+            .map(|address| Address::new(address.network, address.payload)),
+          amount: holder.amount,
+          outpoints: holder.outpoints,
+        })
+        .collect(),
+      prev_page,
+      next_page,
+    }
+  }
+}
+
+impl PageContent for RuneHoldersHtml {
+  fn title(&self) -> String {
+    format!("Rune {} Holders", self.entry.rune)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, crate::runes::Rune};
+
+  fn entry() -> RuneEntry {
+    RuneEntry {
+      divisibility: 1,
+      rune: Rune(0),
+      symbol: Some('$'),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn no_holders() {
+    assert_regex_match!(
+      RuneHoldersHtml {
+        entry: entry(),
+        holders: Vec::new(),
+        prev_page: None,
+        next_page: None,
+      },
+      "<h1><a href=/rune/A>Rune A</a> Holders</h1>
+<h3>No holders</h3>
+"
+    );
+  }
+
+  #[test]
+  fn with_holders() {
+    assert_regex_match!(
+      RuneHoldersHtml {
+        entry: entry(),
+        holders: vec![
+          RuneHolder {
+            address: Some(address()),
+            amount: 20,
+            outpoints: vec![outpoint(1), outpoint(2)],
+          },
+          RuneHolder {
+            address: None,
+            amount: 15,
+            outpoints: vec![outpoint(3)],
+          },
+        ],
+        prev_page: None,
+        next_page: Some(1),
+      },
+      "<h1><a href=/rune/A>Rune A</a> Holders</h1>
+<table>
+  <tr>
+    <th>address</th>
+    <th>amount</th>
+    <th>outputs</th>
+  </tr>
+  <tr>
+    <td><a class=monospace href=/address/bc1q[[:alnum:]]+>bc1q[[:alnum:]]+</a></td>
+    <td>\\$2</td>
+    <td>
+      <a class=monospace href=/output/1{64}:1>1{64}:1</a>
+      <a class=monospace href=/output/2{64}:2>2{64}:2</a>
+    </td>
+  </tr>
+  <tr>
+    <td></td>
+    <td>\\$1.5</td>
+    <td>
+      <a class=monospace href=/output/3{64}:3>3{64}:3</a>
+    </td>
+  </tr>
+</table>
+<div class=center>
+prev
+  <a class=next href=/rune/A/holders/1>next</a>
+</div>
+"
+    );
+  }
+
+  #[test]
+  fn json() {
+    assert_eq!(
+      serde_json::to_value(RuneHoldersJson::new(
+        vec![RuneHolder {
+          address: None,
+          amount: 15,
+          outpoints: vec![outpoint(1)],
+        }],
+        Some(0),
+        None
+      ))
+      .unwrap(),
+      serde_json::json!({
+        "holders": [{
+          "address": null,
+          "amount": 15,
+          "outpoints": [outpoint(1)],
+        }],
+        "prev_page": 0,
+        "next_page": null,
+      }),
+    );
+  }
+}
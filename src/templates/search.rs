@@ -0,0 +1,75 @@
+use super::*;
+
+#[derive(Boilerplate)]
+pub(crate) struct SearchHtml {
+  pub(crate) query: String,
+  pub(crate) sat: Option<Sat>,
+  pub(crate) words: Vec<String>,
+  pub(crate) inscriptions: Vec<InscriptionId>,
+  pub(crate) prev_page: Option<usize>,
+  pub(crate) next_page: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SearchJson {
+  pub ids: Vec<InscriptionId>,
+  pub more: bool,
+  pub page: usize,
+  pub query: String,
+}
+
+impl PageContent for SearchHtml {
+  fn title(&self) -> String {
+    format!("Search results for {}", self.query)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn empty() {
+    assert_regex_match!(
+      SearchHtml {
+        query: "foo bar".into(),
+        sat: None,
+        words: vec!["bar".into(), "foo".into()],
+        inscriptions: Vec::new(),
+        prev_page: None,
+        next_page: None,
+      },
+      "
+        <h1>Search results for foo bar</h1>
+        <h3>No inscriptions</h3>
+      "
+      .unindent()
+    );
+  }
+
+  #[test]
+  fn with_sat_and_prev_and_next() {
+    assert_regex_match!(
+      SearchHtml {
+        query: "nvtdijuwxlp".into(),
+        sat: Some(Sat(0)),
+        words: vec!["nvtdijuwxlp".into()],
+        inscriptions: vec![inscription_id(1)],
+        prev_page: Some(1),
+        next_page: Some(3),
+      },
+      "
+        <h1>Search results for nvtdijuwxlp</h1>
+        <p>Looking for <a href=/sat/0>sat nvtdijuwxlp</a>\\?</p>
+        <div class=thumbnails>
+          <a href=/inscription/1{64}i1><iframe .* src=/preview/1{64}i1></iframe></a>
+        </div>
+        <div class=center>
+          <a class=prev href=/search\\?query=nvtdijuwxlp&amp;page=1>prev</a>
+          <a class=next href=/search\\?query=nvtdijuwxlp&amp;page=3>next</a>
+        </div>
+      "
+      .unindent()
+    );
+  }
+}
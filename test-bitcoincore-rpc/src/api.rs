@@ -55,7 +55,7 @@ pub trait Api {
     &self,
     tx: String,
     utxos: Option<Vec<SignRawTransactionInput>>,
-    sighash_type: Option<()>,
+    sighash_type: Option<String>,
   ) -> Result<Value, jsonrpc_core::Error>;
 
   #[rpc(name = "sendrawtransaction")]
@@ -92,6 +92,14 @@ pub trait Api {
     blockhash: Option<BlockHash>,
   ) -> Result<Value, jsonrpc_core::Error>;
 
+  #[rpc(name = "gettxout")]
+  fn get_tx_out(
+    &self,
+    txid: Txid,
+    vout: u32,
+    include_mempool: Option<bool>,
+  ) -> Result<Option<GetTxOutResult>, jsonrpc_core::Error>;
+
   #[rpc(name = "listunspent")]
   fn list_unspent(
     &self,
@@ -105,6 +113,12 @@ pub trait Api {
   #[rpc(name = "listlockunspent")]
   fn list_lock_unspent(&self) -> Result<Vec<JsonOutPoint>, jsonrpc_core::Error>;
 
+  #[rpc(name = "getaddressinfo")]
+  fn get_address_info(
+    &self,
+    address: Address<NetworkUnchecked>,
+  ) -> Result<AddressInfo, jsonrpc_core::Error>;
+
   #[rpc(name = "getrawchangeaddress")]
   fn get_raw_change_address(
     &self,
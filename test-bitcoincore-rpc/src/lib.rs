@@ -19,11 +19,11 @@ use {
   bitcoincore_rpc::json::{
     Bip125Replaceable, CreateRawTransactionInput, Descriptor, EstimateMode, GetBalancesResult,
     GetBalancesResultEntry, GetBlockHeaderResult, GetBlockchainInfoResult, GetDescriptorInfoResult,
-    GetNetworkInfoResult, GetRawTransactionResult, GetTransactionResult,
-    GetTransactionResultDetail, GetTransactionResultDetailCategory, GetWalletInfoResult,
-    ImportDescriptors, ImportMultiResult, ListDescriptorsResult, ListTransactionResult,
-    ListUnspentResultEntry, LoadWalletResult, SignRawTransactionInput, SignRawTransactionResult,
-    Timestamp, WalletTxInfo,
+    GetNetworkInfoResult, GetRawTransactionResult, GetRawTransactionResultVoutScriptPubKey,
+    GetTransactionResult, GetTransactionResultDetail, GetTransactionResultDetailCategory,
+    GetTxOutResult, GetWalletInfoResult, ImportDescriptors, ImportMultiResult,
+    ListDescriptorsResult, ListTransactionResult, ListUnspentResultEntry, LoadWalletResult,
+    SignRawTransactionInput, SignRawTransactionResult, Timestamp, WalletTxInfo,
   },
   jsonrpc_core::{IoHandler, Value},
   jsonrpc_http_server::{CloseHandle, ServerBuilder},
@@ -45,14 +45,18 @@ mod state;
 pub fn builder() -> Builder {
   Builder {
     fail_lock_unspent: false,
+    min_relay_fee_rate: 0.0,
     network: Network::Bitcoin,
+    txindex: true,
     version: 240000,
   }
 }
 
 pub struct Builder {
   fail_lock_unspent: bool,
+  min_relay_fee_rate: f64,
   network: Network,
+  txindex: bool,
   version: usize,
 }
 
@@ -64,10 +68,21 @@ impl Builder {
     }
   }
 
+  pub fn min_relay_fee_rate(self, min_relay_fee_rate: f64) -> Self {
+    Self {
+      min_relay_fee_rate,
+      ..self
+    }
+  }
+
   pub fn network(self, network: Network) -> Self {
     Self { network, ..self }
   }
 
+  pub fn txindex(self, txindex: bool) -> Self {
+    Self { txindex, ..self }
+  }
+
   pub fn version(self, version: usize) -> Self {
     Self { version, ..self }
   }
@@ -77,6 +92,8 @@ impl Builder {
       self.network,
       self.version,
       self.fail_lock_unspent,
+      self.min_relay_fee_rate,
+      self.txindex,
     )));
     let server = Server::new(state.clone());
     let mut io = IoHandler::default();
@@ -125,6 +142,7 @@ pub struct TransactionTemplate<'a> {
   pub op_return_index: Option<usize>,
   pub output_values: &'a [u64],
   pub outputs: usize,
+  pub recipient: Option<Address>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -134,6 +152,11 @@ pub struct Sent {
   pub locked: Vec<OutPoint>,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct AddressInfo {
+  ismine: bool,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct JsonOutPoint {
   txid: bitcoin::Txid,
@@ -158,6 +181,7 @@ impl<'a> Default for TransactionTemplate<'a> {
       op_return_index: None,
       output_values: &[],
       outputs: 1,
+      recipient: None,
     }
   }
 }
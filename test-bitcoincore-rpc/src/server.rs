@@ -242,14 +242,18 @@ impl Api for Server {
     &self,
     tx: String,
     _utxos: Option<Vec<SignRawTransactionInput>>,
-    sighash_type: Option<()>,
+    sighash_type: Option<String>,
   ) -> Result<Value, jsonrpc_core::Error> {
-    assert_eq!(sighash_type, None, "sighash_type param not supported");
+    let signature = match sighash_type.as_deref() {
+      None => [0; 64].to_vec(),
+      Some("SINGLE|ANYONECANPAY") => [[0; 64].as_slice(), &[0x83]].concat(),
+      Some(sighash_type) => panic!("sighash_type {sighash_type} not supported"),
+    };
 
     let mut transaction: Transaction = deserialize(&hex::decode(tx).unwrap()).unwrap();
     for input in &mut transaction.input {
       if input.witness.is_empty() {
-        input.witness = Witness::from_slice(&[&[0; 64]]);
+        input.witness = Witness::from_slice(&[&signature]);
       }
     }
 
@@ -265,7 +269,48 @@ impl Api for Server {
 
   fn send_raw_transaction(&self, tx: String) -> Result<String, jsonrpc_core::Error> {
     let tx: Transaction = deserialize(&hex::decode(tx).unwrap()).unwrap();
-    self.state.lock().unwrap().mempool.push(tx.clone());
+
+    let mut state = self.state.lock().unwrap();
+
+    let input_value = tx
+      .input
+      .iter()
+      .map(|input| {
+        state
+          .transactions
+          .get(&input.previous_output.txid)
+          .or(
+            state
+              .mempool
+              .iter()
+              .find(|tx| tx.txid() == input.previous_output.txid),
+          )
+          .and_then(|tx| tx.output.get(input.previous_output.vout as usize))
+          .map(|output| output.value)
+      })
+      .sum::<Option<u64>>();
+
+    if let Some(input_value) = input_value {
+      let output_value = tx.output.iter().map(|output| output.value).sum::<u64>();
+
+      if ((input_value - output_value) as f64) < state.min_relay_fee_rate * tx.vsize() as f64 {
+        return Err(jsonrpc_core::Error {
+          code: jsonrpc_core::types::error::ErrorCode::ServerError(-26),
+          message: "min relay fee not met".into(),
+          data: None,
+        });
+      }
+    }
+
+    state.mempool.retain(|mempool_tx| {
+      !mempool_tx.input.iter().any(|mempool_input| {
+        tx.input
+          .iter()
+          .any(|input| input.previous_output == mempool_input.previous_output)
+      })
+    });
+
+    state.mempool.push(tx.clone());
 
     Ok(tx.txid().to_string())
   }
@@ -354,12 +399,18 @@ impl Api for Server {
     txid: Txid,
     _include_watchonly: Option<bool>,
   ) -> Result<Value, jsonrpc_core::Error> {
-    match self.state.lock().unwrap().transactions.get(&txid) {
+    let state = self.state();
+
+    match state
+      .transactions
+      .get(&txid)
+      .or(state.mempool.iter().find(|tx| tx.txid() == txid))
+    {
       Some(tx) => Ok(
         serde_json::to_value(GetTransactionResult {
           info: WalletTxInfo {
             txid,
-            confirmations: 0,
+            confirmations: state.get_confirmations(tx),
             time: 0,
             timereceived: 0,
             blockhash: None,
@@ -376,9 +427,7 @@ impl Api for Server {
         })
         .unwrap(),
       ),
-      None => Err(jsonrpc_core::Error::new(
-        jsonrpc_core::types::error::ErrorCode::ServerError(-8),
-      )),
+      None => Err(Self::not_found()),
     }
   }
 
@@ -390,36 +439,101 @@ impl Api for Server {
   ) -> Result<Value, jsonrpc_core::Error> {
     assert_eq!(blockhash, None, "Blockhash param is unsupported");
     if verbose.unwrap_or(false) {
-      match self.state().transactions.get(&txid) {
-        Some(_) => Ok(
-          serde_json::to_value(GetRawTransactionResult {
-            in_active_chain: Some(true),
-            hex: Vec::new(),
-            txid: Txid::all_zeros(),
-            hash: Wtxid::all_zeros(),
-            size: 0,
-            vsize: 0,
-            version: 0,
-            locktime: 0,
-            vin: Vec::new(),
-            vout: Vec::new(),
-            blockhash: None,
-            confirmations: Some(1),
-            time: None,
-            blocktime: None,
-          })
-          .unwrap(),
-        ),
-        None => Err(Self::not_found()),
-      }
+      let state = self.state();
+
+      let confirmations = if state.txindex && state.transactions.contains_key(&txid) {
+        Some(1)
+      } else if state.mempool.iter().any(|tx| tx.txid() == txid) {
+        None
+      } else {
+        return Err(Self::not_found());
+      };
+
+      Ok(
+        serde_json::to_value(GetRawTransactionResult {
+          in_active_chain: Some(true),
+          hex: Vec::new(),
+          txid: Txid::all_zeros(),
+          hash: Wtxid::all_zeros(),
+          size: 0,
+          vsize: 0,
+          version: 0,
+          locktime: 0,
+          vin: Vec::new(),
+          vout: Vec::new(),
+          blockhash: None,
+          confirmations,
+          time: None,
+          blocktime: None,
+        })
+        .unwrap(),
+      )
     } else {
-      match self.state().transactions.get(&txid) {
+      let state = self.state();
+      match state
+        .transactions
+        .get(&txid)
+        .filter(|_| state.txindex)
+        .or(state.mempool.iter().find(|tx| tx.txid() == txid))
+      {
         Some(tx) => Ok(Value::String(hex::encode(serialize(tx)))),
         None => Err(Self::not_found()),
       }
     }
   }
 
+  fn get_tx_out(
+    &self,
+    txid: Txid,
+    vout: u32,
+    include_mempool: Option<bool>,
+  ) -> Result<Option<GetTxOutResult>, jsonrpc_core::Error> {
+    let state = self.state();
+
+    let outpoint = OutPoint { txid, vout };
+
+    let include_mempool = include_mempool.unwrap_or(true);
+
+    if include_mempool
+      && state.mempool.iter().any(|tx| {
+        tx.input
+          .iter()
+          .any(|input| input.previous_output == outpoint)
+      })
+    {
+      return Ok(None);
+    }
+
+    let tx = if state.utxos.contains_key(&outpoint) {
+      state.transactions.get(&txid)
+    } else if include_mempool {
+      state.mempool.iter().find(|tx| tx.txid() == txid)
+    } else {
+      None
+    };
+
+    let Some((tx, tx_out)) =
+      tx.and_then(|tx| Some((tx, tx.output.get(usize::try_from(vout).unwrap())?)))
+    else {
+      return Ok(None);
+    };
+
+    Ok(Some(GetTxOutResult {
+      bestblock: *state.hashes.last().unwrap(),
+      confirmations: state.get_confirmations(tx).try_into().unwrap(),
+      value: Amount::from_sat(tx_out.value),
+      script_pub_key: GetRawTransactionResultVoutScriptPubKey {
+        asm: String::new(),
+        hex: tx_out.script_pubkey.to_bytes(),
+        req_sigs: None,
+        type_: None,
+        addresses: Vec::new(),
+        address: None,
+      },
+      coinbase: tx.is_coin_base(),
+    }))
+  }
+
   fn list_unspent(
     &self,
     minconf: Option<usize>,
@@ -440,7 +554,14 @@ impl Api for Server {
       state
         .utxos
         .iter()
-        .filter(|(outpoint, _amount)| !state.locked.contains(outpoint))
+        .filter(|(outpoint, _amount)| {
+          !state.locked.contains(outpoint)
+            && !state.mempool.iter().any(|tx| {
+              tx.input
+                .iter()
+                .any(|input| input.previous_output == **outpoint)
+            })
+        })
         .map(|(outpoint, &amount)| ListUnspentResultEntry {
           txid: outpoint.txid,
           vout: outpoint.vout,
@@ -471,6 +592,18 @@ impl Api for Server {
     )
   }
 
+  fn get_address_info(
+    &self,
+    address: Address<NetworkUnchecked>,
+  ) -> Result<AddressInfo, jsonrpc_core::Error> {
+    Ok(AddressInfo {
+      ismine: self
+        .state()
+        .wallet_script_pubkeys
+        .contains(&address.assume_checked().script_pubkey()),
+    })
+  }
+
   fn get_raw_change_address(
     &self,
     _address_type: Option<bitcoincore_rpc::json::AddressType>,
@@ -480,6 +613,11 @@ impl Api for Server {
     let (public_key, _parity) = XOnlyPublicKey::from_keypair(&key_pair);
     let address = Address::p2tr(&secp256k1, public_key, None, self.network);
 
+    self
+      .state()
+      .wallet_script_pubkeys
+      .insert(address.script_pubkey());
+
     Ok(address)
   }
 
@@ -522,6 +660,11 @@ impl Api for Server {
     let (public_key, _parity) = XOnlyPublicKey::from_keypair(&key_pair);
     let address = Address::p2tr(&secp256k1, public_key, None, self.network);
 
+    self
+      .state()
+      .wallet_script_pubkeys
+      .insert(address.script_pubkey());
+
     Ok(address)
   }
 
@@ -574,8 +717,6 @@ impl Api for Server {
     unlock: bool,
     outputs: Vec<JsonOutPoint>,
   ) -> Result<bool, jsonrpc_core::Error> {
-    assert!(!unlock);
-
     let mut state = self.state();
 
     if state.fail_lock_unspent {
@@ -587,8 +728,12 @@ impl Api for Server {
         vout: output.vout,
         txid: output.txid,
       };
-      assert!(state.utxos.contains_key(&output));
-      state.locked.insert(output);
+      if unlock {
+        assert!(state.locked.remove(&output));
+      } else {
+        assert!(state.utxos.contains_key(&output));
+        state.locked.insert(output);
+      }
     }
 
     Ok(true)
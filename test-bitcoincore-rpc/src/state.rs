@@ -8,17 +8,26 @@ pub(crate) struct State {
   pub(crate) loaded_wallets: BTreeSet<String>,
   pub(crate) locked: BTreeSet<OutPoint>,
   pub(crate) mempool: Vec<Transaction>,
+  pub(crate) min_relay_fee_rate: f64,
   pub(crate) network: Network,
   pub(crate) nonce: u32,
   pub(crate) sent: Vec<Sent>,
   pub(crate) transactions: BTreeMap<Txid, Transaction>,
+  pub(crate) txindex: bool,
   pub(crate) utxos: BTreeMap<OutPoint, Amount>,
   pub(crate) version: usize,
+  pub(crate) wallet_script_pubkeys: BTreeSet<ScriptBuf>,
   pub(crate) wallets: BTreeSet<String>,
 }
 
 impl State {
-  pub(crate) fn new(network: Network, version: usize, fail_lock_unspent: bool) -> Self {
+  pub(crate) fn new(
+    network: Network,
+    version: usize,
+    fail_lock_unspent: bool,
+    min_relay_fee_rate: f64,
+    txindex: bool,
+  ) -> Self {
     let mut hashes = Vec::new();
     let mut blocks = BTreeMap::new();
 
@@ -34,12 +43,15 @@ impl State {
       hashes,
       locked: BTreeSet::new(),
       mempool: Vec::new(),
+      min_relay_fee_rate,
       network,
       nonce: 0,
       sent: Vec::new(),
       transactions: BTreeMap::new(),
+      txindex,
       utxos: BTreeMap::new(),
       version,
+      wallet_script_pubkeys: BTreeSet::new(),
       wallets: BTreeSet::new(),
       loaded_wallets: BTreeSet::new(),
     }
@@ -160,7 +172,10 @@ impl State {
             .get(i)
             .cloned()
             .unwrap_or(value_per_output),
-          script_pubkey: script::Builder::new().into_script(),
+          script_pubkey: match &template.recipient {
+            Some(recipient) => recipient.script_pubkey(),
+            None => script::Builder::new().into_script(),
+          },
         })
         .collect(),
     };
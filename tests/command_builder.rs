@@ -35,7 +35,7 @@ pub(crate) struct CommandBuilder {
   expected_stdout: Expected,
   rpc_server_url: Option<String>,
   stdin: Vec<u8>,
-  tempdir: TempDir,
+  tempdir: Arc<TempDir>,
 }
 
 impl CommandBuilder {
@@ -47,7 +47,7 @@ impl CommandBuilder {
       expected_stdout: Expected::String(String::new()),
       rpc_server_url: None,
       stdin: Vec::new(),
-      tempdir: TempDir::new().unwrap(),
+      tempdir: Arc::new(TempDir::new().unwrap()),
     }
   }
 
@@ -95,7 +95,7 @@ impl CommandBuilder {
     }
   }
 
-  pub(crate) fn temp_dir(self, tempdir: TempDir) -> Self {
+  pub(crate) fn temp_dir(self, tempdir: Arc<TempDir>) -> Self {
     Self { tempdir, ..self }
   }
 
@@ -118,7 +118,7 @@ impl CommandBuilder {
       .stdin(Stdio::piped())
       .stdout(Stdio::piped())
       .stderr(Stdio::piped())
-      .current_dir(&self.tempdir)
+      .current_dir(self.tempdir.path())
       .arg("--data-dir")
       .arg(self.tempdir.path())
       .args(&self.args);
@@ -126,7 +126,7 @@ impl CommandBuilder {
     command
   }
 
-  fn run(self) -> (TempDir, String) {
+  fn run(self) -> (Arc<TempDir>, String) {
     let child = self.command().spawn().unwrap();
 
     child
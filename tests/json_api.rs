@@ -148,6 +148,7 @@ fn get_inscription() {
     inscription_json,
     InscriptionJson {
       parent: None,
+      charms: Vec::new(),
       children: Vec::new(),
       inscription_id,
       inscription_number: 0,
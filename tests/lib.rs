@@ -5,6 +5,7 @@ use {
   bitcoin::{
     address::{Address, NetworkUnchecked},
     blockdata::constants::COIN_VALUE,
+    psbt::Psbt,
     Network, OutPoint, Txid,
   },
   executable_path::executable_path,
@@ -29,6 +30,7 @@ use {
     path::Path,
     process::{Child, Command, Stdio},
     str::{self, FromStr},
+    sync::Arc,
     thread,
     time::Duration,
   },
@@ -85,6 +87,42 @@ fn envelope(payload: &[&[u8]]) -> bitcoin::Witness {
   bitcoin::Witness::from_slice(&[script.into_bytes(), Vec::new()])
 }
 
+const RUNE: u128 = 99246114928149462;
+
+fn etch(
+  rpc_server: &test_bitcoincore_rpc::Handle,
+  rune: u128,
+  divisibility: u8,
+  supply: u128,
+) -> Txid {
+  let mut payload = Vec::new();
+
+  for integer in [2, rune, 1, divisibility.into(), 0, 0, supply, 0] {
+    ord::runes::varint::encode_to_vec(integer, &mut payload);
+  }
+
+  let mut push = bitcoin::script::PushBytesBuf::new();
+  push.extend_from_slice(&payload).unwrap();
+
+  rpc_server.mine_blocks(1);
+
+  let txid = rpc_server.broadcast_tx(TransactionTemplate {
+    inputs: &[(1, 0, 0, Default::default())],
+    op_return: Some(
+      bitcoin::script::Builder::new()
+        .push_opcode(bitcoin::opcodes::all::OP_RETURN)
+        .push_slice(b"RUNE_TEST")
+        .push_slice(push)
+        .into_script(),
+    ),
+    ..Default::default()
+  });
+
+  rpc_server.mine_blocks(1);
+
+  txid
+}
+
 fn create_wallet(rpc_server: &test_bitcoincore_rpc::Handle) {
   CommandBuilder::new(format!("--chain {} wallet create", rpc_server.network()))
     .rpc_server(rpc_server)
@@ -104,6 +142,7 @@ mod info;
 mod json_api;
 mod list;
 mod parse;
+mod provenance;
 mod server;
 mod subsidy;
 mod supply;
@@ -0,0 +1,94 @@
+use {
+  super::*,
+  ord::subcommand::provenance::{Descendant, Output},
+};
+
+#[test]
+fn provenance_walks_ancestors_and_descendants() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  let (parent, _) = inscribe(&rpc_server);
+
+  let child = CommandBuilder::new(format!(
+    "wallet inscribe --fee-rate 1 --parent {parent} --file child.txt"
+  ))
+  .write("child.txt", "CHILD")
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<Inscribe>()
+  .inscriptions[0]
+    .id;
+
+  rpc_server.mine_blocks(1);
+
+  let grandchild = CommandBuilder::new(format!(
+    "wallet inscribe --fee-rate 1 --parent {child} --file grandchild.txt"
+  ))
+  .write("grandchild.txt", "GRANDCHILD")
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<Inscribe>()
+  .inscriptions[0]
+    .id;
+
+  rpc_server.mine_blocks(1);
+
+  pretty_assert_eq!(
+    CommandBuilder::new(format!("provenance {child}"))
+      .rpc_server(&rpc_server)
+      .run_and_deserialize_output::<Output>(),
+    Output {
+      ancestors: vec![parent],
+      ancestors_truncated: false,
+      descendants: vec![Descendant {
+        children: Vec::new(),
+        id: grandchild,
+        truncated: false,
+      }],
+      descendants_truncated: false,
+      inscription: child,
+    }
+  );
+
+  pretty_assert_eq!(
+    CommandBuilder::new(format!("provenance --depth 1 {parent}"))
+      .rpc_server(&rpc_server)
+      .run_and_deserialize_output::<Output>(),
+    Output {
+      ancestors: Vec::new(),
+      ancestors_truncated: false,
+      descendants: vec![Descendant {
+        children: Vec::new(),
+        id: child,
+        truncated: true,
+      }],
+      descendants_truncated: false,
+      inscription: parent,
+    }
+  );
+
+  pretty_assert_eq!(
+    CommandBuilder::new(format!("provenance --depth 1 {grandchild}"))
+      .rpc_server(&rpc_server)
+      .run_and_deserialize_output::<Output>(),
+    Output {
+      ancestors: vec![child],
+      ancestors_truncated: true,
+      descendants: Vec::new(),
+      descendants_truncated: false,
+      inscription: grandchild,
+    }
+  );
+}
+
+#[test]
+fn provenance_of_unknown_inscription_fails() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+
+  let inscription_id = "0000000000000000000000000000000000000000000000000000000000000000i0";
+
+  CommandBuilder::new(format!("provenance {inscription_id}"))
+    .rpc_server(&rpc_server)
+    .expected_stderr(format!("error: inscription {inscription_id} not found\n"))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
@@ -73,6 +73,8 @@ fn inscription_page() {
   <dd>3 bytes</dd>
   <dt>content type</dt>
   <dd>text/plain;charset=utf-8</dd>
+  <dt>content hash</dt>
+  <dd><a class=monospace href=/content-hash/9520437ce8902eb379a7d8aaa98fc4c94eeb07b6684854868fa6f72bf34b0fd3>9520437ce8902eb379a7d8aaa98fc4c94eeb07b6684854868fa6f72bf34b0fd3</a></dd>
   <dt>timestamp</dt>
   <dd><time>1970-01-01 00:00:02 UTC</time></dd>
   <dt>genesis height</dt>
@@ -87,6 +89,8 @@ fn inscription_page() {
   <dd><a class=monospace href=/output/{reveal}:0>{reveal}:0</a></dd>
   <dt>offset</dt>
   <dd>0</dd>
+  <dt>history</dt>
+  <dd><a href=/inscription/{inscription}/history>link</a></dd>
   <dt>ethereum teleburn address</dt>
   <dd>{ethereum_teleburn_address}</dd>
 </dl>.*",
@@ -1,13 +1,23 @@
 use super::*;
 
+mod abandon;
 mod balance;
+mod bump;
 mod cardinals;
 mod create;
+mod etch;
+mod finalize_psbt;
+mod freeze;
 mod inscribe;
 mod inscriptions;
+mod label;
+mod mint;
+mod offer;
 mod outputs;
 mod receive;
 mod restore;
+mod resume;
+mod runes;
 mod sats;
 mod send;
 mod transactions;
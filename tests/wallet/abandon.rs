@@ -0,0 +1,121 @@
+use {
+  super::*,
+  bitcoin::hashes::Hash,
+  ord::subcommand::wallet::{abandon::Output, resume},
+};
+
+#[test]
+fn abandon_unknown_commit_fails() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  CommandBuilder::new(format!("wallet abandon --fee-rate 1 {}", Txid::all_zeros()))
+    .rpc_server(&rpc_server)
+    .expected_stderr(format!(
+      "error: no pending inscription with commit transaction {}\n",
+      Txid::all_zeros()
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn abandon_sweeps_commit_output() {
+  let rpc_server = test_bitcoincore_rpc::builder()
+    .min_relay_fee_rate(1.0)
+    .build();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  CommandBuilder::new("wallet inscribe --file foo.txt --commit-fee-rate 2 --fee-rate 0.5")
+    .temp_dir(tempdir.clone())
+    .write("foo.txt", "FOO")
+    .rpc_server(&rpc_server)
+    .stderr_regex("error: Failed to send reveal transaction: .*")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  let commit = rpc_server.mempool()[0].txid();
+
+  let output = CommandBuilder::new(format!("wallet abandon --fee-rate 1 {commit}"))
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Output>();
+
+  assert_eq!(output.commit, commit);
+
+  let mempool = rpc_server.mempool();
+
+  assert_eq!(mempool.len(), 2);
+
+  let sweep = &mempool[1];
+
+  assert_eq!(output.sweep, Some(sweep.txid()));
+  assert_eq!(sweep.input.len(), 1);
+  assert_eq!(sweep.input[0].previous_output.txid, commit);
+  assert_eq!(sweep.output.len(), 1);
+
+  let output = CommandBuilder::new("wallet resume")
+    .temp_dir(tempdir)
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<resume::Output>();
+
+  assert!(output.inscriptions.is_empty());
+}
+
+#[test]
+fn abandon_sweeps_confirmed_commit_output_without_txindex() {
+  let rpc_server = test_bitcoincore_rpc::builder()
+    .min_relay_fee_rate(1.0)
+    .txindex(false)
+    .build();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  CommandBuilder::new("wallet inscribe --file foo.txt --commit-fee-rate 2 --fee-rate 0.5")
+    .temp_dir(tempdir.clone())
+    .write("foo.txt", "FOO")
+    .rpc_server(&rpc_server)
+    .stderr_regex("error: Failed to send reveal transaction: .*")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  let commit = rpc_server.mempool()[0].txid();
+
+  rpc_server.mine_blocks(1);
+
+  let output = CommandBuilder::new(format!("wallet abandon --fee-rate 1 {commit}"))
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Output>();
+
+  let mempool = rpc_server.mempool();
+
+  assert_eq!(mempool.len(), 1);
+  assert_eq!(output.sweep, Some(mempool[0].txid()));
+  assert_eq!(mempool[0].input[0].previous_output.txid, commit);
+
+  CommandBuilder::new(format!("wallet resume {commit}"))
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .expected_stderr(format!(
+      "error: inscription with commit transaction {commit} has been abandoned\n"
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  rpc_server.mine_blocks(1);
+
+  CommandBuilder::new(format!("wallet resume {commit}"))
+    .temp_dir(tempdir)
+    .rpc_server(&rpc_server)
+    .expected_stderr(format!(
+      "error: no pending inscription with commit transaction {commit}\n"
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
@@ -0,0 +1,335 @@
+use {super::*, ord::subcommand::wallet::bump::Output};
+
+#[test]
+fn bump_replaces_send_transaction() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let (inscription, _) = inscribe(&rpc_server);
+
+  rpc_server.mine_blocks(1);
+
+  let send = CommandBuilder::new(format!(
+    "wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {inscription}",
+  ))
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<ord::subcommand::wallet::send::Output>();
+
+  let original = rpc_server.mempool()[0].clone();
+
+  let output = CommandBuilder::new(format!("wallet bump --fee-rate 10 {}", send.transaction))
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Output>();
+
+  assert!(!output.cpfp);
+  assert!(output.inscriptions.is_none());
+
+  let mempool = rpc_server.mempool();
+
+  assert_eq!(mempool.len(), 1);
+
+  let replacement = &mempool[0];
+
+  assert_eq!(replacement.txid(), output.transaction);
+  assert_eq!(
+    output.fee,
+    ord::FeeRate::try_from(10.0)
+      .unwrap()
+      .fee(replacement.vsize())
+      .to_sat()
+  );
+  assert_eq!(replacement.input.len(), original.input.len() + 1);
+  assert_eq!(replacement.output.len(), original.output.len() + 1);
+  assert_eq!(
+    replacement.output[..original.output.len()],
+    original.output[..]
+  );
+
+  rpc_server.mine_blocks(1);
+
+  TestServer::spawn_with_args(&rpc_server, &[]).assert_response_regex(
+    format!("/inscription/{inscription}"),
+    format!(
+      ".*<dt>location</dt>\\s*<dd class=monospace>{}:0:0</dd>.*",
+      output.transaction
+    ),
+  );
+}
+
+#[test]
+fn bump_replaces_reveal_transaction() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(2);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  let inscribe = CommandBuilder::new("wallet inscribe --file foo.txt --fee-rate 1")
+    .temp_dir(tempdir.clone())
+    .write("foo.txt", "FOO")
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<ord::subcommand::wallet::inscribe::Output>();
+
+  let output = CommandBuilder::new(format!("wallet bump --fee-rate 10 {}", inscribe.reveal))
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Output>();
+
+  assert!(!output.cpfp);
+
+  let inscriptions = output.inscriptions.unwrap();
+
+  assert_eq!(inscriptions.len(), 1);
+  assert_eq!(inscriptions[0].id.txid, output.transaction);
+  assert_eq!(inscriptions[0].id.index, 0);
+
+  let mempool = rpc_server.mempool();
+
+  assert_eq!(mempool.len(), 2);
+  assert_eq!(mempool[0].txid(), inscribe.commit);
+  assert_eq!(mempool[1].txid(), output.transaction);
+
+  rpc_server.mine_blocks(1);
+
+  let response = TestServer::spawn_with_args(&rpc_server, &[])
+    .request(format!("/content/{}", inscriptions[0].id));
+
+  assert_eq!(response.status(), 200);
+  assert_eq!(response.text().unwrap(), "FOO");
+
+  let resume = CommandBuilder::new("wallet resume")
+    .temp_dir(tempdir)
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<ord::subcommand::wallet::resume::Output>();
+
+  assert!(resume.inscriptions.is_empty());
+}
+
+#[test]
+fn bump_commit_transaction_fails() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(2);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  let inscribe = CommandBuilder::new("wallet inscribe --file foo.txt --fee-rate 1")
+    .temp_dir(tempdir.clone())
+    .write("foo.txt", "FOO")
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<ord::subcommand::wallet::inscribe::Output>();
+
+  let commit = inscribe.commit;
+
+  CommandBuilder::new(format!("wallet bump --fee-rate 10 {commit}"))
+    .temp_dir(tempdir)
+    .rpc_server(&rpc_server)
+    .expected_stderr(format!(
+      "error: transaction {commit} is the commit transaction of a pending inscription and cannot be replaced, bump it with `--cpfp` instead\n"
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  assert_eq!(rpc_server.mempool().len(), 2);
+}
+
+#[test]
+fn bump_with_cpfp_spends_wallet_output() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let (inscription, _) = inscribe(&rpc_server);
+
+  rpc_server.mine_blocks(1);
+
+  let address = CommandBuilder::new("wallet receive")
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<ord::subcommand::wallet::receive::Output>()
+    .address
+    .assume_checked();
+
+  let send = CommandBuilder::new(format!("wallet send --fee-rate 1 {address} {inscription}",))
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<ord::subcommand::wallet::send::Output>();
+
+  let output = CommandBuilder::new(format!(
+    "wallet bump --cpfp --fee-rate 10 {}",
+    send.transaction
+  ))
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<Output>();
+
+  assert!(output.cpfp);
+  assert!(output.inscriptions.is_none());
+
+  let mempool = rpc_server.mempool();
+
+  assert_eq!(mempool.len(), 2);
+  assert_eq!(mempool[0].txid(), send.transaction);
+  assert_eq!(mempool[1].txid(), output.transaction);
+
+  let child = &mempool[1];
+
+  assert!(child
+    .input
+    .iter()
+    .any(|input| input.previous_output.txid == send.transaction));
+
+  let fees = mempool[0].vsize() + child.vsize();
+
+  assert!(output.fee > 0);
+  assert!(output.fee < ord::FeeRate::try_from(10.0).unwrap().fee(fees).to_sat());
+
+  rpc_server.mine_blocks(1);
+
+  TestServer::spawn_with_args(&rpc_server, &[]).assert_response_regex(
+    format!("/inscription/{inscription}"),
+    format!(
+      ".*<dt>location</dt>\\s*<dd class=monospace>{}:0:0</dd>.*",
+      output.transaction
+    ),
+  );
+}
+
+#[test]
+fn bump_confirmed_transaction_fails() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let (inscription, _) = inscribe(&rpc_server);
+
+  rpc_server.mine_blocks(1);
+
+  let send = CommandBuilder::new(format!(
+    "wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {inscription}",
+  ))
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<ord::subcommand::wallet::send::Output>();
+
+  rpc_server.mine_blocks(1);
+
+  CommandBuilder::new(format!("wallet bump --fee-rate 10 {}", send.transaction))
+    .rpc_server(&rpc_server)
+    .expected_stderr(format!(
+      "error: transaction {} is already confirmed\n",
+      send.transaction
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn bump_at_lower_fee_rate_fails() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let (inscription, _) = inscribe(&rpc_server);
+
+  rpc_server.mine_blocks(1);
+
+  let send = CommandBuilder::new(format!(
+    "wallet send --fee-rate 10 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {inscription}",
+  ))
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<ord::subcommand::wallet::send::Output>();
+
+  CommandBuilder::new(format!("wallet bump --fee-rate 1 {}", send.transaction))
+    .rpc_server(&rpc_server)
+    .stderr_regex(format!(
+      "error: transaction {} already pays \\d+ sats, which is at least \\d+ sats at new fee rate\n",
+      send.transaction
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn bump_replaces_send_transaction_without_txindex() {
+  let rpc_server = test_bitcoincore_rpc::builder().txindex(false).build();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let (inscription, _) = inscribe(&rpc_server);
+
+  rpc_server.mine_blocks(1);
+
+  let send = CommandBuilder::new(format!(
+    "wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {inscription}",
+  ))
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<ord::subcommand::wallet::send::Output>();
+
+  let output = CommandBuilder::new(format!("wallet bump --fee-rate 10 {}", send.transaction))
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Output>();
+
+  let mempool = rpc_server.mempool();
+
+  assert_eq!(mempool.len(), 1);
+  assert_eq!(mempool[0].txid(), output.transaction);
+  assert_eq!(
+    output.fee,
+    ord::FeeRate::try_from(10.0)
+      .unwrap()
+      .fee(mempool[0].vsize())
+      .to_sat()
+  );
+}
+
+#[test]
+fn bump_does_not_spend_rune_outputs() {
+  let rpc_server = test_bitcoincore_rpc::builder()
+    .network(Network::Regtest)
+    .build();
+  create_wallet(&rpc_server);
+
+  let etch = etch(&rpc_server, RUNE, 0, 1000);
+
+  let coinbase = rpc_server.mine_blocks(1)[0].txdata[0].txid();
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  let inscribe = CommandBuilder::new(format!(
+    "--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet inscribe --fee-rate 1 --file foo.txt --inputs {coinbase}:0",
+  ))
+  .temp_dir(tempdir.clone())
+  .write("foo.txt", "FOO")
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<ord::subcommand::wallet::inscribe::Output>();
+
+  let outputs = CommandBuilder::new(
+    "--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet outputs",
+  )
+  .temp_dir(tempdir.clone())
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<Vec<ord::subcommand::wallet::outputs::Output>>();
+
+  let cardinals = outputs
+    .iter()
+    .map(|output| output.output)
+    .filter(|outpoint| outpoint.txid != etch && outpoint.txid != inscribe.reveal)
+    .map(|outpoint| outpoint.to_string())
+    .collect::<Vec<String>>();
+
+  CommandBuilder::new(format!(
+    "--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet freeze {}",
+    cardinals.join(" ")
+  ))
+  .temp_dir(tempdir.clone())
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<ord::subcommand::wallet::freeze::Output>();
+
+  CommandBuilder::new(format!(
+    "--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet bump --cpfp --fee-rate 10 {}",
+    inscribe.reveal
+  ))
+  .temp_dir(tempdir)
+  .rpc_server(&rpc_server)
+  .stderr_regex("error: wallet contains no cardinal utxo worth at least .* sats\n")
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
@@ -0,0 +1,114 @@
+use {super::*, ord::subcommand::wallet::etch::Output};
+
+#[test]
+fn etch_requires_rune_index() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  CommandBuilder::new("wallet etch --fee-rate 1 --rune AAAAAAAAAAAAA --supply 1")
+    .rpc_server(&rpc_server)
+    .expected_stderr("error: `ord wallet etch` requires index created with `--index-runes-pre-alpha-i-agree-to-get-rekt` flag\n")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn etched_supply_is_sent_to_wallet() {
+  let rpc_server = test_bitcoincore_rpc::builder()
+    .network(Network::Regtest)
+    .build();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let output = CommandBuilder::new(
+    "--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet etch --fee-rate 1 --rune AAAAAAAAAAAAA --divisibility 2 --supply 1000.5 --symbol ¢",
+  )
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<Output>();
+
+  assert_eq!(output.rune.to_string(), "AAAAAAAAAAAAA");
+  assert_eq!(output.supply, "¢1000.5");
+  assert_eq!(rpc_server.mempool()[0].txid(), output.transaction);
+
+  rpc_server.mine_blocks(1);
+
+  assert_eq!(
+    CommandBuilder::new("--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet runes")
+      .rpc_server(&rpc_server)
+      .run_and_deserialize_output::<ord::subcommand::wallet::runes::Output>()
+      .runes
+      .into_iter()
+      .map(|(rune, balance)| (rune.to_string(), balance))
+      .collect::<Vec<(String, String)>>(),
+    [("AAAAAAAAAAAAA".into(), "¢1000.5".into())],
+  );
+}
+
+#[test]
+fn etch_rejects_rune_below_minimum() {
+  let rpc_server = test_bitcoincore_rpc::builder()
+    .network(Network::Regtest)
+    .build();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  CommandBuilder::new(
+    "--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet etch --fee-rate 1 --rune AAA --supply 1",
+  )
+  .rpc_server(&rpc_server)
+  .expected_stderr("error: rune `AAA` is less than minimum for next block: `AAAAAAAAAAAAA`\n")
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn etch_rejects_rune_that_has_already_been_etched() {
+  let rpc_server = test_bitcoincore_rpc::builder()
+    .network(Network::Regtest)
+    .build();
+  create_wallet(&rpc_server);
+
+  etch(&rpc_server, RUNE, 0, 1000);
+
+  CommandBuilder::new(
+    "--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet etch --fee-rate 1 --rune AAAAAAAAAAAAA --supply 1",
+  )
+  .rpc_server(&rpc_server)
+  .expected_stderr("error: rune `AAAAAAAAAAAAA` has already been etched\n")
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn etch_rejects_supply_above_limit() {
+  let rpc_server = test_bitcoincore_rpc::builder()
+    .network(Network::Regtest)
+    .build();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  CommandBuilder::new(
+    "--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet etch --fee-rate 1 --rune AAAAAAAAAAAAA --supply 2 --limit 1",
+  )
+  .rpc_server(&rpc_server)
+  .expected_stderr("error: supply must be less than or equal to limit\n")
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn etch_rejects_excessive_precision() {
+  let rpc_server = test_bitcoincore_rpc::builder()
+    .network(Network::Regtest)
+    .build();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  CommandBuilder::new(
+    "--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet etch --fee-rate 1 --rune AAAAAAAAAAAAA --supply 1.5",
+  )
+  .rpc_server(&rpc_server)
+  .expected_stderr("error: excessive precision\n")
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
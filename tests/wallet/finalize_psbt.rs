@@ -0,0 +1,61 @@
+use {
+  super::*,
+  ord::subcommand::wallet::{finalize_psbt::Output, send},
+};
+
+fn unsigned_psbt(rpc_server: &test_bitcoincore_rpc::Handle) -> Psbt {
+  create_wallet(rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let (inscription, _) = inscribe(rpc_server);
+
+  rpc_server.mine_blocks(1);
+
+  CommandBuilder::new(format!(
+    "wallet send --fee-rate 1 --psbt bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {inscription}",
+  ))
+  .rpc_server(rpc_server)
+  .run_and_deserialize_output::<send::Output>()
+  .psbt
+  .unwrap()
+  .parse()
+  .unwrap()
+}
+
+#[test]
+fn finalized_psbt_is_broadcast() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+
+  let mut psbt = unsigned_psbt(&rpc_server);
+
+  for input in &mut psbt.inputs {
+    input.final_script_witness = Some(bitcoin::Witness::from_slice(&[[0; 64]]));
+  }
+
+  let output = CommandBuilder::new(format!("wallet finalize-psbt {psbt}"))
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Output>();
+
+  assert_eq!(rpc_server.mempool().len(), 1);
+  assert_eq!(rpc_server.mempool()[0].txid(), output.transaction);
+  assert_eq!(psbt.unsigned_tx.txid(), output.transaction);
+  assert_eq!(
+    rpc_server.mempool()[0].input[0].witness,
+    bitcoin::Witness::from_slice(&[[0; 64]])
+  );
+}
+
+#[test]
+fn unsigned_psbt_cannot_be_finalized() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+
+  let psbt = unsigned_psbt(&rpc_server);
+
+  CommandBuilder::new(format!("wallet finalize-psbt {psbt}"))
+    .rpc_server(&rpc_server)
+    .stderr_regex("error: failed to finalize PSBT: .*")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  assert!(rpc_server.mempool().is_empty());
+}
@@ -0,0 +1,142 @@
+use {super::*, ord::subcommand::wallet::freeze::Output};
+
+#[test]
+fn freeze_and_unfreeze_outputs() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  let coinbase_tx = &rpc_server.mine_blocks(1)[0].txdata[0];
+  let outpoint = OutPoint::new(coinbase_tx.txid(), 0);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  let output = CommandBuilder::new(format!("wallet freeze {outpoint}"))
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Output>();
+
+  assert_eq!(output.frozen, [outpoint]);
+
+  let outputs = CommandBuilder::new("wallet outputs")
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Vec<ord::subcommand::wallet::outputs::Output>>();
+
+  assert_eq!(outputs[0].output, outpoint);
+  assert!(outputs[0].frozen);
+
+  let output = CommandBuilder::new(format!("wallet unfreeze {outpoint}"))
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<ord::subcommand::wallet::unfreeze::Output>();
+
+  assert!(output.frozen.is_empty());
+
+  let outputs = CommandBuilder::new("wallet outputs")
+    .temp_dir(tempdir)
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Vec<ord::subcommand::wallet::outputs::Output>>();
+
+  assert!(!outputs[0].frozen);
+}
+
+#[test]
+fn freeze_output_not_in_wallet_fails() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  CommandBuilder::new(format!("wallet freeze {}", OutPoint::null()))
+    .rpc_server(&rpc_server)
+    .expected_stderr(format!(
+      "error: output {} not in wallet\n",
+      OutPoint::null()
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn unfreeze_output_that_is_not_frozen_fails() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  CommandBuilder::new(format!("wallet unfreeze {}", OutPoint::null()))
+    .rpc_server(&rpc_server)
+    .expected_stderr(format!(
+      "error: output {} is not frozen\n",
+      OutPoint::null()
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn frozen_outputs_are_not_inscribed_or_used_to_pay_fees() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  let coinbase_tx = &rpc_server.mine_blocks(1)[0].txdata[0];
+  let outpoint = OutPoint::new(coinbase_tx.txid(), 0);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  CommandBuilder::new(format!("wallet freeze {outpoint}"))
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Output>();
+
+  CommandBuilder::new("wallet inscribe --file foo.txt --fee-rate 1")
+    .temp_dir(tempdir.clone())
+    .write("foo.txt", "FOO")
+    .rpc_server(&rpc_server)
+    .expected_stderr("error: wallet contains no cardinal utxos\n")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  CommandBuilder::new(format!("wallet unfreeze {outpoint}"))
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<ord::subcommand::wallet::unfreeze::Output>();
+
+  CommandBuilder::new("wallet inscribe --file foo.txt --fee-rate 1")
+    .temp_dir(tempdir)
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<ord::subcommand::wallet::inscribe::Output>();
+}
+
+#[test]
+fn frozen_outputs_are_locked_when_sending_amounts() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  let coinbase_tx = &rpc_server.mine_blocks(1)[0].txdata[0];
+  let outpoint = OutPoint::new(coinbase_tx.txid(), 0);
+
+  rpc_server.mine_blocks(1);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  CommandBuilder::new(format!("wallet freeze {outpoint}"))
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Output>();
+
+  CommandBuilder::new("wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc")
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<ord::subcommand::wallet::send::Output>();
+
+  assert_eq!(rpc_server.sent()[0].locked, [outpoint]);
+  assert_ne!(rpc_server.mempool()[0].input[0].previous_output, outpoint);
+
+  CommandBuilder::new(format!("wallet unfreeze {outpoint}"))
+    .temp_dir(tempdir)
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<ord::subcommand::wallet::unfreeze::Output>();
+
+  let outputs = CommandBuilder::new("wallet cardinals")
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Vec<ord::subcommand::wallet::cardinals::CardinalUtxo>>();
+
+  assert!(outputs.iter().any(|cardinal| cardinal.output == outpoint));
+}
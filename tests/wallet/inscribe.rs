@@ -57,10 +57,138 @@ fn metaprotocol_appears_on_inscription_page() {
 
   ord_server.assert_response_regex(
     format!("/inscription/{}", inscribe.inscriptions[0].id),
-    r".*<dt>metaprotocol</dt>\s*<dd>foo</dd>.*",
+    r#".*<dt>metaprotocol</dt>\s*<dd><a href="/metaprotocol/foo">foo</a></dd>.*"#,
   );
 }
 
+#[test]
+fn compressed_inscription_is_served_with_content_encoding() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let inscribe = CommandBuilder::new("wallet inscribe --file foo.txt --compress --fee-rate 1")
+    .write("foo.txt", "foo".repeat(1000))
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Inscribe>();
+
+  rpc_server.mine_blocks(1);
+
+  let ord_server = TestServer::spawn_with_args(&rpc_server, &[]);
+
+  let response = reqwest::blocking::Client::new()
+    .get(
+      ord_server
+        .url()
+        .join(&format!("/content/{}", inscribe.inscriptions[0].id))
+        .unwrap(),
+    )
+    .header(reqwest::header::ACCEPT_ENCODING, "br")
+    .send()
+    .unwrap();
+
+  assert_eq!(response.status(), StatusCode::OK);
+  assert_eq!(response.headers()["content-encoding"], "br");
+  assert!(response.bytes().unwrap().len() < 3000);
+
+  let response = ord_server.request(format!("/content/{}", inscribe.inscriptions[0].id));
+
+  assert_eq!(response.status(), StatusCode::OK);
+  assert!(response.headers().get("content-encoding").is_none());
+  assert_eq!(response.text().unwrap(), "foo".repeat(1000));
+}
+
+#[test]
+fn inscription_with_delegate_serves_delegate_content() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  let (delegate, _) = inscribe(&rpc_server);
+
+  let inscribe = CommandBuilder::new(format!(
+    "wallet inscribe --fee-rate 1 --delegate {delegate}"
+  ))
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<Inscribe>();
+
+  rpc_server.mine_blocks(1);
+
+  let ord_server = TestServer::spawn_with_args(&rpc_server, &[]);
+
+  let response = ord_server.request(format!("/content/{}", inscribe.inscriptions[0].id));
+
+  assert_eq!(response.status(), StatusCode::OK);
+  assert_eq!(
+    response.headers()["content-type"],
+    "text/plain;charset=utf-8"
+  );
+  assert_eq!(response.text().unwrap(), "FOO");
+}
+
+#[test]
+fn inscribe_with_non_existent_delegate_fails() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let delegate = "0000000000000000000000000000000000000000000000000000000000000000i0";
+
+  CommandBuilder::new(format!(
+    "wallet inscribe --fee-rate 1 --delegate {delegate}"
+  ))
+  .rpc_server(&rpc_server)
+  .expected_stderr(format!("error: delegate {delegate} does not exist\n"))
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn inscribe_with_check_duplicate_fails_if_content_was_already_inscribed() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  let (original, _) = inscribe(&rpc_server);
+
+  CommandBuilder::new("wallet inscribe --fee-rate 1 --file foo.txt --check-duplicate")
+    .write("foo.txt", "FOO")
+    .rpc_server(&rpc_server)
+    .expected_stderr(format!(
+      "error: content with hash 9520437ce8902eb379a7d8aaa98fc4c94eeb07b6684854868fa6f72bf34b0fd3 has already been inscribed as {original}\n"
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  CommandBuilder::new("wallet inscribe --fee-rate 1 --file bar.txt --check-duplicate")
+    .write("bar.txt", "BAR")
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Inscribe>();
+}
+
+#[test]
+fn batch_inscribe_with_delegate() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  let (delegate, _) = inscribe(&rpc_server);
+
+  let output = CommandBuilder::new("wallet inscribe --fee-rate 1 --batch batch.yaml")
+    .write(
+      "batch.yaml",
+      format!("mode: separate-outputs\ninscriptions:\n- delegate: {delegate}\n"),
+    )
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Inscribe>();
+
+  rpc_server.mine_blocks(1);
+
+  let ord_server = TestServer::spawn_with_args(&rpc_server, &[]);
+
+  let response = ord_server.request(format!("/content/{}", output.inscriptions[0].id));
+
+  assert_eq!(response.status(), StatusCode::OK);
+  assert_eq!(response.text().unwrap(), "FOO");
+}
+
 #[test]
 fn inscribe_fails_if_bitcoin_core_is_too_old() {
   let rpc_server = test_bitcoincore_rpc::builder().version(230000).build();
@@ -815,7 +943,7 @@ fn batch_inscribe_can_create_one_inscription() {
 
   ord_server.assert_response_regex(
     format!("/inscription/{}", output.inscriptions[0].id),
-    r".*<dt>metadata</dt>\s*<dd>\n    123\n  </dd>.*<dt>metaprotocol</dt>\s*<dd>foo</dd>.*",
+    r#".*<dt>metadata</dt>\s*<dd>\n    123\n  </dd>.*<dt>metaprotocol</dt>\s*<dd><a href="/metaprotocol/foo">foo</a></dd>.*"#,
   );
 }
 
@@ -1242,3 +1370,451 @@ fn inscribe_does_not_pick_locked_utxos() {
     .stderr_regex("error: wallet contains no cardinal utxos\n")
     .run_and_extract_stdout();
 }
+
+#[test]
+fn batch_in_separate_outputs_with_per_entry_destination_and_postage() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  rpc_server.mine_blocks(1);
+
+  create_wallet(&rpc_server);
+
+  let destination = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"
+    .parse::<Address<NetworkUnchecked>>()
+    .unwrap()
+    .assume_checked();
+
+  let output = CommandBuilder::new("wallet inscribe --fee-rate 1 --batch batch.yaml")
+    .write("inscription.txt", "Hello World")
+    .write("tulip.png", [0; 555])
+    .write(
+      "batch.yaml",
+      format!("mode: separate-outputs\ninscriptions:\n- file: inscription.txt\n  destination: {destination}\n  postage: 777\n- file: tulip.png\n"),
+    )
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Inscribe>();
+
+  let reveal_tx = &rpc_server.mempool()[1];
+
+  assert_eq!(reveal_tx.txid(), output.reveal);
+  assert_eq!(reveal_tx.output.len(), 2);
+  assert_eq!(reveal_tx.output[0].value, 777);
+  assert_eq!(
+    reveal_tx.output[0].script_pubkey,
+    destination.script_pubkey()
+  );
+  assert_eq!(reveal_tx.output[1].value, 10_000);
+  assert_ne!(
+    reveal_tx.output[1].script_pubkey,
+    destination.script_pubkey()
+  );
+
+  assert_eq!(
+    output.inscriptions[1].location,
+    SatPoint {
+      outpoint: OutPoint {
+        txid: output.reveal,
+        vout: 1,
+      },
+      offset: 0,
+    }
+  );
+}
+
+#[test]
+fn batch_with_dust_postage_fails() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  rpc_server.mine_blocks(1);
+
+  create_wallet(&rpc_server);
+
+  CommandBuilder::new("wallet inscribe --fee-rate 1 --batch batch.yaml")
+    .write("inscription.txt", "Hello World")
+    .write("tulip.png", [0; 555])
+    .write(
+      "batch.yaml",
+      "mode: separate-outputs\ninscriptions:\n- file: inscription.txt\n- file: tulip.png\n  postage: 1\n",
+    )
+    .rpc_server(&rpc_server)
+    .expected_stderr("error: reveal output 1 would be dust\n")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn batch_in_shared_output_cannot_have_per_entry_destination() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  rpc_server.mine_blocks(1);
+
+  create_wallet(&rpc_server);
+
+  CommandBuilder::new("wallet inscribe --fee-rate 1 --batch batch.yaml")
+    .write("inscription.txt", "Hello World")
+    .write(
+      "batch.yaml",
+      "mode: shared-output\ninscriptions:\n- file: inscription.txt\n  destination: bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4\n",
+    )
+    .rpc_server(&rpc_server)
+    .expected_stderr(
+      "error: `destination` cannot be set for individual inscriptions in shared-output mode\n",
+    )
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn batch_in_same_sat() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  rpc_server.mine_blocks(1);
+
+  create_wallet(&rpc_server);
+
+  let output = CommandBuilder::new("wallet inscribe --fee-rate 1 --batch batch.yaml")
+    .write("inscription.txt", "Hello World")
+    .write("tulip.png", [0; 555])
+    .write("meow.wav", [0; 2048])
+    .write(
+      "batch.yaml",
+      "mode: same-sat\ninscriptions:\n- file: inscription.txt\n- file: tulip.png\n- file: meow.wav\n",
+    )
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Inscribe>();
+
+  let reveal_tx = &rpc_server.mempool()[1];
+
+  assert_eq!(reveal_tx.output.len(), 1);
+  assert_eq!(reveal_tx.output[0].value, 10_000);
+
+  let location = SatPoint {
+    outpoint: OutPoint {
+      txid: output.reveal,
+      vout: 0,
+    },
+    offset: 0,
+  };
+
+  for inscription in &output.inscriptions {
+    assert_eq!(inscription.location, location);
+  }
+
+  rpc_server.mine_blocks(1);
+
+  let ord_server = TestServer::spawn_with_args(&rpc_server, &[]);
+
+  for inscription in &output.inscriptions {
+    ord_server.assert_response_regex(
+      format!("/inscription/{}", inscription.id),
+      format!(".*<dt>location</dt>\\s*<dd class=monospace>{location}</dd>.*"),
+    );
+  }
+}
+
+#[test]
+fn batch_in_satpoints_mode_inscribes_specific_sats() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+
+  create_wallet(&rpc_server);
+
+  let coinbase = rpc_server.mine_blocks(1)[0].txdata[0].txid();
+  rpc_server.mine_blocks(2);
+
+  let satpoint = SatPoint {
+    outpoint: OutPoint {
+      txid: coinbase,
+      vout: 0,
+    },
+    offset: 100,
+  };
+
+  let sat = 100 * COIN_VALUE;
+
+  let output = CommandBuilder::new("--index-sats wallet inscribe --fee-rate 1 --batch batch.yaml")
+    .write("inscription.txt", "Hello World")
+    .write("tulip.png", [0; 555])
+    .write(
+      "batch.yaml",
+      format!("mode: satpoints\ninscriptions:\n- file: inscription.txt\n  satpoint: {satpoint}\n- file: tulip.png\n  sat: {sat}\n"),
+    )
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Inscribe>();
+
+  assert_eq!(output.inscriptions[0].location.offset, 100);
+  assert_eq!(output.inscriptions[1].location.offset, 0);
+
+  rpc_server.mine_blocks(1);
+
+  let ord_server = TestServer::spawn_with_args(&rpc_server, &["--index-sats"]);
+
+  ord_server.assert_response_regex(
+    format!("/inscription/{}", output.inscriptions[0].id),
+    format!(
+      ".*<dt>sat</dt>\\s*<dd><a href=/sat/{}>{}</a></dd>.*",
+      50 * COIN_VALUE + 100,
+      50 * COIN_VALUE + 100,
+    ),
+  );
+
+  ord_server.assert_response_regex(
+    format!("/inscription/{}", output.inscriptions[1].id),
+    format!(".*<dt>sat</dt>\\s*<dd><a href=/sat/{sat}>{sat}</a></dd>.*"),
+  );
+}
+
+#[test]
+fn batch_in_satpoints_mode_with_per_entry_destination() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+
+  create_wallet(&rpc_server);
+
+  let coinbase = rpc_server.mine_blocks(1)[0].txdata[0].txid();
+  rpc_server.mine_blocks(2);
+
+  let destination = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"
+    .parse::<Address<NetworkUnchecked>>()
+    .unwrap()
+    .assume_checked();
+
+  let satpoint = SatPoint {
+    outpoint: OutPoint {
+      txid: coinbase,
+      vout: 0,
+    },
+    offset: 100,
+  };
+
+  let sat = 100 * COIN_VALUE;
+
+  let output = CommandBuilder::new("--index-sats wallet inscribe --fee-rate 1 --batch batch.yaml")
+    .write("inscription.txt", "Hello World")
+    .write("tulip.png", [0; 555])
+    .write(
+      "batch.yaml",
+      format!("mode: satpoints\ninscriptions:\n- file: inscription.txt\n  satpoint: {satpoint}\n  destination: {destination}\n- file: tulip.png\n  sat: {sat}\n"),
+    )
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Inscribe>();
+
+  let reveal_tx = &rpc_server.mempool()[1];
+
+  assert_eq!(reveal_tx.txid(), output.reveal);
+  assert_eq!(reveal_tx.output.len(), 2);
+  assert_eq!(
+    reveal_tx.output[0].script_pubkey,
+    destination.script_pubkey()
+  );
+  assert_ne!(
+    reveal_tx.output[1].script_pubkey,
+    destination.script_pubkey()
+  );
+
+  assert_eq!(output.inscriptions[0].location.outpoint.vout, 0);
+  assert_eq!(output.inscriptions[0].location.offset, 100);
+  assert_eq!(output.inscriptions[1].location.outpoint.vout, 1);
+
+  rpc_server.mine_blocks(1);
+
+  let ord_server = TestServer::spawn_with_args(&rpc_server, &["--index-sats"]);
+
+  ord_server.assert_response_regex(
+    format!("/inscription/{}", output.inscriptions[0].id),
+    format!(
+      ".*<dt>address</dt>\\s*<dd class=monospace>{destination}</dd>.*<dt>sat</dt>\\s*<dd><a href=/sat/{}>{}</a></dd>.*",
+      50 * COIN_VALUE + 100,
+      50 * COIN_VALUE + 100,
+    ),
+  );
+}
+
+#[test]
+fn batch_in_satpoints_mode_requires_satpoint_for_each_inscription() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  rpc_server.mine_blocks(1);
+
+  create_wallet(&rpc_server);
+
+  CommandBuilder::new("wallet inscribe --fee-rate 1 --batch batch.yaml")
+    .write("inscription.txt", "Hello World")
+    .write(
+      "batch.yaml",
+      "mode: satpoints\ninscriptions:\n- file: inscription.txt\n",
+    )
+    .rpc_server(&rpc_server)
+    .expected_stderr("error: each inscription must set `sat` or `satpoint` in satpoints mode\n")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn inscribe_sat_by_notation() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(2);
+
+  for (notation, sat) in [
+    ("1.1000", 50 * COIN_VALUE + 1000),
+    ("2.0", 100 * COIN_VALUE),
+  ] {
+    let output = CommandBuilder::new(format!(
+      "--index-sats wallet inscribe --fee-rate 1 --file foo.txt --sat {notation}"
+    ))
+    .write("foo.txt", "FOO")
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Inscribe>();
+
+    rpc_server.mine_blocks(1);
+
+    TestServer::spawn_with_args(&rpc_server, &["--index-sats"]).assert_response_regex(
+      format!("/inscription/{}", output.inscriptions[0].id),
+      format!(".*<dt>sat</dt>\\s*<dd><a href=/sat/{sat}>{sat}</a></dd>.*"),
+    );
+  }
+}
+
+#[test]
+fn inscribe_sat_requires_sat_index() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  CommandBuilder::new("wallet inscribe --fee-rate 1 --file foo.txt --sat 1.0")
+    .write("foo.txt", "FOO")
+    .rpc_server(&rpc_server)
+    .expected_stderr(
+      "error: inscribing on a specific sat requires index created with `--index-sats` flag\n",
+    )
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn inscribe_sat_not_in_wallet() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  CommandBuilder::new("--index-sats wallet inscribe --fee-rate 1 --file foo.txt --sat 0")
+    .write("foo.txt", "FOO")
+    .rpc_server(&rpc_server)
+    .expected_stderr("error: could not find sat 0 in wallet outputs\n")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn inscribe_with_inputs_only_uses_given_cardinals() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  let inputs = rpc_server
+    .mine_blocks(2)
+    .iter()
+    .map(|block| OutPoint::new(block.txdata[0].txid(), 0))
+    .collect::<Vec<OutPoint>>();
+
+  for input in inputs {
+    let output = CommandBuilder::new(format!(
+      "wallet inscribe --fee-rate 1 --file foo.txt --inputs {input}"
+    ))
+    .write("foo.txt", "FOO")
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Inscribe>();
+
+    let commit = rpc_server
+      .mempool()
+      .into_iter()
+      .find(|tx| tx.txid() == output.commit)
+      .unwrap();
+
+    assert_eq!(commit.input.len(), 1);
+    assert_eq!(commit.input[0].previous_output, input);
+  }
+}
+
+#[test]
+fn inscribe_with_psbt_outputs_unsigned_commit_and_resumes_reveal() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  let output = CommandBuilder::new("wallet inscribe --psbt --file foo.txt --fee-rate 1")
+    .temp_dir(tempdir.clone())
+    .write("foo.txt", "FOO")
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Inscribe>();
+
+  assert!(rpc_server.mempool().is_empty());
+
+  let mut psbt = output.psbt.unwrap().parse::<bitcoin::psbt::Psbt>().unwrap();
+
+  assert_eq!(psbt.unsigned_tx.txid(), output.commit);
+  assert!(psbt.inputs.iter().all(|input| input.witness_utxo.is_some()));
+
+  let resume = CommandBuilder::new("wallet resume")
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<ord::subcommand::wallet::resume::Output>();
+
+  assert!(resume.inscriptions.is_empty());
+
+  CommandBuilder::new(format!("wallet resume {}", output.commit))
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .expected_stderr(format!(
+      "error: commit transaction {} is unsigned and has not been broadcast\n",
+      output.commit
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  for input in &mut psbt.inputs {
+    input.final_script_witness = Some(bitcoin::Witness::from_slice(&[[0; 64]]));
+  }
+
+  CommandBuilder::new(format!("wallet finalize-psbt {psbt}"))
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<ord::subcommand::wallet::finalize_psbt::Output>();
+
+  let resume = CommandBuilder::new("wallet resume")
+    .temp_dir(tempdir)
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<ord::subcommand::wallet::resume::Output>();
+
+  assert_eq!(resume.inscriptions.len(), 1);
+  assert_eq!(resume.inscriptions[0].reveal, output.reveal);
+
+  let mempool = rpc_server.mempool();
+
+  assert_eq!(mempool.len(), 2);
+  assert_eq!(mempool[0].txid(), output.commit);
+  assert_eq!(mempool[1].txid(), output.reveal);
+
+  rpc_server.mine_blocks(1);
+
+  let response = TestServer::spawn_with_args(&rpc_server, &[])
+    .request(format!("/content/{}", output.inscriptions[0].id));
+
+  assert_eq!(response.status(), 200);
+  assert_eq!(response.text().unwrap(), "FOO");
+}
+
+#[test]
+fn inscribe_with_psbt_and_parent_fails() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let (parent, _) = inscribe(&rpc_server);
+
+  CommandBuilder::new(format!(
+    "wallet inscribe --psbt --parent {parent} --file foo.txt --fee-rate 1"
+  ))
+  .write("foo.txt", "FOO")
+  .rpc_server(&rpc_server)
+  .expected_stderr(
+    "error: --psbt is not supported when inscribing with a parent or in satpoints mode\n",
+  )
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
@@ -0,0 +1,54 @@
+use {super::*, ord::subcommand::wallet::label::Output};
+
+#[test]
+fn label_output() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  let coinbase_tx = &rpc_server.mine_blocks(1)[0].txdata[0];
+  let outpoint = OutPoint::new(coinbase_tx.txid(), 0);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  let output = CommandBuilder::new(format!("wallet label {outpoint} uncommon"))
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Output>();
+
+  assert_eq!(output.output, outpoint);
+  assert_eq!(output.label, Some("uncommon".into()));
+
+  let outputs = CommandBuilder::new("wallet outputs")
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Vec<ord::subcommand::wallet::outputs::Output>>();
+
+  assert_eq!(outputs[0].label, Some("uncommon".into()));
+
+  CommandBuilder::new(format!("wallet label {outpoint}"))
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Output>();
+
+  let outputs = CommandBuilder::new("wallet outputs")
+    .temp_dir(tempdir)
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Vec<ord::subcommand::wallet::outputs::Output>>();
+
+  assert_eq!(outputs[0].label, None);
+}
+
+#[test]
+fn label_output_not_in_wallet_fails() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  CommandBuilder::new(format!("wallet label {} foo", OutPoint::null()))
+    .rpc_server(&rpc_server)
+    .expected_stderr(format!(
+      "error: output {} not in wallet\n",
+      OutPoint::null()
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
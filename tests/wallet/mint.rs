@@ -0,0 +1,82 @@
+use {super::*, ord::subcommand::wallet::mint::Output};
+
+fn runes(rpc_server: &test_bitcoincore_rpc::Handle) -> Vec<(String, String)> {
+  CommandBuilder::new("--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet runes")
+    .rpc_server(rpc_server)
+    .run_and_deserialize_output::<ord::subcommand::wallet::runes::Output>()
+    .runes
+    .into_iter()
+    .map(|(rune, balance)| (rune.to_string(), balance))
+    .collect()
+}
+
+#[test]
+fn mint_requires_rune_index() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  CommandBuilder::new("wallet mint --fee-rate 1 AAAAAAAAAAAAA")
+    .rpc_server(&rpc_server)
+    .expected_stderr("error: `ord wallet mint` requires index created with `--index-runes-pre-alpha-i-agree-to-get-rekt` flag\n")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn runes_can_be_minted_until_term_ends() {
+  let rpc_server = test_bitcoincore_rpc::builder()
+    .network(Network::Regtest)
+    .build();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  CommandBuilder::new(
+    "--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet etch --fee-rate 1 --rune AAAAAAAAAAAAA --divisibility 1 --limit 2.5 --supply 0 --term 2",
+  )
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<ord::subcommand::wallet::etch::Output>();
+
+  rpc_server.mine_blocks(1);
+
+  assert_eq!(runes(&rpc_server), []);
+
+  let output = CommandBuilder::new(
+    "--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet mint --fee-rate 1 AAAAAAAAAAAAA",
+  )
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<Output>();
+
+  assert_eq!(output.rune.to_string(), "AAAAAAAAAAAAA");
+  assert_eq!(output.amount, "2.5");
+  assert_eq!(rpc_server.mempool()[0].txid(), output.transaction);
+
+  rpc_server.mine_blocks(1);
+
+  assert_eq!(runes(&rpc_server), [("AAAAAAAAAAAAA".into(), "2.5".into())]);
+
+  CommandBuilder::new(
+    "--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet mint --fee-rate 1 AAAAAAAAAAAAA",
+  )
+  .rpc_server(&rpc_server)
+  .expected_stderr("error: rune `AAAAAAAAAAAAA` mint term ended at block 4\n")
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn mint_fails_for_closed_rune() {
+  let rpc_server = test_bitcoincore_rpc::builder()
+    .network(Network::Regtest)
+    .build();
+  create_wallet(&rpc_server);
+
+  etch(&rpc_server, RUNE, 0, 1000);
+
+  CommandBuilder::new(
+    "--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet mint --fee-rate 1 AAAAAAAAAAAAA",
+  )
+  .rpc_server(&rpc_server)
+  .expected_stderr("error: rune `AAAAAAAAAAAAA` is not mintable\n")
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
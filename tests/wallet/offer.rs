@@ -0,0 +1,172 @@
+use {
+  super::*,
+  bitcoin::psbt::Psbt,
+  ord::subcommand::wallet::offer::{accept, create},
+};
+
+fn create_offer(rpc_server: &test_bitcoincore_rpc::Handle) -> (InscriptionId, Txid, Psbt) {
+  create_wallet(rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let (inscription, reveal) = inscribe(rpc_server);
+
+  let output = CommandBuilder::new(format!("wallet offer create {inscription} 1btc"))
+    .rpc_server(rpc_server)
+    .run_and_deserialize_output::<create::Output>();
+
+  assert_eq!(output.inscription, inscription);
+  assert_eq!(output.price, COIN_VALUE);
+
+  (inscription, reveal, output.psbt.parse().unwrap())
+}
+
+#[test]
+fn offer_can_be_created_and_accepted() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+
+  let (inscription, reveal, psbt) = create_offer(&rpc_server);
+
+  assert_eq!(psbt.unsigned_tx.input.len(), 1);
+  assert_eq!(
+    psbt.unsigned_tx.input[0].previous_output,
+    OutPoint {
+      txid: reveal,
+      vout: 0
+    }
+  );
+  assert_eq!(psbt.unsigned_tx.output.len(), 1);
+  assert_eq!(psbt.unsigned_tx.output[0].value, COIN_VALUE);
+  assert_eq!(
+    psbt.inputs[0]
+      .final_script_witness
+      .as_ref()
+      .unwrap()
+      .nth(0)
+      .unwrap()
+      .last(),
+    Some(&0x83)
+  );
+
+  let output = CommandBuilder::new(format!(
+    "wallet offer accept --fee-rate 1 --max-price 1btc {psbt}"
+  ))
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<accept::Output>();
+
+  assert_eq!(output.inscriptions, [inscription]);
+  assert_eq!(output.price, COIN_VALUE);
+
+  let transaction = rpc_server.mempool()[0].clone();
+
+  assert_eq!(transaction.txid(), output.transaction);
+  assert_eq!(
+    transaction.input[1].previous_output,
+    psbt.unsigned_tx.input[0].previous_output
+  );
+  assert_eq!(
+    transaction.input[1].witness,
+    *psbt.inputs[0].final_script_witness.as_ref().unwrap()
+  );
+  assert_eq!(transaction.output[1], psbt.unsigned_tx.output[0]);
+
+  rpc_server.mine_blocks(1);
+
+  TestServer::spawn_with_args(&rpc_server, &[]).assert_response_regex(
+    format!("/inscription/{inscription}"),
+    format!(
+      r".*<dt>location</dt>\s*<dd class=monospace>{}:0:.*</dd>.*",
+      output.transaction
+    ),
+  );
+}
+
+#[test]
+fn unsigned_offer_cannot_be_accepted() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+
+  let (_, _, mut psbt) = create_offer(&rpc_server);
+
+  psbt.inputs[0].final_script_witness = None;
+
+  CommandBuilder::new(format!(
+    "wallet offer accept --fee-rate 1 --max-price 1btc {psbt}"
+  ))
+  .rpc_server(&rpc_server)
+  .expected_stderr("error: offer input is not signed\n")
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn offer_signed_with_wrong_sighash_type_cannot_be_accepted() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+
+  let (_, _, mut psbt) = create_offer(&rpc_server);
+
+  psbt.inputs[0].final_script_witness = Some(bitcoin::Witness::from_slice(&[[0; 64]]));
+
+  CommandBuilder::new(format!(
+    "wallet offer accept --fee-rate 1 --max-price 1btc {psbt}"
+  ))
+  .rpc_server(&rpc_server)
+  .expected_stderr("error: offer input must be signed with SIGHASH_SINGLE|SIGHASH_ANYONECANPAY\n")
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn offer_for_moved_inscription_cannot_be_accepted() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+
+  let (inscription, reveal, psbt) = create_offer(&rpc_server);
+
+  CommandBuilder::new(format!(
+    "wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {inscription}",
+  ))
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<ord::subcommand::wallet::send::Output>();
+
+  rpc_server.mine_blocks(1);
+
+  CommandBuilder::new(format!(
+    "wallet offer accept --fee-rate 1 --max-price 1btc {psbt}"
+  ))
+  .rpc_server(&rpc_server)
+  .expected_stderr(format!(
+    "error: offer input {reveal}:0 does not contain inscription {inscription}\n"
+  ))
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn offer_price_must_be_above_dust() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let (inscription, _) = inscribe(&rpc_server);
+
+  CommandBuilder::new(format!("wallet offer create {inscription} 1sat"))
+    .rpc_server(&rpc_server)
+    .expected_stderr("error: price 1 is below dust value 330\n")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn offer_above_max_price_cannot_be_accepted() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+
+  let (_, _, psbt) = create_offer(&rpc_server);
+
+  CommandBuilder::new(format!(
+    "wallet offer accept --fee-rate 1 --max-price 0.5btc {psbt}"
+  ))
+  .rpc_server(&rpc_server)
+  .expected_stderr("error: offer price of 100000000 sats exceeds maximum price of 50000000 sats\n")
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+
+  assert!(rpc_server.mempool().is_empty());
+}
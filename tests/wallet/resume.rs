@@ -0,0 +1,169 @@
+use {super::*, bitcoin::hashes::Hash, ord::subcommand::wallet::resume::Output};
+
+#[test]
+fn resume_with_nothing_pending() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  let output = CommandBuilder::new("wallet resume")
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Output>();
+
+  assert!(output.inscriptions.is_empty());
+}
+
+#[test]
+fn resume_unknown_commit_fails() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  CommandBuilder::new(format!("wallet resume {}", Txid::all_zeros()))
+    .rpc_server(&rpc_server)
+    .expected_stderr(format!(
+      "error: no pending inscription with commit transaction {}\n",
+      Txid::all_zeros()
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn resume_re_signs_rejected_reveal_at_higher_fee_rate() {
+  let rpc_server = test_bitcoincore_rpc::builder()
+    .min_relay_fee_rate(1.0)
+    .build();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  CommandBuilder::new("wallet inscribe --file foo.txt --commit-fee-rate 2 --fee-rate 0.5")
+    .temp_dir(tempdir.clone())
+    .write("foo.txt", "FOO")
+    .rpc_server(&rpc_server)
+    .stderr_regex(
+      "error: Failed to send reveal transaction: .*min relay fee not met.*\nCommit tx [[:xdigit:]]{64} was saved, run `ord wallet resume` to retry or `ord wallet abandon [[:xdigit:]]{64}` to recover its funds\n",
+    )
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  assert_eq!(rpc_server.mempool().len(), 1);
+
+  let commit = rpc_server.mempool()[0].txid();
+
+  CommandBuilder::new("wallet resume")
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .stderr_regex(".*min relay fee not met.*")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  assert_eq!(rpc_server.mempool().len(), 1);
+
+  let output = CommandBuilder::new(format!("wallet resume --fee-rate 2 {commit}"))
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Output>();
+
+  assert_eq!(output.inscriptions.len(), 1);
+
+  let inscribe = &output.inscriptions[0];
+
+  assert_eq!(inscribe.commit, commit);
+
+  let mempool = rpc_server.mempool();
+
+  assert_eq!(mempool.len(), 2);
+  assert_eq!(inscribe.reveal, mempool[1].txid());
+  assert_eq!(inscribe.inscriptions[0].id.txid, inscribe.reveal);
+
+  rpc_server.mine_blocks(1);
+
+  let response = TestServer::spawn_with_args(&rpc_server, &[])
+    .request(format!("/content/{}", inscribe.inscriptions[0].id));
+
+  assert_eq!(response.status(), 200);
+  assert_eq!(response.text().unwrap(), "FOO");
+
+  let output = CommandBuilder::new("wallet resume")
+    .temp_dir(tempdir)
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Output>();
+
+  assert!(output.inscriptions.is_empty());
+}
+
+#[test]
+fn resume_does_not_rebroadcast_confirmed_commit_without_txindex() {
+  let rpc_server = test_bitcoincore_rpc::builder()
+    .min_relay_fee_rate(1.0)
+    .txindex(false)
+    .build();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  CommandBuilder::new("wallet inscribe --file foo.txt --commit-fee-rate 2 --fee-rate 0.5")
+    .temp_dir(tempdir.clone())
+    .write("foo.txt", "FOO")
+    .rpc_server(&rpc_server)
+    .stderr_regex("error: Failed to send reveal transaction: .*")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  let commit = rpc_server.mempool()[0].txid();
+
+  rpc_server.mine_blocks(1);
+
+  let output = CommandBuilder::new("wallet resume --fee-rate 2")
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Output>();
+
+  assert_eq!(output.inscriptions.len(), 1);
+  assert_eq!(output.inscriptions[0].commit, commit);
+
+  let mempool = rpc_server.mempool();
+
+  assert_eq!(mempool.len(), 1);
+  assert_eq!(mempool[0].txid(), output.inscriptions[0].reveal);
+
+  let output = CommandBuilder::new("wallet resume")
+    .temp_dir(tempdir)
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<Output>();
+
+  assert!(output.inscriptions.is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn journal_is_only_readable_by_owner() {
+  use std::os::unix::fs::PermissionsExt;
+
+  let rpc_server = test_bitcoincore_rpc::builder()
+    .min_relay_fee_rate(1.0)
+    .build();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  CommandBuilder::new("wallet inscribe --file foo.txt --commit-fee-rate 2 --fee-rate 0.5")
+    .temp_dir(tempdir.clone())
+    .write("foo.txt", "FOO")
+    .rpc_server(&rpc_server)
+    .stderr_regex("error: Failed to send reveal transaction: .*")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  assert_eq!(
+    fs::metadata(tempdir.path().join("wallets/ord/journal.json"))
+      .unwrap()
+      .permissions()
+      .mode()
+      & 0o777,
+    0o600
+  );
+}
@@ -0,0 +1,55 @@
+use {super::*, ord::subcommand::wallet::runes::Output};
+
+#[test]
+fn wallet_runes_requires_rune_index() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  CommandBuilder::new("wallet runes")
+    .rpc_server(&rpc_server)
+    .expected_stderr("error: `ord wallet runes` requires index created with `--index-runes-pre-alpha-i-agree-to-get-rekt` flag\n")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn wallet_runes() {
+  let rpc_server = test_bitcoincore_rpc::builder()
+    .network(Network::Regtest)
+    .build();
+  create_wallet(&rpc_server);
+
+  let runes = || {
+    CommandBuilder::new("--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet runes")
+      .rpc_server(&rpc_server)
+      .run_and_deserialize_output::<Output>()
+      .runes
+      .into_iter()
+      .map(|(rune, balance)| (rune.to_string(), balance))
+      .collect::<Vec<(String, String)>>()
+  };
+
+  assert_eq!(runes(), []);
+
+  etch(&rpc_server, RUNE, 1, 1000);
+
+  assert_eq!(runes(), [("AAAAAAAAAAAAA".into(), "100".into())]);
+
+  let address = CommandBuilder::new("--chain regtest wallet receive")
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<ord::subcommand::wallet::receive::Output>()
+    .address
+    .assume_checked();
+
+  CommandBuilder::new(format!(
+    "--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet send --fee-rate 1 {address} 12.5:AAAAAAAAAAAAA",
+  ))
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<ord::subcommand::wallet::send::Output>();
+
+  assert_eq!(rpc_server.mempool().len(), 1);
+
+  rpc_server.mine_blocks(1);
+
+  assert_eq!(runes(), [("AAAAAAAAAAAAA".into(), "100".into())]);
+}
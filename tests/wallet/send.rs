@@ -524,3 +524,304 @@ fn send_btc_does_not_send_locked_utxos() {
     .stderr_regex("error:.*")
     .run_and_extract_stdout();
 }
+
+#[test]
+fn inscriptions_can_be_sent_as_psbt() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let (inscription, reveal) = inscribe(&rpc_server);
+
+  rpc_server.mine_blocks(1);
+
+  let output = CommandBuilder::new(format!(
+    "wallet send --fee-rate 1 --psbt bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {inscription}",
+  ))
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<Output>();
+
+  assert!(rpc_server.mempool().is_empty());
+
+  let psbt = output.psbt.unwrap().parse::<Psbt>().unwrap();
+
+  assert_eq!(psbt.unsigned_tx.txid(), output.transaction);
+  assert_eq!(
+    psbt.unsigned_tx.input[0].previous_output,
+    OutPoint {
+      txid: reveal,
+      vout: 0
+    }
+  );
+  assert_eq!(psbt.inputs[0].witness_utxo.as_ref().unwrap().value, 10_000);
+  assert_eq!(
+    psbt.inputs[0]
+      .proprietary
+      .values()
+      .collect::<Vec<&Vec<u8>>>(),
+    [&0u64.to_le_bytes().to_vec()]
+  );
+  assert_eq!(
+    psbt.inputs[0].proprietary.keys().next().unwrap().key,
+    inscription.to_string().into_bytes()
+  );
+}
+
+#[test]
+fn sending_amount_as_psbt_is_not_supported() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  CommandBuilder::new(
+    "wallet send --fee-rate 1 --psbt bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  )
+  .rpc_server(&rpc_server)
+  .expected_stderr("error: --psbt is only supported when sending sats or inscriptions\n")
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn runes_can_be_sent() {
+  let rpc_server = test_bitcoincore_rpc::builder()
+    .network(Network::Regtest)
+    .build();
+  create_wallet(&rpc_server);
+
+  let etch = etch(&rpc_server, RUNE, 1, 1000);
+
+  let output = CommandBuilder::new(
+    "--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet send --fee-rate 1 bcrt1q6rhpng9evdsfnn833a4f4vej0asu6dk5srld6x 12.5:AAAAAAAAAAAAA",
+  )
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<Output>();
+
+  rpc_server.mine_blocks(1);
+
+  let server = TestServer::spawn_with_server_args(
+    &rpc_server,
+    &[
+      "--regtest",
+      "--cookie-file",
+      ".cookie",
+      "--index-runes-pre-alpha-i-agree-to-get-rekt",
+    ],
+    &["--enable-json-api"],
+  );
+
+  let runes = |outpoint: String| {
+    serde_json::from_str::<OutputJson>(
+      &server
+        .json_request(format!("/output/{outpoint}"))
+        .text()
+        .unwrap(),
+    )
+    .unwrap()
+    .runes
+    .into_iter()
+    .map(|(rune, amount)| (rune.to_string(), amount))
+    .collect::<Vec<(String, u128)>>()
+  };
+
+  assert_eq!(runes(format!("{etch}:0")), []);
+
+  assert_eq!(
+    runes(format!("{}:1", output.transaction)),
+    [("AAAAAAAAAAAAA".into(), 875)],
+  );
+
+  assert_eq!(
+    runes(format!("{}:2", output.transaction)),
+    [("AAAAAAAAAAAAA".into(), 125)],
+  );
+}
+
+#[test]
+fn sending_runes_requires_rune_index() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  CommandBuilder::new("wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1:AAAAAAAAAAAAA")
+    .rpc_server(&rpc_server)
+    .expected_stderr("error: sending runes with `ord send` requires index created with `--index-runes-pre-alpha-i-agree-to-get-rekt` flag\n")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn sending_unetched_rune_fails() {
+  let rpc_server = test_bitcoincore_rpc::builder()
+    .network(Network::Regtest)
+    .build();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  CommandBuilder::new(
+    "--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet send --fee-rate 1 bcrt1q6rhpng9evdsfnn833a4f4vej0asu6dk5srld6x 1:AAAAAAAAAAAAA",
+  )
+  .rpc_server(&rpc_server)
+  .expected_stderr("error: rune `AAAAAAAAAAAAA` has not been etched\n")
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn sending_more_runes_than_balance_fails() {
+  let rpc_server = test_bitcoincore_rpc::builder()
+    .network(Network::Regtest)
+    .build();
+  create_wallet(&rpc_server);
+
+  etch(&rpc_server, RUNE, 1, 1000);
+
+  CommandBuilder::new(
+    "--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet send --fee-rate 1 bcrt1q6rhpng9evdsfnn833a4f4vej0asu6dk5srld6x 100.1:AAAAAAAAAAAAA",
+  )
+  .rpc_server(&rpc_server)
+  .expected_stderr("error: insufficient `AAAAAAAAAAAAA` balance, only 100 in wallet\n")
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn send_with_inputs_only_uses_given_cardinals() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let (inscription, _) = inscribe(&rpc_server);
+
+  let coinbase_tx = &rpc_server.mine_blocks(1)[0].txdata[0];
+  let input = OutPoint::new(coinbase_tx.txid(), 0);
+
+  rpc_server.mine_blocks(1);
+
+  let output = CommandBuilder::new(format!(
+    "wallet send --fee-rate 1 --postage 30000sat --inputs {input} bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {inscription}",
+  ))
+  .rpc_server(&rpc_server)
+  .run_and_deserialize_output::<Output>();
+
+  let transaction = &rpc_server.mempool()[0];
+
+  assert_eq!(transaction.txid(), output.transaction);
+  assert_eq!(transaction.input.len(), 2);
+  assert_eq!(transaction.input[1].previous_output, input);
+}
+
+#[test]
+fn send_with_inputs_not_in_wallet_fails() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let (inscription, _) = inscribe(&rpc_server);
+
+  CommandBuilder::new(format!(
+    "wallet send --fee-rate 1 --inputs {} bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {inscription}",
+    OutPoint::null(),
+  ))
+  .rpc_server(&rpc_server)
+  .expected_stderr(format!("error: input {} not in wallet\n", OutPoint::null()))
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn send_with_frozen_inputs_fails() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let (inscription, _) = inscribe(&rpc_server);
+
+  let coinbase_tx = &rpc_server.mine_blocks(1)[0].txdata[0];
+  let input = OutPoint::new(coinbase_tx.txid(), 0);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  CommandBuilder::new(format!("wallet freeze {input}"))
+    .temp_dir(tempdir.clone())
+    .rpc_server(&rpc_server)
+    .run_and_deserialize_output::<ord::subcommand::wallet::freeze::Output>();
+
+  CommandBuilder::new(format!(
+    "wallet send --fee-rate 1 --inputs {input} bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {inscription}",
+  ))
+  .temp_dir(tempdir)
+  .rpc_server(&rpc_server)
+  .expected_stderr(format!("error: input {input} is frozen\n"))
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn send_with_inscribed_inputs_fails() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+  rpc_server.mine_blocks(1);
+
+  let (inscription, reveal) = inscribe(&rpc_server);
+
+  let coinbase_tx = &rpc_server.mine_blocks(1)[0].txdata[0];
+  let satpoint = SatPoint {
+    outpoint: OutPoint::new(coinbase_tx.txid(), 0),
+    offset: 0,
+  };
+
+  let input = OutPoint::new(reveal, 0);
+
+  CommandBuilder::new(format!(
+    "wallet send --fee-rate 1 --inputs {input} bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {satpoint}",
+  ))
+  .rpc_server(&rpc_server)
+  .expected_stderr(format!(
+    "error: input {input} contains inscription {inscription}\n"
+  ))
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn send_with_rune_inputs_fails() {
+  let rpc_server = test_bitcoincore_rpc::builder()
+    .network(Network::Regtest)
+    .build();
+  create_wallet(&rpc_server);
+
+  let etch = etch(&rpc_server, RUNE, 1, 1000);
+
+  let coinbase_tx = &rpc_server.mine_blocks(1)[0].txdata[0];
+  let satpoint = SatPoint {
+    outpoint: OutPoint::new(coinbase_tx.txid(), 0),
+    offset: 0,
+  };
+
+  let input = OutPoint::new(etch, 0);
+
+  CommandBuilder::new(format!(
+    "--chain regtest --index-runes-pre-alpha-i-agree-to-get-rekt wallet send --fee-rate 1 --inputs {input} bcrt1q6rhpng9evdsfnn833a4f4vej0asu6dk5srld6x {satpoint}",
+  ))
+  .rpc_server(&rpc_server)
+  .expected_stderr(format!("error: input {input} contains runes\n"))
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn send_amount_with_inputs_fails() {
+  let rpc_server = test_bitcoincore_rpc::spawn();
+  create_wallet(&rpc_server);
+
+  let coinbase_tx = &rpc_server.mine_blocks(1)[0].txdata[0];
+  let input = OutPoint::new(coinbase_tx.txid(), 0);
+
+  CommandBuilder::new(format!(
+    "wallet send --fee-rate 1 --inputs {input} bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  ))
+  .rpc_server(&rpc_server)
+  .expected_stderr("error: --inputs is only supported when sending sats or inscriptions\n")
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}